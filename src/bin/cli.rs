@@ -0,0 +1,297 @@
+//! Headless subcommands to manage doc caches without starting the TUI.
+//!
+//! Progress and errors are printed to stderr, and `list` prints caches to stdout.
+
+use crate::{
+    database::{CachedDocInfo, DataBase, Features, PkgWithFeatures},
+    err,
+    event::Event,
+    local_registry::{workspace_members, LocalRegistry, PkgInfo},
+    Result,
+};
+use semver::VersionReq;
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Instant,
+};
+use term_rustdoc::util::XString;
+
+const USAGE: &str = "\
+Usage:
+    term-rustdoc                 start the TUI
+    term-rustdoc <PATH>          start the TUI with workspace members of the Cargo.toml or dir
+    term-rustdoc build <PKG>... [--features <FEATURES>] [--all-features] [--no-default-features]
+                                 compile and cache docs for pkgs in local registry
+    term-rustdoc list            list all cached docs
+    term-rustdoc rm <PKG>...     remove cached docs of all features for pkgs
+
+PKG is `name` for the latest version in local registry, or `name@version`.
+FEATURES are separated by comma or space, and the flag can be repeated.";
+
+pub enum Args {
+    /// Start the TUI with the project manifest and its workspace members if given.
+    Tui {
+        manifest: Option<PathBuf>,
+        workspace: Vec<PkgInfo>,
+    },
+    /// Run a subcommand without the TUI.
+    Command(Command),
+}
+
+pub enum Command {
+    Build {
+        pkgs: Vec<PkgSpec>,
+        features: Features,
+    },
+    List,
+    Remove(Vec<PkgSpec>),
+    Help,
+}
+
+/// A pkg given as `name` or `name@version` in command line.
+pub struct PkgSpec {
+    name: String,
+    version: Option<String>,
+}
+
+impl PkgSpec {
+    fn parse(arg: &str) -> Self {
+        match arg.split_once('@') {
+            Some((name, ver)) => PkgSpec {
+                name: name.to_owned(),
+                version: Some(ver.to_owned()),
+            },
+            None => PkgSpec {
+                name: arg.to_owned(),
+                version: None,
+            },
+        }
+    }
+
+    fn matches(&self, name: &str, ver: &str) -> bool {
+        self.name == name && self.version.as_deref().is_none_or(|v| v == ver)
+    }
+
+    /// Find the pkg in local registry. The version can be exact or a requirement
+    /// like `1.0`, and the latest matched version is chosen.
+    fn find(&self, pkgs: &[PkgInfo]) -> Result<PkgInfo> {
+        let candidates = pkgs.iter().filter(|pkg| pkg.name() == self.name);
+        let found = match &self.version {
+            None => candidates.max_by(|a, b| a.version().cmp(b.version())),
+            Some(ver) => match candidates.clone().find(|pkg| pkg.ver() == ver) {
+                Some(pkg) => Some(pkg),
+                None => {
+                    let req = VersionReq::parse(ver)
+                        .map_err(|e| err!("Invalid version `{ver}` for {}: {e}", self.name))?;
+                    candidates
+                        .filter(|pkg| req.matches(pkg.version()))
+                        .max_by(|a, b| a.version().cmp(b.version()))
+                }
+            },
+        };
+        found
+            .cloned()
+            .ok_or_else(|| err!("`{self}` is not found in local registry"))
+    }
+}
+
+impl std::fmt::Display for PkgSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.version {
+            Some(ver) => write!(f, "{}@{ver}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl Args {
+    pub fn parse() -> Result<Self> {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    /// Parse arguments without the program name.
+    fn parse_from(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let Some(cmd) = args.next() else {
+            return Ok(Args::Tui {
+                manifest: None,
+                workspace: Vec::new(),
+            });
+        };
+        let cmd = match &*cmd {
+            "build" => {
+                let mut pkgs = Vec::new();
+                let [mut all, mut no_default] = [false; 2];
+                let mut features = Vec::<XString>::new();
+                while let Some(arg) = args.next() {
+                    match &*arg {
+                        "--all-features" => all = true,
+                        "--no-default-features" => no_default = true,
+                        "--features" | "-F" => {
+                            let arg = args
+                                .next()
+                                .ok_or_else(|| err!("`{arg}` requires a value\n\n{USAGE}"))?;
+                            let iter = arg.split([',', ' ']).filter(|f| !f.is_empty());
+                            features.extend(iter.map(XString::from));
+                        }
+                        flag if flag.starts_with('-') => {
+                            return Err(err!("Unknown flag `{flag}`\n\n{USAGE}"))
+                        }
+                        pkg => pkgs.push(PkgSpec::parse(pkg)),
+                    }
+                }
+                let features = match (all, no_default, features.is_empty()) {
+                    (true, _, _) => Features::All,
+                    (false, false, true) => Features::Default,
+                    (false, false, false) => Features::DefaultPlus(features.into()),
+                    (false, true, true) => Features::NoDefault,
+                    (false, true, false) => Features::NoDefaultPlus(features.into()),
+                };
+                Command::Build { pkgs, features }
+            }
+            "list" => Command::List,
+            "rm" => Command::Remove(args.map(|arg| PkgSpec::parse(&arg)).collect()),
+            "help" | "--help" | "-h" => Command::Help,
+            path if Path::new(path).is_dir() || path.ends_with("Cargo.toml") => {
+                let mut manifest = Path::new(path).to_owned();
+                if manifest.is_dir() {
+                    manifest.push("Cargo.toml");
+                }
+                let workspace = workspace_members(&manifest)?;
+                return Ok(Args::Tui {
+                    manifest: Some(manifest),
+                    workspace,
+                });
+            }
+            _ => return Err(err!("Unknown subcommand `{cmd}`\n\n{USAGE}")),
+        };
+        Ok(Args::Command(cmd))
+    }
+}
+
+impl Command {
+    pub fn run(self) -> Result<()> {
+        match self {
+            Command::Build { pkgs, features } => build(pkgs, features),
+            Command::List => list(),
+            Command::Remove(pkgs) => remove(pkgs),
+            Command::Help => {
+                eprintln!("{USAGE}");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Compile docs in parallel, and block until all of them are written into db files.
+fn build(pkgs: Vec<PkgSpec>, features: Features) -> Result<()> {
+    if pkgs.is_empty() {
+        return Err(err!("No pkg to build\n\n{USAGE}"));
+    }
+    let registry = LocalRegistry::all_pkgs_in_latest_registry()?;
+    let pkgs = pkgs
+        .iter()
+        .map(|spec| spec.find(&registry))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (sender, receiver) = mpsc::channel();
+    let db = DataBase::init(sender)?;
+    let now = Instant::now();
+    let mut pending = 0;
+    let mut failed = 0;
+    for info in pkgs {
+        let pkg = PkgWithFeatures {
+            features: features.clone(),
+            info,
+        };
+        let name = format!("{}@{}", pkg.info.name(), pkg.info.ver());
+        match db.compile_doc(pkg) {
+            Some(key) => {
+                eprintln!("Compiling {key:?}");
+                pending += 1;
+            }
+            None => {
+                eprintln!("Failed to start compiling {name}; see the log for details");
+                failed += 1;
+            }
+        }
+    }
+
+    while pending > 0 {
+        match receiver.recv()? {
+            Event::DocCompiled(info) => {
+                eprintln!(
+                    "Cached {:?} in {:.2}s",
+                    info.pkg,
+                    now.elapsed().as_secs_f32()
+                );
+            }
+            Event::DocFailed(key, err) => {
+                eprintln!("Failed to cache {key:?}:\n{err}");
+                failed += 1;
+            }
+            _ => continue,
+        }
+        pending -= 1;
+    }
+    match failed {
+        0 => Ok(()),
+        n => Err(err!("{n} doc(s) failed to be cached")),
+    }
+}
+
+fn all_caches() -> Result<Vec<CachedDocInfo>> {
+    // no event is sent for reading and removing caches
+    let (sender, _) = mpsc::channel();
+    DataBase::init(sender)?.all_caches()
+}
+
+fn list() -> Result<()> {
+    let mut caches = all_caches()?;
+    caches.sort_unstable_by(|a, b| {
+        let key = |info: &CachedDocInfo| (info.pkg.name().to_owned(), info.pkg.version());
+        key(a).cmp(&key(b))
+    });
+    for info in &caches {
+        let pkg = &info.pkg;
+        println!("{}@{} {:?}", pkg.name(), pkg.ver_str(), pkg.features());
+    }
+    eprintln!("{} cached doc(s) in total", caches.len());
+    Ok(())
+}
+
+fn remove(pkgs: Vec<PkgSpec>) -> Result<()> {
+    if pkgs.is_empty() {
+        return Err(err!("No pkg to remove\n\n{USAGE}"));
+    }
+    let (removed, remained): (Vec<_>, Vec<_>) = all_caches()?.into_iter().partition(|info| {
+        let pkg = &info.pkg;
+        pkgs.iter()
+            .any(|spec| spec.matches(pkg.name(), pkg.ver_str()))
+    });
+    for spec in &pkgs {
+        let pkg = |info: &CachedDocInfo| spec.matches(info.pkg.name(), info.pkg.ver_str());
+        if !removed.iter().any(pkg) {
+            eprintln!("`{spec}` is not cached");
+        }
+    }
+    for info in &removed {
+        info.remove()?;
+        eprintln!("Removed {:?}", info.pkg);
+    }
+    // delete db files no longer used by other docs
+    let in_use: HashSet<_> = remained.iter().map(|info| info.db_file()).collect();
+    let unused: HashSet<_> = removed.iter().map(|info| info.db_file()).collect();
+    for file in unused.difference(&in_use) {
+        if file.exists() {
+            fs::remove_file(file)?;
+            eprintln!("Deleted {}", file.display());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,148 @@
+use super::{Args, Command, PkgSpec};
+use crate::{database::Features, local_registry::PkgInfo, Result};
+
+fn parse(args: &str) -> Result<Args> {
+    Args::parse_from(args.split_whitespace().map(String::from))
+}
+
+fn command(args: &str) -> Command {
+    match parse(args) {
+        Ok(Args::Command(cmd)) => cmd,
+        Ok(Args::Tui { .. }) => panic!("`{args}` starts the TUI"),
+        Err(err) => panic!("`{args}` fails to be parsed:\n{err}"),
+    }
+}
+
+fn error(args: &str) -> String {
+    match parse(args) {
+        Err(err) => err.to_string(),
+        Ok(_) => panic!("`{args}` should fail to be parsed"),
+    }
+}
+
+/// Pkgs and features of the build command.
+fn build(args: &str) -> (Vec<String>, Features) {
+    match command(args) {
+        Command::Build { pkgs, features } => {
+            (pkgs.iter().map(PkgSpec::to_string).collect(), features)
+        }
+        _ => panic!("`{args}` is not a build command"),
+    }
+}
+
+fn plus(features: &[&str]) -> Box<[term_rustdoc::util::XString]> {
+    features.iter().map(|&f| f.into()).collect()
+}
+
+#[test]
+fn build_command() {
+    assert_eq!(build("build"), (vec![], Features::Default));
+    assert_eq!(
+        build("build serde tokio@1.0"),
+        (vec!["serde".into(), "tokio@1.0".into()], Features::Default)
+    );
+    assert_eq!(
+        build("build serde --all-features --no-default-features").1,
+        Features::All
+    );
+    assert_eq!(
+        build("build serde --no-default-features").1,
+        Features::NoDefault
+    );
+    assert_eq!(
+        build("build serde --features derive,rc -F alloc").1,
+        Features::DefaultPlus(plus(&["derive", "rc", "alloc"]))
+    );
+    assert_eq!(
+        build("build --no-default-features -F derive serde@1").1,
+        Features::NoDefaultPlus(plus(&["derive"]))
+    );
+    let features = Args::parse_from(
+        ["build", "serde", "-F", "derive rc"]
+            .map(String::from)
+            .into_iter(),
+    );
+    match features {
+        Ok(Args::Command(Command::Build { features, .. })) => {
+            assert_eq!(features, Features::DefaultPlus(plus(&["derive", "rc"])));
+        }
+        _ => panic!("space separated features fail to be parsed"),
+    }
+}
+
+#[test]
+fn other_commands() {
+    assert!(matches!(parse(""), Ok(Args::Tui { manifest: None, .. })));
+    assert!(matches!(command("list"), Command::List));
+    for help in ["help", "--help", "-h"] {
+        assert!(matches!(command(help), Command::Help));
+    }
+    match command("rm serde tokio@1.0.0") {
+        Command::Remove(pkgs) => {
+            let pkgs: Vec<_> = pkgs.iter().map(PkgSpec::to_string).collect();
+            assert_eq!(pkgs, ["serde", "tokio@1.0.0"]);
+        }
+        _ => panic!("rm is not parsed as Remove"),
+    }
+}
+
+#[test]
+fn invalid_args() {
+    assert!(error("build serde --all").starts_with("Unknown flag `--all`"));
+    assert!(error("build serde --features").starts_with("`--features` requires a value"));
+    assert!(error("build -F").starts_with("`-F` requires a value"));
+    assert!(error("compile serde").starts_with("Unknown subcommand `compile`"));
+    assert!(error("not/a/dir").starts_with("Unknown subcommand `not/a/dir`"));
+}
+
+#[test]
+fn path_argument() {
+    let dir = env!("CARGO_MANIFEST_DIR");
+    for path in [dir.to_owned(), format!("{dir}/Cargo.toml")] {
+        let Ok(Args::Tui {
+            manifest,
+            workspace,
+        }) = parse(&path)
+        else {
+            panic!("`{path}` doesn't start the TUI");
+        };
+        assert_eq!(
+            manifest.unwrap(),
+            std::path::Path::new(dir).join("Cargo.toml")
+        );
+        let members: Vec<_> = workspace.iter().map(PkgInfo::name).collect();
+        assert!(members.contains(&"term-rustdoc"), "{members:?}");
+        assert!(workspace.iter().all(PkgInfo::is_path));
+    }
+}
+
+#[test]
+fn find_pkg() -> Result<()> {
+    let pkgs = [
+        "serde-1.0.190",
+        "serde-1.0.197",
+        "serde-0.9.15",
+        "tokio-1.36.0",
+    ]
+    .map(PkgInfo::from_dir_name);
+    let find = |spec: &str| {
+        PkgSpec::parse(spec)
+            .find(&pkgs)
+            .map(|pkg| pkg.ver().to_owned())
+    };
+
+    // the latest version without a version
+    assert_eq!(find("serde")?, "1.0.197");
+    // the exact version
+    assert_eq!(find("serde@1.0.190")?, "1.0.190");
+    // the latest version matching the requirement
+    assert_eq!(find("serde@1.0")?, "1.0.197");
+    assert_eq!(find("serde@0.9")?, "0.9.15");
+    assert_eq!(find("serde@<1.0.197")?, "1.0.190");
+
+    let err = |spec: &str| find(spec).unwrap_err().to_string();
+    assert_eq!(err("serde@2"), "`serde@2` is not found in local registry");
+    assert_eq!(err("anyhow"), "`anyhow` is not found in local registry");
+    assert!(err("serde@x.y").starts_with("Invalid version `x.y` for serde"));
+    Ok(())
+}
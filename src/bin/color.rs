@@ -37,6 +37,27 @@ pub const HOLDON: Style = Style {
     fg: Some(Color::from_u32(0x00FF768C)), // #FF768C
     ..Style::new()
 };
+pub const DIFF_BASE: Style = Style {
+    fg: Some(Color::Black),
+    bg: Some(Color::from_u32(0x00FFD48E)), // #FFD48E
+    ..Style::new()
+};
+
+pub const PROJECT_DEP: Style = Style {
+    fg: Some(Color::Black),
+    bg: Some(Color::from_u32(0x0098C379)), // #98C379
+    ..Style::new()
+};
+
+// API Diff
+pub const DIFF_HEADING: Style = Style {
+    fg: Some(Color::White),
+    add_modifier: Modifier::BOLD,
+    ..Style::new()
+};
+pub const DIFF_REMOVED: Style = Style::new().fg(Color::from_u32(0x00FF768C)); // #FF768C
+pub const DIFF_ADDED: Style = Style::new().fg(Color::from_u32(0x0098C379)); // #98C379
+pub const DIFF_BREAKING: Style = Style::new().fg(Color::White).bg(Color::Red);
 
 pub const PKG_TOML: Style = Style {
     fg: Some(Color::Green),
@@ -50,6 +71,14 @@ pub const HEAD: Style = Style {
     bg: Some(Color::LightCyan),
     ..Style::new()
 };
+pub const TAB: Style = Style::new().fg(Color::Gray).bg(Color::from_u32(0x0029335b)); // #29335b
+pub const TAB_CURRENT: Style = Style {
+    fg: Some(Color::Black),
+    bg: Some(Color::from_u32(0x00FFD48E)), // #FFD48E
+    add_modifier: Modifier::BOLD,
+    ..Style::new()
+};
+pub const SEARCH_MATCH: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
 pub const SET: Style = Style::new().bg(Color::Rgb(20, 19, 18)); // #141312
 pub const NEW: Style = Style::new();
 pub const DECLARATION_BORDER: Style = Style::new().fg(Color::Gray);
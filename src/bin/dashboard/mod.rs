@@ -1,7 +1,8 @@
 mod ui;
 
-use crate::{event::Sender, fuzzy::Fuzzy, Result};
+use crate::{event::Sender, fuzzy::Fuzzy, local_registry::PkgInfo, Result};
 use ratatui::layout::Rect;
+use std::path::PathBuf;
 
 use self::ui::UI;
 
@@ -10,8 +11,14 @@ pub struct DashBoard {
 }
 
 impl DashBoard {
-    pub fn new(full: Rect, fuzzy: Fuzzy, sender: Sender) -> Result<Self> {
-        let ui = UI::new(full, fuzzy, sender);
+    pub fn new(
+        full: Rect,
+        fuzzy: Fuzzy,
+        sender: Sender,
+        project: Option<PathBuf>,
+        workspace: &[PkgInfo],
+    ) -> Result<Self> {
+        let ui = UI::new(full, fuzzy, sender, project, workspace);
         Ok(DashBoard { ui })
     }
 
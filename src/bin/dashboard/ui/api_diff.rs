@@ -0,0 +1,194 @@
+//! A popup that shows the public API diff between two cached docs of the same crate.
+
+use crate::{
+    color::{BG_CURSOR_LINE, DIFF_ADDED, DIFF_BREAKING, DIFF_HEADING, DIFF_REMOVED},
+    database::PkgKey,
+    ui::{render_line, LineState, PopupContent, Scroll, Surround},
+};
+use public_api::diff::PublicApiDiff;
+use ratatui::prelude::{Buffer, Rect, Style};
+use std::fmt::Display;
+use term_rustdoc::util::{xformat, XString};
+
+#[derive(Default)]
+pub struct ApiDiff {
+    inner: Scroll<DiffLines>,
+    title: XString,
+    /// Counts of removed, changed and added items.
+    counts: [usize; 3],
+}
+
+#[derive(Default)]
+pub struct DiffLines(Vec<DiffLine>);
+
+impl std::ops::Deref for DiffLines {
+    type Target = [DiffLine];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct DiffLine {
+    kind: LineKind,
+    text: String,
+}
+
+#[derive(Clone, Copy)]
+enum LineKind {
+    Heading,
+    /// A removed item is always a breaking change.
+    Removed,
+    /// The declaration of a changed item in the old version.
+    Before,
+    /// The declaration of a changed item in the new version.
+    After,
+    Added,
+    Blank,
+}
+
+impl LineState for DiffLine {
+    type State = String;
+
+    fn state(&self) -> Self::State {
+        self.text.clone()
+    }
+
+    fn is_identical(&self, state: &Self::State) -> bool {
+        self.text == *state
+    }
+}
+
+impl DiffLines {
+    fn push(&mut self, kind: LineKind, text: impl ToString) {
+        self.0.push(DiffLine {
+            kind,
+            text: text.to_string(),
+        });
+    }
+
+    fn new(diff: &PublicApiDiff) -> Self {
+        let changed = diff.changed.iter().map(|item| [&item.old, &item.new]);
+        DiffLines::from_items(&diff.removed, changed, &diff.added)
+    }
+
+    /// Lines of removed items, old and new declarations of changed items, and added items.
+    fn from_items<'a, T: Display + 'a>(
+        removed: &[T],
+        changed: impl ExactSizeIterator<Item = [&'a T; 2]>,
+        added: &[T],
+    ) -> Self {
+        let mut lines = DiffLines::default();
+        if removed.is_empty() && changed.len() == 0 && added.is_empty() {
+            lines.push(LineKind::Heading, "No change in public API.");
+            return lines;
+        }
+        if !removed.is_empty() {
+            lines.push(LineKind::Heading, format!("Removed ({})", removed.len()));
+            for item in removed {
+                lines.push(LineKind::Removed, item);
+            }
+            lines.push(LineKind::Blank, "");
+        }
+        if changed.len() != 0 {
+            lines.push(LineKind::Heading, format!("Changed ({})", changed.len()));
+            for [old, new] in changed {
+                lines.push(LineKind::Before, old);
+                lines.push(LineKind::After, new);
+            }
+            lines.push(LineKind::Blank, "");
+        }
+        if !added.is_empty() {
+            lines.push(LineKind::Heading, format!("Added ({})", added.len()));
+            for item in added {
+                lines.push(LineKind::Added, item);
+            }
+        }
+        lines
+    }
+}
+
+impl DiffLine {
+    /// Styled texts put before the line text, and the style of the line text.
+    fn label(&self) -> (&'static [(&'static str, Style)], Style) {
+        const NONE: Style = Style::new();
+        match self.kind {
+            LineKind::Heading => (&[], DIFF_HEADING),
+            LineKind::Removed => (
+                &[
+                    ("- ", DIFF_REMOVED),
+                    ("[breaking]", DIFF_BREAKING),
+                    (" ", NONE),
+                ],
+                DIFF_REMOVED,
+            ),
+            LineKind::Before => (&[("  before: ", DIFF_REMOVED)], NONE),
+            LineKind::After => (&[("  after:  ", DIFF_ADDED)], NONE),
+            LineKind::Added => (&[("+ ", DIFF_ADDED)], DIFF_ADDED),
+            LineKind::Blank => (&[], NONE),
+        }
+    }
+}
+
+impl ApiDiff {
+    pub fn new(old: &PkgKey, new: &PkgKey, diff: PublicApiDiff) -> Self {
+        let title = xformat!(
+            " API Diff: {} v{} => v{} ",
+            new.name(),
+            old.ver_str(),
+            new.ver_str()
+        );
+        let counts = [diff.removed.len(), diff.changed.len(), diff.added.len()];
+        ApiDiff {
+            inner: Scroll {
+                lines: DiffLines::new(&diff),
+                ..Default::default()
+            },
+            title,
+            counts,
+        }
+    }
+
+    pub fn scroll_text(&mut self) -> &mut Scroll<DiffLines> {
+        &mut self.inner
+    }
+}
+
+impl PopupContent for ApiDiff {
+    /// The diff takes the whole center area.
+    const SIZE: [u16; 2] = [100, 100];
+
+    fn set_inner(&mut self, inner: Rect) {
+        self.inner.area = inner;
+    }
+
+    fn render(&self, border: &Surround, buf: &mut Buffer) {
+        border.render_only_top_left_text(buf, &self.title, 0);
+
+        let text = &self.inner;
+        if let Some(lines) = text.visible_lines() {
+            let Rect {
+                x, mut y, width, ..
+            } = text.area;
+            let width = width as usize;
+            if text.get_line_of_current_cursor().is_some() {
+                text.highlight_current_line(buf, |cell| {
+                    cell.set_bg(BG_CURSOR_LINE);
+                });
+            }
+            for line in lines {
+                let (label, style) = line.label();
+                let styled = label.iter().copied().chain([(line.text.as_str(), style)]);
+                render_line(styled, buf, x, y, width);
+                y += 1;
+            }
+        }
+
+        let [removed, changed, added] = self.counts;
+        let text = xformat!(" Removed {removed} / Changed {changed} / Added {added} ");
+        border.render_only_bottom_right_text(buf, &text);
+    }
+}
+
+#[cfg(test)]
+mod tests;
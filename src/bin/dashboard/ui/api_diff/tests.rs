@@ -0,0 +1,66 @@
+use super::DiffLines;
+use crate::color::DIFF_BREAKING;
+
+/// Plain lines with labels put before texts.
+fn plain(lines: &DiffLines) -> Vec<String> {
+    let line = |line: &super::DiffLine| {
+        let (label, _) = line.label();
+        let label = label.iter().map(|(text, _)| *text);
+        label.chain([line.text.as_str()]).collect()
+    };
+    lines.iter().map(line).collect()
+}
+
+fn is_breaking(lines: &DiffLines) -> Vec<bool> {
+    let breaking = |line: &super::DiffLine| line.label().0.contains(&("[breaking]", DIFF_BREAKING));
+    lines.iter().map(breaking).collect()
+}
+
+#[test]
+fn label_diff_lines() {
+    let removed = ["pub fn a::removed()", "pub struct a::Old"];
+    let changed = [["pub fn a::f()", "pub fn a::f(x: u8)"]];
+    let added = ["pub fn a::added()"];
+    let lines = DiffLines::from_items(&removed, changed.iter().map(|[o, n]| [o, n]), &added);
+    assert_eq!(
+        plain(&lines),
+        [
+            "Removed (2)",
+            "- [breaking] pub fn a::removed()",
+            "- [breaking] pub struct a::Old",
+            "",
+            "Changed (1)",
+            "  before: pub fn a::f()",
+            "  after:  pub fn a::f(x: u8)",
+            "",
+            "Added (1)",
+            "+ pub fn a::added()",
+        ]
+    );
+    // only removed items are tagged as breaking changes
+    let breaking = is_breaking(&lines);
+    let expected = [
+        false, true, true, false, false, false, false, false, false, false,
+    ];
+    assert_eq!(breaking, expected);
+}
+
+#[test]
+fn label_partial_diff() {
+    let none: [&str; 0] = [];
+    let no_change = || none.iter().map(|item| [item, item]);
+
+    let lines = DiffLines::from_items(&none, no_change(), &none);
+    assert_eq!(plain(&lines), ["No change in public API."]);
+    assert_eq!(is_breaking(&lines), [false]);
+
+    let lines = DiffLines::from_items(&none, no_change(), &["pub fn a::added()"]);
+    assert_eq!(plain(&lines), ["Added (1)", "+ pub fn a::added()"]);
+    assert!(!is_breaking(&lines).contains(&true));
+
+    let lines = DiffLines::from_items(&["pub fn a::removed()"], no_change(), &none);
+    assert_eq!(
+        plain(&lines),
+        ["Removed (1)", "- [breaking] pub fn a::removed()", ""]
+    );
+}
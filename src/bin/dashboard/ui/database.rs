@@ -2,13 +2,16 @@ mod cache;
 
 use self::cache::{Cache, CacheID, Count, SortKind};
 use crate::{
-    color::BG_CURSOR_LINE,
-    database::{CachedDocInfo, DataBase, Features, PkgKey, PkgWithFeatures},
+    color::{BG_CURSOR_LINE, DIFF_BASE, PROJECT_DEP},
+    database::{CachedDocInfo, DataBase, Features, LoadedDocs, PkgKey, PkgWithFeatures},
     event::Sender,
     fuzzy::Fuzzy,
+    local_registry::SysrootDoc,
     ui::{render_line, Scroll, Surround},
 };
-use ratatui::prelude::{Buffer, Rect};
+use ratatui::prelude::{Buffer, Rect, Style};
+use semver::Version;
+use std::{mem, path::PathBuf};
 use term_rustdoc::{tree::CrateDoc, util::xformat};
 
 #[derive(Default)]
@@ -34,6 +37,12 @@ impl std::ops::Deref for PkgDocs {
 pub struct DataBaseUI {
     inner: Scroll<PkgDocs>,
     border: Surround,
+    /// The doc marked as one side of API diff.
+    diff_base: Option<PkgKey>,
+    /// Dependencies of the current project, which are shown first as a group.
+    project_deps: Vec<PkgKey>,
+    /// Caches replaced by the docs being rebuilt, which are restored if rebuilding fails.
+    replaced: Vec<Cache>,
 }
 
 impl DataBaseUI {
@@ -116,22 +125,64 @@ impl DataBaseUI {
     /// In this case, this method will change its status.
     pub fn compile_doc(&mut self, pkg: PkgWithFeatures) {
         if let Some(pkg_key) = self.pkg_docs().db.compile_doc(pkg) {
-            let caches = &mut self.pkg_docs().caches;
-            if let Some(old) = caches.iter_mut().find(|cache| **cache == pkg_key) {
-                *old = Cache::new_being_cached(pkg_key);
-            } else {
-                let id = CacheID(caches.len());
-                caches.push(Cache::new_being_cached(pkg_key));
-                self.pkg_docs().indices.push(id);
+            self.being_cached(pkg_key);
+        }
+    }
+
+    fn being_cached(&mut self, pkg_key: PkgKey) {
+        let caches = &mut self.pkg_docs().caches;
+        if let Some(old) = caches.iter_mut().find(|cache| **cache == pkg_key) {
+            let old = mem::replace(old, Cache::new_being_cached(pkg_key));
+            if old.loadable() {
+                self.replaced.push(old);
+            }
+        } else {
+            let id = CacheID(caches.len());
+            caches.push(Cache::new_being_cached(pkg_key));
+            self.pkg_docs().indices.push(id);
+        }
+        self.sort_caches();
+    }
+
+    /// Import json docs of std crates from sysroot. Like other docs, they're cached by
+    /// name and version, thus a doc from the same nightly is replaced.
+    pub fn import_sysroot_docs(&mut self, docs: Vec<SysrootDoc>) {
+        for doc in docs {
+            if let Some(pkg_key) = self.pkg_docs().db.import_doc(doc) {
+                self.being_cached(pkg_key);
             }
-            self.sort_caches();
         }
     }
 
+    /// Resolve project dependencies in background, which are received by `compile_project_deps`.
+    pub fn resolve_project_deps(&self, manifest: Option<PathBuf>) {
+        self.inner.lines.db.project_deps(manifest);
+    }
+
+    /// Compile docs for project dependencies that are not cached yet.
+    pub fn compile_project_deps(&mut self, deps: Vec<PkgWithFeatures>) {
+        self.project_deps.clear();
+        for pkg in deps {
+            let key = PkgKey::new(pkg.info.to_name_ver(), pkg.features.clone());
+            if !self.pkg_docs().caches.iter().any(|cache| *cache == key) {
+                self.compile_doc(pkg);
+            }
+            self.project_deps.push(key);
+        }
+        info!("{} docs of project dependencies", self.project_deps.len());
+        self.sort_caches();
+    }
+
     /// Sort the Cache vec because the inner states have changed.
     fn sort_caches(&mut self) {
         let kind = self.pkg_docs().caches_sort;
-        self.pkg_docs().caches.sort_unstable_by(kind.cmp_fn());
+        let deps = &self.project_deps;
+        let caches = &mut self.inner.lines.caches;
+        caches.sort_unstable_by(kind.cmp_fn());
+        if !deps.is_empty() {
+            // project dependencies come first as a group in the same sort order
+            caches.sort_by_key(|cache| !deps.iter().any(|dep| cache == dep));
+        }
     }
 
     pub fn switch_sort(&mut self) {
@@ -197,8 +248,21 @@ impl DataBaseUI {
             let num = xformat!("{start:02}. ");
             let [(kind, style_kind), (name, style_name), (ver, style_ver), (feat, style_feat)] =
                 pkgs[id.0].line();
+            let is_base = self
+                .diff_base
+                .as_ref()
+                .is_some_and(|base| pkgs[id.0] == *base);
+            let is_dep = self.project_deps.iter().any(|dep| pkgs[id.0] == *dep);
+            let (mark, style_mark) = if is_base {
+                ("[Diff]", DIFF_BASE)
+            } else if is_dep {
+                ("[Dep]", PROJECT_DEP)
+            } else {
+                ("", Style::new())
+            };
             let line = [
                 (kind, style_kind),
+                (mark, style_mark),
                 (" ", style_kind),
                 (&*num, style_name),
                 (name, style_name),
@@ -240,8 +304,31 @@ impl DataBaseUI {
         }
     }
 
+    /// Load a cached doc of the crate to follow an item in it. See [`follow_rank`] for the
+    /// preferred one. Returns false if none is cached.
+    pub fn load_crate(&mut self, krate: &str) -> bool {
+        let deps = &self.project_deps;
+        let caches = &mut self.inner.lines.caches;
+        let found = caches
+            .iter_mut()
+            .filter(|cache| cache.loadable() && cache.name().replace('-', "_") == krate)
+            .max_by_key(|cache| follow_rank(cache.pkg_key(), cache.is_loaded(), deps));
+        match found {
+            Some(cache) => {
+                cache.load_doc(&self.inner.lines.db);
+                self.sort_caches();
+                true
+            }
+            None => {
+                warn!("The doc of `{krate}` is not cached, thus can't follow items in it.");
+                false
+            }
+        }
+    }
+
     pub fn receive_compiled_doc(&mut self, info: CachedDocInfo) {
         let key = &info.pkg;
+        self.replaced.retain(|cache| cache != key);
         let caches = &mut self.pkg_docs().caches;
         if let Some(cache) = caches.iter_mut().find(|cache| cache.is_in_progress(key)) {
             *cache = Cache::new_unloaded(info);
@@ -254,6 +341,26 @@ impl DataBaseUI {
         self.sort_caches();
     }
 
+    /// Restore the previous cache of the doc being rebuilt, or remove the doc being compiled
+    /// because the compilation or caching fails.
+    pub fn receive_failed_doc(&mut self, key: &PkgKey) {
+        let previous = self.replaced.iter().position(|cache| cache == key);
+        let previous = previous.map(|pos| self.replaced.swap_remove(pos));
+        let caches = &mut self.pkg_docs().caches;
+        let Some(pos) = caches.iter().position(|cache| cache.is_in_progress(key)) else {
+            return;
+        };
+        if let Some(previous) = previous {
+            caches[pos] = previous;
+            self.sort_caches();
+            return;
+        }
+        caches.remove(pos);
+        // indices must match the caches length
+        self.clear_and_reset();
+        self.sort_caches();
+    }
+
     pub fn is_empty(&self) -> bool {
         self.inner.all_lines().is_empty()
     }
@@ -267,6 +374,60 @@ impl DataBaseUI {
         iter.find_map(|cache| cache.get_loaded_doc(key))
     }
 
+    /// The local pkg dir where the doc was compiled, read from the doc db.
+    pub fn pkg_dir(&self, key: &PkgKey) -> Option<PathBuf> {
+        let info = self.inner.lines.caches.iter().find_map(|cache| {
+            let info = cache.info()?;
+            (info.pkg == *key).then_some(info)
+        })?;
+        match info.load_pkg_info_features() {
+            Ok(pkg) => Some(pkg.info.path().to_owned()),
+            Err(err) => {
+                error!("Failed to read PkgInfo for {key:?}:\n{err}");
+                None
+            }
+        }
+    }
+
+    /// All loaded docs with their keys.
+    pub fn loaded_docs(&self) -> LoadedDocs {
+        let caches = self.inner.lines.caches.iter();
+        caches
+            .filter_map(|cache| cache.loaded_doc())
+            .map(|(key, doc)| (key.clone(), doc))
+            .collect()
+    }
+
+    /// Mark the doc under cursor for API diff. Once two docs of the same crate are marked,
+    /// their public API are diffed in background, and sent back via `Event::ApiDiff`.
+    pub fn mark_for_diff(&mut self) -> Option<()> {
+        let idx = self.inner.get_line_of_current_cursor()?.0;
+        let caches = &self.inner.lines.caches;
+        let Some(info) = caches[idx].info() else {
+            error!("The doc is being compiled, thus can't be diffed.");
+            return None;
+        };
+        let base = match self.diff_base.take() {
+            // unmark the base
+            Some(base) if base == info.pkg => return None,
+            Some(base) if base.name() == info.pkg.name() => base,
+            _ => {
+                info!("Marked {:?} for API diff.", info.pkg);
+                self.diff_base = Some(info.pkg.clone());
+                return None;
+            }
+        };
+        let base = caches
+            .iter()
+            .find(|cache| cache.info().is_some_and(|i| i.pkg == base))?;
+        let [old, new] = old_and_new(
+            (base.info()?, base.version()),
+            (info, caches[idx].version()),
+        );
+        self.inner.lines.db.diff_api(old, new);
+        Some(())
+    }
+
     pub fn get_current_pkg(&self) -> Option<(&str, &str, &Features)> {
         if let Some(idx) = self.inner.get_line_of_current_cursor().map(|id| id.0) {
             if let Some(cache) = self.inner.lines.caches.get(idx) {
@@ -276,3 +437,28 @@ impl DataBaseUI {
         None
     }
 }
+
+/// The sort key of a cached doc to follow items of its crate in. The version recorded for
+/// project dependencies wins even if another version is loaded, because items are likely
+/// to be followed from the project. Then loaded docs and higher versions are preferred.
+///
+/// Features are not compared, since items hardly differ in path between features.
+fn follow_rank(key: &PkgKey, loaded: bool, deps: &[PkgKey]) -> (bool, bool, Version) {
+    let is_dep = deps
+        .iter()
+        .any(|dep| dep.name() == key.name() && dep.ver_str() == key.ver_str());
+    (is_dep, loaded, key.version())
+}
+
+/// Order the diff base and the doc marked later by version. The base is the old one
+/// if versions are the same, e.g. the docs differ only in features.
+fn old_and_new<T>(base: (T, &Version), marked: (T, &Version)) -> [T; 2] {
+    if base.1 <= marked.1 {
+        [base.0, marked.0]
+    } else {
+        [marked.0, base.0]
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -22,11 +22,20 @@ pub struct Cache {
     ver: Version,
 }
 
+/// Features of the pkg, prefixed with `[path]` for a pkg added via local path.
+fn features_text(pkg: &PkgKey) -> XString {
+    if pkg.is_path() {
+        xformat!("[path] {:?}", pkg.features())
+    } else {
+        xformat!("{:?}", pkg.features())
+    }
+}
+
 impl Cache {
     pub fn new_being_cached(pkg_key: PkgKey) -> Cache {
         Cache {
             ver: pkg_key.version(),
-            features: xformat!("{:?}", pkg_key.features()),
+            features: features_text(&pkg_key),
             inner: CacheInner::BeingCached(pkg_key, SystemTime::now()),
         }
     }
@@ -34,7 +43,7 @@ impl Cache {
     pub fn new_unloaded(info: CachedDocInfo) -> Cache {
         Cache {
             ver: info.pkg.version(),
-            features: xformat!("{:?}", info.pkg.features()),
+            features: features_text(&info.pkg),
             inner: CacheInner::Unloaded(info),
         }
     }
@@ -48,6 +57,10 @@ impl Cache {
         matches!(&self.inner, CacheInner::BeingCached(pkg, _) if pkg == key)
     }
 
+    pub fn is_loaded(&self) -> bool {
+        matches!(self.inner, CacheInner::Loaded(_))
+    }
+
     pub fn loadable(&self) -> bool {
         matches!(self.inner, CacheInner::Unloaded(_) | CacheInner::Loaded(_))
     }
@@ -123,6 +136,19 @@ impl Cache {
         *self = old;
     }
 
+    /// Info of a cached doc, which doesn't exist for the doc being compiled.
+    pub fn info(&self) -> Option<&CachedDocInfo> {
+        match &self.inner {
+            CacheInner::Loaded(loaded) => Some(&loaded.info),
+            CacheInner::Unloaded(info) => Some(info),
+            CacheInner::BeingCached(_, _) => None,
+        }
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.ver
+    }
+
     pub fn get_loaded_doc(&self, key: &PkgKey) -> Option<CrateDoc> {
         match &self.inner {
             CacheInner::Loaded(loaded) if loaded.info.pkg == *key => Some(loaded.doc.clone()),
@@ -130,6 +156,13 @@ impl Cache {
         }
     }
 
+    pub fn loaded_doc(&self) -> Option<(&PkgKey, CrateDoc)> {
+        match &self.inner {
+            CacheInner::Loaded(loaded) => Some((&loaded.info.pkg, loaded.doc.clone())),
+            _ => None,
+        }
+    }
+
     pub fn downgrade(&mut self) -> Option<Box<PkgKey>> {
         let mut key = None;
         let mut old = mem::replace(self, Cache::empty_state());
@@ -153,7 +186,7 @@ impl Cache {
         ]
     }
 
-    fn pkg_key(&self) -> &PkgKey {
+    pub fn pkg_key(&self) -> &PkgKey {
         self.inner.pkg_key()
     }
 
@@ -0,0 +1,56 @@
+use super::{follow_rank, old_and_new};
+use crate::{
+    database::{Features, PkgKey},
+    local_registry::PkgNameVersion,
+};
+
+fn key(ver: &str, features: Features) -> PkgKey {
+    PkgKey::new(PkgNameVersion::new("serde", ver), features)
+}
+
+/// The version of the doc to follow items in from the cached docs and whether they're loaded.
+fn followed(cached: &[(&str, bool)], deps: &[PkgKey]) -> String {
+    let keys = cached
+        .iter()
+        .map(|&(ver, loaded)| (key(ver, Features::Default), loaded));
+    let rank = |(key, loaded): &(PkgKey, bool)| follow_rank(key, *loaded, deps);
+    keys.max_by_key(rank).unwrap().0.ver_str().to_owned()
+}
+
+#[test]
+fn follow_project_dep_version() {
+    let cached = [("1.0.190", false), ("1.0.197", false), ("0.9.15", true)];
+    // without project deps, a loaded doc is preferred over higher versions
+    assert_eq!(followed(&cached, &[]), "0.9.15");
+    assert_eq!(followed(&cached[..2], &[]), "1.0.197");
+
+    // the dep version wins over a loaded doc and higher versions, whatever the features are
+    let deps = [key("1.0.190", Features::All)];
+    assert_eq!(followed(&cached, &deps), "1.0.190");
+
+    // a dep whose version isn't cached makes no difference
+    let deps = [key("1.0.100", Features::Default)];
+    assert_eq!(followed(&cached, &deps), "0.9.15");
+}
+
+#[test]
+fn diff_old_and_new_by_version() {
+    let order = |base: &'static str, marked: &'static str| {
+        let ver = |v: &str| v.parse::<semver::Version>().unwrap();
+        let [base_ver, marked_ver] = [ver(base), ver(marked)];
+        old_and_new((base, &base_ver), (marked, &marked_ver))
+    };
+    // the base marked first can be either the old or the new one
+    assert_eq!(order("1.0.190", "1.0.197"), ["1.0.190", "1.0.197"]);
+    assert_eq!(order("1.0.197", "1.0.190"), ["1.0.190", "1.0.197"]);
+    // versions are compared by semver instead of strings
+    assert_eq!(order("0.10.0", "0.9.1"), ["0.9.1", "0.10.0"]);
+    assert_eq!(order("1.0.0", "1.0.0-alpha.2"), ["1.0.0-alpha.2", "1.0.0"]);
+    // the base is the old one for the same version with different features
+    let same = |base, marked| {
+        let ver = semver::Version::new(1, 0, 0);
+        old_and_new((base, &ver), (marked, &ver))
+    };
+    assert_eq!(same("default", "all"), ["default", "all"]);
+    assert_eq!(same("all", "default"), ["all", "default"]);
+}
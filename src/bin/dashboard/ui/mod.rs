@@ -1,3 +1,4 @@
+mod api_diff;
 mod database;
 mod registry;
 mod search;
@@ -5,21 +6,24 @@ mod ver_feat_toml;
 mod version_features;
 
 use self::{
-    database::DataBaseUI, registry::Registry, search::Search, ver_feat_toml::PkgToml,
-    version_features::VersionFeatures,
+    api_diff::ApiDiff, database::DataBaseUI, registry::Registry, search::Search,
+    ver_feat_toml::PkgToml, version_features::VersionFeatures,
 };
 use crate::{
-    database::{CachedDocInfo, PkgKey},
+    database::{CachedDocInfo, LoadedDocs, PkgKey, PkgWithFeatures},
     event::Sender,
     frame::centered_rect,
     fuzzy::Fuzzy,
-    ui::{ScrollOffset, Scrollable, Surround},
+    local_registry::{sysroot_docs, PkgInfo},
+    ui::{Popup, PopupClick, ScrollOffset, Scrollable, Surround},
 };
 use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use public_api::diff::PublicApiDiff;
 use ratatui::{
     prelude::{Buffer, Constraint, Layout, Rect, Widget},
     widgets::{Block, Borders},
 };
+use std::path::PathBuf;
 use term_rustdoc::tree::CrateDoc;
 
 #[derive(Default)]
@@ -29,6 +33,9 @@ pub struct UI {
     registry: Registry,
     pkg_toml: PkgToml,
     ver_feat: VersionFeatures,
+    api_diff: Popup<ApiDiff>,
+    /// Cargo.toml of the current project. None means the project in current dir.
+    project: Option<PathBuf>,
     area: Area,
 }
 
@@ -43,12 +50,20 @@ impl UI {
             self.registry.set_area(registry);
         }
         self.ver_feat.update_area(self.center());
+        self.api_diff.update_area(self.center());
     }
 
-    pub fn new(full: Rect, fuzzy: Fuzzy, sender: Sender) -> Self {
+    pub fn new(
+        full: Rect,
+        fuzzy: Fuzzy,
+        sender: Sender,
+        project: Option<PathBuf>,
+        workspace: &[PkgInfo],
+    ) -> Self {
         let mut ui = UI {
+            project,
             database: DataBaseUI::init(sender, fuzzy.clone()),
-            registry: Registry::new_local(fuzzy),
+            registry: Registry::new_local(fuzzy, workspace),
             ..Default::default()
         };
         ui.switch_panel(); // switch to database if caches are not empty
@@ -63,6 +78,7 @@ impl UI {
             Panel::Database => self.database.scroll_text() as &mut dyn Scrollable,
             Panel::LocalRegistry => self.registry.scroll_text(),
             Panel::VersionFeatures => &mut self.ver_feat,
+            Panel::ApiDiff => self.api_diff.scroll_text(),
         }
     }
 
@@ -78,7 +94,7 @@ impl UI {
                     self.pkg_toml.update_toml(name, ver, &Default::default());
                 }
             }
-            Panel::VersionFeatures => (),
+            Panel::VersionFeatures | Panel::ApiDiff => (),
         };
     }
 
@@ -138,6 +154,7 @@ impl UI {
             Panel::VersionFeatures => {
                 self.ver_feat.toggle_features();
             }
+            Panel::ApiDiff => (),
         }
     }
 
@@ -155,6 +172,7 @@ impl UI {
                     self.comfirm_features_and_compile_doc();
                 }
             }
+            Panel::ApiDiff => (),
             _ => self.push_char(ch),
         };
         self.update_pkg_toml();
@@ -164,6 +182,10 @@ impl UI {
         self.database.receive_compiled_doc(info);
     }
 
+    pub fn receive_failed_doc(&mut self, key: &PkgKey) {
+        self.database.receive_failed_doc(key);
+    }
+
     pub fn switch_panel(&mut self) {
         if self.database.is_empty() {
             self.area.current = Panel::LocalRegistry;
@@ -173,13 +195,54 @@ impl UI {
             Panel::Database => self.area.current = Panel::LocalRegistry,
             Panel::LocalRegistry => self.area.current = Panel::Database,
             Panel::VersionFeatures => self.ver_feat.switch_panel(),
+            Panel::ApiDiff => (),
         };
         self.update_pkg_toml();
     }
 
-    pub fn close_ver_feat(&mut self) {
-        if matches!(self.area.current, Panel::VersionFeatures) {
-            self.area.current = Panel::LocalRegistry;
+    pub fn close_popup(&mut self) {
+        match self.area.current {
+            Panel::VersionFeatures => self.area.current = Panel::LocalRegistry,
+            Panel::ApiDiff => self.area.current = Panel::Database,
+            _ => (),
+        }
+    }
+
+    /// Mark the selected doc in database panel for API diff, and diff them
+    /// once two docs of the same crate are marked.
+    pub fn api_diff(&mut self) {
+        if let Panel::Database = self.area.current {
+            self.database.mark_for_diff();
+        }
+    }
+
+    /// Pop up the diff of public API computed in background.
+    pub fn receive_api_diff(&mut self, old: &PkgKey, new: &PkgKey, diff: PublicApiDiff) {
+        self.api_diff = Popup::new(ApiDiff::new(old, new, diff), self.center());
+        self.area.current = Panel::ApiDiff;
+    }
+
+    /// Resolve dependencies of the current project in background.
+    pub fn compile_project_deps(&mut self) {
+        self.database.resolve_project_deps(self.project.clone());
+    }
+
+    /// Compile docs for the resolved dependencies, and show them in database panel.
+    pub fn receive_project_deps(&mut self, deps: Vec<PkgWithFeatures>) {
+        self.database.compile_project_deps(deps);
+        self.area.current = Panel::Database;
+        self.update_pkg_toml();
+    }
+
+    /// Import json docs of std, core, alloc and proc_macro from the nightly toolchain.
+    pub fn import_sysroot_docs(&mut self) {
+        match sysroot_docs() {
+            Ok(docs) => {
+                self.database.import_sysroot_docs(docs);
+                self.area.current = Panel::Database;
+                self.update_pkg_toml();
+            }
+            Err(err) => error!("{err}"),
         }
     }
 
@@ -193,6 +256,20 @@ impl UI {
         self.database.get_loaded_doc(key)
     }
 
+    pub fn loaded_docs(&self) -> LoadedDocs {
+        self.database.loaded_docs()
+    }
+
+    pub fn pkg_dir(&self, key: &PkgKey) -> Option<PathBuf> {
+        self.database.pkg_dir(key)
+    }
+
+    /// Load the cached doc of the crate whose item is followed from a Page.
+    /// Returns false if the crate is not cached.
+    pub fn load_crate(&mut self, krate: &str) -> bool {
+        self.database.load_crate(krate)
+    }
+
     /// the full screen area
     pub fn get_full_area(&self) -> Rect {
         self.area.full
@@ -214,6 +291,14 @@ impl UI {
                     return true;
                 }
 
+                if matches!(self.area.current, Panel::ApiDiff) {
+                    if let PopupClick::Outside = self.api_diff.click(position.into()) {
+                        // left click out of range will back to Database panel
+                        self.area.current = Panel::Database;
+                    }
+                    return false;
+                }
+
                 if matches!(self.area.current, Panel::VersionFeatures) {
                     if self.ver_feat.contains(position) {
                         self.ver_feat.respond_to_left_click(position);
@@ -289,6 +374,10 @@ impl Widget for &mut UI {
                 self.ver_feat.render(buf);
                 return;
             }
+            Panel::ApiDiff => {
+                self.api_diff.render(buf);
+                return;
+            }
         };
         self.search.render(buf);
         self.database.render(buf, db);
@@ -310,6 +399,7 @@ enum Panel {
     #[default]
     LocalRegistry,
     VersionFeatures,
+    ApiDiff,
 }
 
 impl Area {
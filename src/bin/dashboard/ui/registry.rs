@@ -1,5 +1,5 @@
 use crate::{
-    color::BG_CURSOR_LINE,
+    color::{BG_CURSOR_LINE, PKG_FEATURES},
     fuzzy::Fuzzy,
     local_registry::{LocalRegistry, PkgInfo},
     ui::{render_line, LineState, Scroll, Surround},
@@ -20,19 +20,20 @@ pub(super) struct PkgLists {
 }
 
 impl PkgLists {
-    fn new_local(fuzzy: Fuzzy) -> Self {
-        let [registry, all] = match LocalRegistry::all_pkgs_with_latest_and_all_versions() {
-            Ok(registry) => registry,
-            Err(err) => {
+    /// Pkgs in local registry, and workspace members from local paths if any.
+    fn new_local(fuzzy: Fuzzy, workspace: &[PkgInfo]) -> Self {
+        let [mut registry, mut all] = LocalRegistry::all_pkgs_with_latest_and_all_versions()
+            .unwrap_or_else(|err| {
                 error!("{err}");
-                return PkgLists::default();
-            }
-        };
+                Default::default()
+            });
         info!(
             "Found {} latest pkgs under {}",
             registry.len(),
             registry.registry_src_path().display()
         );
+        registry.extend_front(workspace);
+        all.extend_sorted(workspace);
         PkgLists {
             filter: (0..registry.len()).map(LocalPkgsIndex).collect(),
             local: registry,
@@ -129,10 +130,10 @@ pub struct Registry {
 }
 
 impl Registry {
-    pub fn new_local(fuzzy: Fuzzy) -> Self {
+    pub fn new_local(fuzzy: Fuzzy, workspace: &[PkgInfo]) -> Self {
         Registry {
             inner: Scroll {
-                lines: PkgLists::new_local(fuzzy),
+                lines: PkgLists::new_local(fuzzy, workspace),
                 ..Default::default()
             },
             ..Default::default()
@@ -167,21 +168,25 @@ impl Registry {
             }
         }
 
-        let mut start = text.start + 1;
-        for line in lines {
+        for (start, line) in (text.start + 1..).zip(lines) {
             let pkg = &pkgs[line.0];
             let [(name, style_name), (ver, style_ver)] = pkg.styled_name_ver();
             let num = xformat!("{start:02}. ");
+            let path = if pkgs.is_in_registry(pkg) {
+                ""
+            } else {
+                " [path]"
+            };
             // render name and version, but with extra info and styles
             let line = [
                 (&*num, style_name),
                 (name, style_name),
                 (" v", style_ver),
                 (ver, style_ver),
+                (path, PKG_FEATURES),
             ];
             render_line(line, buf, x, y, width);
             y += 1;
-            start += 1;
         }
 
         // write the match result to the border bottom line
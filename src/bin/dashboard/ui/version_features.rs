@@ -281,6 +281,6 @@ impl LineState for PkgInfo {
     }
 
     fn is_identical(&self, state: &Self::State) -> bool {
-        state.name_ver() == [self.name(), self.ver()]
+        state.name_ver() == [self.name(), self.ver()] && state.is_path() == self.is_path()
     }
 }
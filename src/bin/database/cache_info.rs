@@ -13,12 +13,19 @@ use crate::{
 use serde::{Deserialize, Serialize};
 use std::{
     fs,
+    io::Write,
     path::{Path, PathBuf},
     time::{Instant, SystemTime},
 };
 use term_rustdoc::tree::CrateDoc;
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The layout version of parsed docs in `host-parsed` table.
+///
+/// Bump it when the parsed doc changes in layout, so docs cached by older versions are
+/// parsed again from `host-json` instead of failing or decoding into garbage.
+const PARSED_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CachedDocInfo {
     pub pkg: PkgKey,
     /// file name for doc db (with parent path included); usually is `self.pkg-self.ver.db`.
@@ -53,12 +60,63 @@ impl CachedDocInfo {
         let now = Instant::now();
         debug!(?self.pkg, "Start to load");
         let db = redb::Database::open(&self.db_file)?;
-        let bytes = read_from_doc_db::<PkgKey, Vec<u8>>(&db, "host-parsed", &self.pkg)?;
-        let doc = util::decode_with_xz(&bytes)?;
+        let doc = match self.load_parsed(&db) {
+            Ok(doc) => doc,
+            Err(err) => {
+                warn!(?self.pkg, "Parse the doc again from raw json, because {err}");
+                self.reparse(&db)?
+            }
+        };
         info!(?self.pkg, "Loaded in {:.2}s", now.elapsed().as_secs_f32());
         Ok(doc)
     }
 
+    fn load_parsed(&self, db: &redb::Database) -> Result<CrateDoc> {
+        let version = read_from_doc_db::<PkgKey, u32>(db, "host-parsed-version", &self.pkg)
+            .unwrap_or_default();
+        if version != PARSED_VERSION {
+            return Err(err!(
+                "the parsed doc is in version {version}, but {PARSED_VERSION} is expected"
+            ));
+        }
+        let bytes = read_from_doc_db::<PkgKey, Vec<u8>>(db, "host-parsed", &self.pkg)?;
+        util::decode_with_xz(&bytes)
+    }
+
+    /// Parse the doc from `host-json` table, and overwrite the stale one in `host-parsed`.
+    fn reparse(&self, db: &redb::Database) -> Result<CrateDoc> {
+        let bytes = read_from_doc_db::<PkgKey, Vec<u8>>(db, "host-json", &self.pkg)?;
+        let json = util::xz_decode_on_bytes(&bytes)?;
+        let doc = CrateDoc::new(serde_json::from_slice(&json)?);
+        self.write_parsed(db, &doc)?;
+        Ok(doc)
+    }
+
+    fn write_parsed(&self, db: &redb::Database, doc: &CrateDoc) -> Result<()> {
+        self.write_to_db(db, "host-parsed", util::encode_with_xz(doc)?)?;
+        write_to_db::<PkgKey, u32>(db, "host-parsed-version", &self.pkg, &PARSED_VERSION)?;
+        info!(?self.pkg, "parsed data is succeefully saved");
+        Ok(())
+    }
+
+    /// Build the public API from the raw json doc in db, which is used to diff two docs.
+    pub fn load_public_api(&self) -> Result<public_api::PublicApi> {
+        let now = Instant::now();
+        let db = redb::Database::open(&self.db_file)?;
+        let bytes = read_from_doc_db::<PkgKey, Vec<u8>>(&db, "host-json", &self.pkg)?;
+        let json = util::xz_decode_on_bytes(&bytes)?;
+        // public_api only builds from a json file
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(&json)?;
+        let api = public_api::Builder::from_rustdoc_json(file.path())
+            .omit_blanket_impls(true)
+            .omit_auto_trait_impls(true)
+            .build()
+            .wrap_err_with(|| format!("Failed to build public API for {:?}", self.pkg))?;
+        info!(?self.pkg, "Built public API in {:.2}s", now.elapsed().as_secs_f32());
+        Ok(api)
+    }
+
     /// Get PkgInfo from db and use PkgWithFeatures to recompile the doc.
     ///
     /// FIXME: PkgInfo may contain invalid data because registry caches can be cleaned up.
@@ -100,8 +158,7 @@ impl CachedDocInfo {
         info!(?self.pkg, "raw json is succeefully saved");
 
         // write parsed doc into db
-        self.write_to_db(&db, "host-parsed", util::encode_with_xz(doc)?)?;
-        info!(?self.pkg, "parsed data is succeefully saved");
+        self.write_parsed(&db, &doc)?;
 
         // write to index.db
         self.write_self_to_db()?;
@@ -112,6 +169,7 @@ impl CachedDocInfo {
     /// * `host-pkg-info`: name, version, local pkg dir etc
     /// * `host-json`: raw json file generated by rustdoc
     /// * `host-parsed`: directly used in term-rustdoc to save json parsing
+    /// * `host-parsed-version`: [`PARSED_VERSION`] of the parsed doc
     fn write_to_db(&self, db: &redb::Database, name: &str, value: Vec<u8>) -> Result<()> {
         write_to_db::<PkgKey, Vec<u8>>(db, name, &self.pkg, &value)?;
         Ok(())
@@ -124,6 +182,27 @@ impl CachedDocInfo {
         Ok(())
     }
 
+    /// Remove the doc from its `pkg-version.db` and `index.db`.
+    ///
+    /// NOTE: the db file is shared by docs with different features, thus it's not deleted here.
+    pub fn remove(&self) -> Result<()> {
+        if self.db_file.exists() {
+            let db = redb::Database::create(&self.db_file)?;
+            for name in ["host-pkg-info", "host-json", "host-parsed"] {
+                remove_from_db::<PkgKey, Vec<u8>>(&db, name, &self.pkg)?;
+            }
+            remove_from_db::<PkgKey, u32>(&db, "host-parsed-version", &self.pkg)?;
+        }
+        let db = redb::Database::create(self.db_file.with_file_name("index.db"))?;
+        remove_from_db::<PkgKey, CachedDocInfo>(&db, "CachedDocInfo", &self.pkg)?;
+        info!(?self.pkg, "removed from db");
+        Ok(())
+    }
+
+    pub fn db_file(&self) -> &Path {
+        &self.db_file
+    }
+
     pub(super) fn meta_mut(&mut self) -> &mut DocMeta {
         &mut self.meta
     }
@@ -153,6 +232,21 @@ where
     Ok(())
 }
 
+fn remove_from_db<K, V>(db: &redb::Database, name: &str, key: &K::SelfType<'_>) -> Result<()>
+where
+    K: 'static + redb::RedbKey,
+    V: 'static + redb::RedbValue,
+{
+    let table = redb::TableDefinition::<K, V>::new(name);
+    let write_txn = db.begin_write()?;
+    {
+        let mut table = write_txn.open_table(table)?;
+        table.remove(key)?;
+    }
+    write_txn.commit()?;
+    Ok(())
+}
+
 fn read_from_doc_db<K, V>(db: &redb::Database, name: &str, key: &K) -> Result<V>
 where
     K: 'static + for<'a> redb::RedbKey<SelfType<'a> = K> + std::fmt::Debug,
@@ -16,3 +16,29 @@ pub enum Features {
     NoDefault,
     NoDefaultPlus(Box<[XString]>),
 }
+
+impl Features {
+    /// Features resolved by cargo for a dependency, where `default` is enabled explicitly.
+    pub fn resolved(features: &[XString]) -> Self {
+        let default = features.iter().any(|f| *f == "default");
+        let others: Box<[_]> = features
+            .iter()
+            .filter(|f| **f != "default")
+            .cloned()
+            .collect();
+        match (default, others.is_empty()) {
+            (true, true) => Features::Default,
+            (true, false) => Features::DefaultPlus(others),
+            (false, true) => Features::NoDefault,
+            (false, false) => Features::NoDefaultPlus(others),
+        }
+    }
+
+    /// Features given explicitly besides `default`. Empty for `Default`, `All` and `NoDefault`.
+    pub fn explicit(&self) -> &[XString] {
+        match self {
+            Features::DefaultPlus(features) | Features::NoDefaultPlus(features) => features,
+            _ => &[],
+        }
+    }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime};
 use term_rustdoc::util::XString;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub(super) struct DocMeta {
     /// the rustc/rustdoc/cargo version compiling the doc, gotten by `cargo +nightly -Vv`
     /// NOTE: only nightly toolchain is supported for now
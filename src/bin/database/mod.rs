@@ -8,10 +8,12 @@ use self::meta::DocMeta;
 use crate::{
     err,
     event::{Event, Sender},
+    local_registry::{sysroot_docs, SysrootDoc, SYSROOT_CRATES},
     Result,
 };
 use color_eyre::eyre::WrapErr;
-use std::path::PathBuf;
+use std::{path::PathBuf, rc::Rc};
+use term_rustdoc::tree::CrateDoc;
 
 pub use self::{
     cache_info::CachedDocInfo,
@@ -20,6 +22,9 @@ pub use self::{
     util::PkgWithFeatures,
 };
 
+/// Docs loaded in database, shared by all tabs instead of cloned for each Page.
+pub type LoadedDocs = Rc<[(PkgKey, CrateDoc)]>;
+
 #[derive(Default)]
 pub struct DataBase {
     /// [`dirs::data_local_dir`] + `term-rustdoc` folder
@@ -44,6 +49,22 @@ impl DataBase {
     }
 
     pub fn compile_doc(&self, pkg: PkgWithFeatures) -> Option<PkgKey> {
+        let name = pkg.info.name();
+        if SYSROOT_CRATES.contains(&name) {
+            // std docs are imported from the toolchain again instead of compiled
+            let found = sysroot_docs().map(|docs| docs.into_iter().find(|d| d.info.name() == name));
+            return match found {
+                Ok(Some(doc)) => self.import_doc(doc),
+                Ok(None) => {
+                    error!("The json doc of `{name}` is not found in sysroot");
+                    None
+                }
+                Err(err) => {
+                    error!("{err}");
+                    None
+                }
+            };
+        }
         let Some(parent) = self.dir.clone() else {
             error!("data_local_dir/term_rustdoc does not exist");
             return None;
@@ -55,6 +76,39 @@ impl DataBase {
         Some(util::build(sender, parent, pkg))
     }
 
+    /// Import a json doc from sysroot into db.
+    pub fn import_doc(&self, doc: SysrootDoc) -> Option<PkgKey> {
+        let Some(parent) = self.dir.clone() else {
+            error!("data_local_dir/term_rustdoc does not exist");
+            return None;
+        };
+        let Some(sender) = self.sender.clone() else {
+            error!("DataBase doesn't have a sender. This is a bug.");
+            return None;
+        };
+        Some(util::import(sender, parent, doc))
+    }
+
+    /// Diff public API of two docs in background.
+    pub fn diff_api(&self, old: &CachedDocInfo, new: &CachedDocInfo) {
+        let Some(sender) = self.sender.clone() else {
+            error!("DataBase doesn't have a sender. This is a bug.");
+            return;
+        };
+        info!(?old.pkg, ?new.pkg, "Start to diff public API");
+        util::diff(sender, old.clone(), new.clone());
+    }
+
+    /// Resolve dependencies of the project in background, because `cargo metadata` may
+    /// take a while to read the registry index.
+    pub fn project_deps(&self, manifest: Option<PathBuf>) {
+        let Some(sender) = self.sender.clone() else {
+            error!("DataBase doesn't have a sender. This is a bug.");
+            return;
+        };
+        util::project_deps(sender, manifest);
+    }
+
     pub fn all_caches(&self) -> Result<Vec<CachedDocInfo>> {
         use redb::ReadableTable;
         let dir = self
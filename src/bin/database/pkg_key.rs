@@ -20,11 +20,12 @@ pub struct PkgKey {
 impl fmt::Debug for PkgKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let [name, ver] = self.name_ver.name_ver();
+        let path = if self.is_path() { " [path]" } else { "" };
         let features = &self.features;
         if matches!(features, Features::Default) {
-            write!(f, "{name}_v{ver}")
+            write!(f, "{name}_v{ver}{path}")
         } else {
-            write!(f, "{name}_v{ver} [{features:?}]")
+            write!(f, "{name}_v{ver}{path} [{features:?}]")
         }
     }
 }
@@ -49,6 +50,11 @@ impl PkgKey {
         self.name_ver.ver_str()
     }
 
+    /// Added via local path instead of from the registry.
+    pub fn is_path(&self) -> bool {
+        self.name_ver.is_path()
+    }
+
     /// Parse the version. When the version can't be parsed, this will return a `0.0.0` version.
     pub fn version(&self) -> Version {
         self.ver_str()
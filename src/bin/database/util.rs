@@ -2,17 +2,21 @@ use super::{features::Features, PkgKey};
 use crate::{
     database::CachedDocInfo,
     event::{Event, Sender},
-    local_registry::PkgInfo,
+    local_registry::{project_dependencies, PkgInfo, SysrootDoc},
     Result,
 };
 use bincode::config;
 use bytesize::ByteSize;
+use public_api::diff::PublicApiDiff;
 use serde::{de::DeserializeOwned, Serialize};
-use std::{io::Write, path::PathBuf};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
 use xz2::write::{XzDecoder, XzEncoder};
 
 /// Pkg info and local dir that are used to build the doc.
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct PkgWithFeatures {
     pub features: Features,
     pub info: PkgInfo,
@@ -22,15 +26,14 @@ pub fn build(sender: Sender, db_dir: PathBuf, pkg: PkgWithFeatures) -> PkgKey {
     let in_progress = PkgKey::new(pkg.info.to_name_ver(), pkg.features.clone());
     rayon::spawn(move || {
         let cargo_toml = pkg.info.path().join("Cargo.toml");
+        let mut cache_info =
+            CachedDocInfo::new(pkg.info.to_name_ver(), pkg.features.clone(), db_dir);
+        let key = cache_info.pkg.clone();
+        let failed = |err: String| send_failed(&sender, &key, err);
         let dir = match tempfile::tempdir() {
             Ok(dir) => dir,
-            Err(err) => {
-                error!("Can't create a tempdir:\n{err}");
-                return;
-            }
+            Err(err) => return failed(format!("Can't create a tempdir:\n{err}")),
         };
-        let mut cache_info =
-            CachedDocInfo::new(pkg.info.to_name_ver(), pkg.features.clone(), db_dir);
         info!(?cache_info.pkg, "begin to compile the doc under {}", dir.path().display());
         let compile = rustdoc_json::Builder::default()
             .toolchain("nightly")
@@ -45,30 +48,91 @@ pub fn build(sender: Sender, db_dir: PathBuf, pkg: PkgWithFeatures) -> PkgKey {
             Features::NoDefaultPlus(f) => compile.no_default_features(true).features(f.iter()),
         }
         .build();
-        match built {
-            Ok(json_path) => {
-                let meta = cache_info.meta_mut();
-                meta.set_finished_duration();
-                let duration = meta.duration_as_secs();
-                info!(?cache_info.pkg, ?json_path, "succeefully compiled the doc in {duration:.2}s");
-                if let Err(err) = cache_info.save_doc(&json_path, pkg.info) {
-                    error!("{err}");
-                }
-                match sender.send(Event::DocCompiled(Box::new(cache_info))) {
-                    Ok(()) => (),
-                    Err(err) => {
-                        error!(
-                            "Failed to send `DocCompiled` event when CachedDocInfo is ready:\n{err}"
-                        )
-                    }
-                }
+        let json_path = match built {
+            Ok(json_path) => json_path,
+            Err(err) => {
+                return failed(format!(
+                    "Failed to compile {}:\n{err}",
+                    cargo_toml.display()
+                ))
             }
-            Err(err) => error!("Failed to compile {}:\n{err}", cargo_toml.display()),
-        }
+        };
+        let meta = cache_info.meta_mut();
+        meta.set_finished_duration();
+        let duration = meta.duration_as_secs();
+        info!(?cache_info.pkg, ?json_path, "succeefully compiled the doc in {duration:.2}s");
+        save(&sender, cache_info, &json_path, pkg.info);
     });
     in_progress
 }
 
+/// Import the json doc shipped by the toolchain, which needs no compilation.
+pub fn import(sender: Sender, db_dir: PathBuf, doc: SysrootDoc) -> PkgKey {
+    let cache_info = CachedDocInfo::new(doc.info.to_name_ver(), Features::Default, db_dir);
+    let in_progress = cache_info.pkg.clone();
+    rayon::spawn(move || {
+        info!(?cache_info.pkg, "begin to import the doc from {}", doc.json.display());
+        save(&sender, cache_info, &doc.json, doc.info);
+    });
+    in_progress
+}
+
+/// Diff public API of two docs, and notify UI with the result.
+pub fn diff(sender: Sender, old: CachedDocInfo, new: CachedDocInfo) {
+    rayon::spawn(move || {
+        let api = |info: &CachedDocInfo| {
+            info.load_public_api()
+                .map_err(|err| error!("Failed to diff API:\n{err}"))
+                .ok()
+        };
+        let (Some(old_api), Some(new_api)) = rayon::join(|| api(&old), || api(&new)) else {
+            return;
+        };
+        let diff = PublicApiDiff::between(old_api, new_api);
+        if let Err(err) = sender.send(Event::ApiDiff(Box::new((old.pkg, new.pkg, diff)))) {
+            error!("Failed to send `ApiDiff` event:\n{err}");
+        }
+    });
+}
+
+/// Resolve dependencies of the project, and notify UI with them.
+pub fn project_deps(sender: Sender, manifest: Option<PathBuf>) {
+    rayon::spawn(move || {
+        let deps = match project_dependencies(manifest.as_deref()) {
+            Ok(deps) => deps,
+            Err(err) => return error!("{err}"),
+        };
+        if let Err(err) = sender.send(Event::ProjectDeps(deps)) {
+            error!("Failed to send `ProjectDeps` event:\n{err}");
+        }
+    });
+}
+
+/// Save the json doc into db file, and notify UI with the result.
+fn save(sender: &Sender, cache_info: CachedDocInfo, json_path: &Path, info: PkgInfo) {
+    if let Err(err) = cache_info.save_doc(json_path, info) {
+        let key = &cache_info.pkg;
+        return send_failed(
+            sender,
+            key,
+            format!("Failed to save the doc for {key:?}:\n{err}"),
+        );
+    }
+    match sender.send(Event::DocCompiled(Box::new(cache_info))) {
+        Ok(()) => (),
+        Err(err) => {
+            error!("Failed to send `DocCompiled` event when CachedDocInfo is ready:\n{err}")
+        }
+    }
+}
+
+fn send_failed(sender: &Sender, key: &PkgKey, err: String) {
+    error!("{err}");
+    if let Err(err) = sender.send(Event::DocFailed(Box::new(key.clone()), err)) {
+        error!("Failed to send `DocFailed` event:\n{err}");
+    }
+}
+
 /// Write source data into db file.
 pub fn encode<T: Serialize>(t: T) -> Result<Vec<u8>> {
     Ok(bincode::serde::encode_to_vec(t, config::standard())?)
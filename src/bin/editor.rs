@@ -0,0 +1,60 @@
+use crate::{err, Result};
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Build the command to open the file at the line in the editor from `$VISUAL` or `$EDITOR`.
+///
+/// The env var can contain arguments like `code --wait`, which are split by whitespaces.
+pub fn command(file: &Path, line: usize) -> Result<Command> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(env::var_os)
+        .find(|var| !var.is_empty())
+        .ok_or_else(|| err!("Neither $VISUAL nor $EDITOR is set."))?;
+    let editor = editor.to_string_lossy();
+    let mut words = editor.split_whitespace();
+    let program = words
+        .next()
+        .ok_or_else(|| err!("The editor command is empty."))?;
+    let mut cmd = Command::new(program);
+    cmd.args(words).args(args(program, file, line));
+    info!(?cmd, "open the editor");
+    Ok(cmd)
+}
+
+/// Editors take the line number in different forms.
+fn args(program: &str, file: &Path, line: usize) -> Vec<OsString> {
+    let file_line = || {
+        let mut arg = file.as_os_str().to_owned();
+        arg.push(format!(":{line}"));
+        arg
+    };
+    let name = PathBuf::from(program);
+    let name = name
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    match &*name {
+        // VSCode and its variants
+        "code" | "code-insiders" | "codium" | "vscodium" => {
+            vec!["--goto".into(), file_line()]
+        }
+        "hx" | "helix" | "subl" | "zed" => vec![file_line()],
+        // vi, vim, nvim, emacs, emacsclient, nano, kak, micro etc
+        _ => vec![format!("+{line}").into(), file.into()],
+    }
+}
+
+#[test]
+fn editor_args() {
+    let file = Path::new("src/lib.rs");
+    let args = |program| args(program, file, 42);
+    assert_eq!(args("nvim"), ["+42", "src/lib.rs"]);
+    assert_eq!(args("/usr/bin/emacsclient"), ["+42", "src/lib.rs"]);
+    assert_eq!(args("hx"), ["src/lib.rs:42"]);
+    assert_eq!(args("code"), ["--goto", "src/lib.rs:42"]);
+}
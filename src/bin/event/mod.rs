@@ -1,12 +1,16 @@
 use crate::{
-    database::{CachedDocInfo, PkgKey},
+    database::{CachedDocInfo, PkgKey, PkgWithFeatures},
     Result,
 };
 use crossterm::event::{
     self, Event as CrosstermEvent, KeyEvent, MouseButton, MouseEvent, MouseEventKind,
 };
+use public_api::diff::PublicApiDiff;
 use std::{
-    sync::mpsc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
     thread,
     time::{Duration, Instant},
 };
@@ -24,10 +28,16 @@ pub enum Event {
     Resize(u16, u16),
     /// Pkg doc that's compiled and written into its db file.
     DocCompiled(Box<CachedDocInfo>),
+    /// Pkg doc that failed to compile or be written into its db file, with the error message.
+    DocFailed(Box<PkgKey>, String),
     /// Compiled and loaded doc for Page.
     CrateDoc(Box<PkgKey>),
     /// Downgraded doc which may or may not be the current one.
     Downgraded(Box<PkgKey>),
+    /// Diff of public API between the old and new docs.
+    ApiDiff(Box<(PkgKey, PkgKey, PublicApiDiff)>),
+    /// Dependencies of the current project resolved by `cargo metadata`.
+    ProjectDeps(Vec<PkgWithFeatures>),
 }
 
 pub type Sender = mpsc::Sender<Event>;
@@ -39,9 +49,12 @@ pub struct EventHandler {
     sender: mpsc::Sender<Event>,
     /// Event receiver channel.
     receiver: mpsc::Receiver<Event>,
+    /// Stop reading terminal events, e.g. when an editor takes over the terminal.
+    pause: Arc<AtomicBool>,
+    /// Notified once the handler thread is paused.
+    paused: mpsc::Receiver<()>,
 
     /// Event handler thread.
-    #[allow(dead_code)]
     handler: thread::JoinHandle<()>,
 }
 
@@ -50,11 +63,21 @@ impl EventHandler {
     pub fn new(timeout: u64) -> Self {
         let timeout = Duration::from_millis(timeout);
         let (sender, receiver) = mpsc::channel();
+        let pause = Arc::new(AtomicBool::new(false));
+        let (paused_sender, paused) = mpsc::channel();
         let handler = {
             let sender = sender.clone();
+            let pause = pause.clone();
             thread::spawn(move || {
                 let mut last_click = Instant::now();
                 loop {
+                    if pause.load(Ordering::Acquire) {
+                        paused_sender.send(()).expect("failed to notify pausing");
+                        while pause.load(Ordering::Acquire) {
+                            thread::park();
+                        }
+                        continue;
+                    }
                     if event::poll(timeout).expect("unable to poll for event") {
                         match event::read().expect("unable to read event") {
                             CrosstermEvent::Key(e) => {
@@ -92,10 +115,25 @@ impl EventHandler {
         Self {
             sender,
             receiver,
+            pause,
+            paused,
             handler,
         }
     }
 
+    /// Block until the handler thread stops reading terminal events.
+    /// This takes at most the timeout of polling.
+    pub fn pause(&self) -> Result<()> {
+        self.pause.store(true, Ordering::Release);
+        Ok(self.paused.recv()?)
+    }
+
+    /// Continue to read terminal events.
+    pub fn resume(&self) {
+        self.pause.store(false, Ordering::Release);
+        self.handler.thread().unpark();
+    }
+
     /// Receive the next event from the handler thread.
     ///
     /// This function will always block the current thread if
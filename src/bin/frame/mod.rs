@@ -1,19 +1,27 @@
 mod help;
+/// multiple Pages as tabs
+mod tabs;
 mod update;
 mod util;
 
 pub use self::util::centered_rect;
 
-use self::help::Help;
-use crate::{dashboard::DashBoard, page::Page};
+use self::{help::Help, tabs::Tabs};
+use crate::{
+    dashboard::DashBoard,
+    page::{ExternalJump, Location},
+};
 use ratatui::prelude::{Buffer, Rect, Widget};
 
 pub struct Frame {
     dash_board: DashBoard,
-    page: Page,
+    /// Pages of opened docs, which deref to the current one.
+    page: Tabs,
     focus: Focus,
     /// Initialize this when needed the first time.
     help: Option<Box<Help>>,
+    /// The item from another crate to jump to once its doc is loaded.
+    pending_jump: Option<ExternalJump>,
     pub should_quit: bool,
 }
 
@@ -27,12 +35,13 @@ enum Focus {
 
 impl Frame {
     pub fn new(dash_board: DashBoard) -> Frame {
-        let (page, focus, help, should_quit) = Default::default();
+        let (page, focus, help, pending_jump, should_quit) = Default::default();
         Frame {
             dash_board,
             page,
             focus,
             help,
+            pending_jump,
             should_quit,
         }
     }
@@ -60,6 +69,34 @@ impl Frame {
         })
     }
 
+    /// Give loaded docs to the Page if candidates are requested, because candidates
+    /// are also from docs other than the current one.
+    fn list_candidates(&mut self) {
+        if self.page.needs_candidates() {
+            let loaded = self.dash_board.ui().loaded_docs();
+            self.page.open_candidates(&loaded);
+        }
+    }
+
+    /// The item location to open in an editor, requested from the Page.
+    pub fn take_editor_request(&mut self) -> Option<Location> {
+        self.page.take_editor_request()
+    }
+
+    pub fn editor_failed(&mut self, err: &str) {
+        error!("Failed to open the editor:\n{err}");
+        self.page.show_message(err, " Editor Failed ");
+    }
+
+    /// Load the doc of another crate if an item in it is followed from the Page.
+    fn follow_external_item(&mut self) {
+        if let Some(jump) = self.page.take_external_jump() {
+            if self.dash_board.ui().load_crate(jump.krate()) {
+                self.pending_jump = Some(jump);
+            }
+        }
+    }
+
     fn quit(&mut self) {
         self.should_quit = true;
     }
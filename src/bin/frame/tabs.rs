@@ -0,0 +1,203 @@
+use crate::{
+    color::{TAB, TAB_CURRENT},
+    database::{LoadedDocs, PkgKey},
+    page::Page,
+    ui::render_line,
+};
+use ratatui::prelude::{Buffer, Rect, Widget};
+use std::ops::{Deref, DerefMut};
+use term_rustdoc::util::{xformat, XString};
+
+/// Pages of loaded docs as tabs. Each Page keeps its own states like outline folding,
+/// content scrolling and navigation history.
+///
+/// Tabs deref to the current Page.
+pub struct Tabs {
+    /// Never empty: an empty Page is the placeholder before any doc is opened.
+    pages: Vec<Page>,
+    current: usize,
+    /// The row of tab bar.
+    bar: Rect,
+    /// Titles and their starting and ending columns on tab bar.
+    titles: Vec<(XString, u16, u16)>,
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Tabs {
+            pages: vec![Page::default()],
+            current: 0,
+            bar: Rect::default(),
+            titles: Vec::new(),
+        }
+    }
+}
+
+impl Deref for Tabs {
+    type Target = Page;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pages[self.current]
+    }
+}
+
+impl DerefMut for Tabs {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.pages[self.current]
+    }
+}
+
+impl Tabs {
+    /// The area for a Page below the tab bar.
+    pub fn page_area(full: Rect) -> Rect {
+        let height = full.height.saturating_sub(1);
+        Rect::new(full.x, full.y + 1, full.width, height)
+    }
+
+    /// Switch to the tab of the pkg. Returns false if the pkg isn't opened.
+    pub fn switch_to(&mut self, key: &PkgKey) -> bool {
+        match self
+            .pages
+            .iter()
+            .position(|page| page.pkg_key() == Some(key))
+        {
+            Some(pos) => {
+                self.current = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Switch to the tab traveled to in history of the current Page, which remembers
+    /// the current one to travel back.
+    pub fn switch_tab_in_history(&mut self) {
+        let Some((key, back)) = self.take_tab_in_history() else {
+            return;
+        };
+        let from = self.tab_visit();
+        if !self.switch_to(&key) {
+            warn!(?key, "the tab in history has been closed");
+            return;
+        }
+        if let Some(from) = from {
+            self.keep_tab_in_history(from, back);
+        }
+    }
+
+    /// Open a new tab next to the current one, unless the current one is the placeholder.
+    pub fn open(&mut self, page: Page) {
+        if self.pages[self.current].is_empty() {
+            self.pages[self.current] = page;
+        } else {
+            self.current += 1;
+            self.pages.insert(self.current, page);
+        }
+        self.update_titles();
+    }
+
+    pub fn next(&mut self) {
+        self.current = (self.current + 1) % self.pages.len();
+    }
+
+    pub fn previous(&mut self) {
+        let len = self.pages.len();
+        self.current = (self.current + len - 1) % len;
+    }
+
+    /// Reorder the current tab by moving it forward or backward.
+    pub fn move_current(&mut self, forward: bool) {
+        let target = if forward {
+            self.current + 1
+        } else {
+            self.current.wrapping_sub(1)
+        };
+        if target < self.pages.len() {
+            self.pages.swap(self.current, target);
+            self.current = target;
+            self.update_titles();
+        }
+    }
+
+    pub fn close_current(&mut self) {
+        self.close(self.current);
+    }
+
+    fn close(&mut self, pos: usize) {
+        let page = self.pages.remove(pos);
+        info!(pkg_key = ?page.pkg_key(), "closed the tab");
+        if self.pages.is_empty() {
+            self.pages.push(Page::default());
+        }
+        if self.current > pos || self.current == self.pages.len() {
+            self.current -= 1;
+        }
+        self.update_titles();
+    }
+
+    /// Close the tab of the downgraded doc, and drop it from other tabs.
+    pub fn drop(&mut self, key: &PkgKey) {
+        if let Some(pos) = self.pages.iter().position(|p| p.pkg_key() == Some(key)) {
+            self.close(pos);
+        }
+        self.pages.iter_mut().for_each(|page| page.drop(key));
+    }
+
+    /// Docs loaded in database for all tabs.
+    pub fn set_other_docs(&mut self, docs: LoadedDocs) {
+        for page in &mut self.pages {
+            page.set_other_docs(docs.clone());
+        }
+    }
+
+    fn update_titles(&mut self) {
+        self.titles.clear();
+        let mut col = self.bar.x;
+        for page in &self.pages {
+            let title = match page.pkg_key() {
+                Some(key) => xformat!(" {} v{} ", key.name(), key.ver_str()),
+                None => continue,
+            };
+            let end = col.saturating_add(title.len() as u16);
+            self.titles.push((title, col, end));
+            col = end.saturating_add(1);
+        }
+    }
+
+    /// Switch to the tab under the position. Returns false if the position is not on tab bar.
+    pub fn click(&mut self, x: u16, y: u16) -> bool {
+        if y != self.bar.y {
+            return false;
+        }
+        if let Some(pos) = self.titles.iter().position(|t| t.1 <= x && x < t.2) {
+            self.current = pos;
+        }
+        true
+    }
+
+    pub fn render(&mut self, full: Rect, buf: &mut Buffer) {
+        let bar = Rect { height: 1, ..full };
+        if self.bar != bar {
+            self.bar = bar;
+            self.update_titles();
+        }
+        let width = bar.width as usize;
+        let line = self
+            .titles
+            .iter()
+            .enumerate()
+            .flat_map(|(pos, (title, ..))| {
+                let style = if pos == self.current {
+                    TAB_CURRENT
+                } else {
+                    TAB
+                };
+                [(&**title, style), (" ", Default::default())]
+            });
+        render_line(line, buf, bar.x, bar.y, width);
+        self.pages[self.current].render(Self::page_area(full), buf);
+    }
+}
+
+#[cfg(test)]
+mod tests;
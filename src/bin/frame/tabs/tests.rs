@@ -0,0 +1,120 @@
+use super::Tabs;
+use crate::{
+    database::{LoadedDocs, PkgKey},
+    local_registry::PkgNameVersion,
+    page::Page,
+};
+use ratatui::prelude::Rect;
+use std::rc::Rc;
+use term_rustdoc::tree::CrateDoc;
+
+fn key(name: &str) -> PkgKey {
+    PkgKey::new_with_default_feature(PkgNameVersion::new(name, "0.1.0"))
+}
+
+/// Open the pages in order, so the last one is the current tab.
+fn tabs(names: &[&str]) -> Tabs {
+    let mut tabs = Tabs {
+        bar: Rect::new(0, 0, 80, 1),
+        ..Default::default()
+    };
+    let area = Rect::new(0, 1, 80, 20);
+    for name in names {
+        tabs.open(Page::new(key(name), CrateDoc::default(), area).unwrap());
+    }
+    tabs
+}
+
+fn names(tabs: &Tabs) -> Vec<&str> {
+    let keys = tabs
+        .pages
+        .iter()
+        .map(|page| page.pkg_key().map(PkgKey::name));
+    keys.map(Option::unwrap_or_default).collect()
+}
+
+fn current(tabs: &Tabs) -> &str {
+    tabs.pkg_key().map(PkgKey::name).unwrap_or_default()
+}
+
+#[test]
+fn close_tabs() {
+    let mut first = tabs(&["a", "b", "c"]);
+    first.current = 0;
+    first.close_current();
+    assert_eq!(names(&first), ["b", "c"]);
+    assert_eq!(current(&first), "b");
+
+    let mut middle = tabs(&["a", "b", "c"]);
+    middle.current = 1;
+    middle.close_current();
+    assert_eq!(names(&middle), ["a", "c"]);
+    assert_eq!(current(&middle), "c");
+
+    let mut last = tabs(&["a", "b", "c"]);
+    last.close_current();
+    assert_eq!(names(&last), ["a", "b"]);
+    assert_eq!(current(&last), "b");
+
+    // closing a tab before the current one keeps the current one
+    let mut before = tabs(&["a", "b", "c"]);
+    before.drop(&key("a"));
+    assert_eq!(names(&before), ["b", "c"]);
+    assert_eq!(current(&before), "c");
+
+    // the placeholder is left after the only tab is closed
+    let mut only = tabs(&["a"]);
+    only.close_current();
+    assert_eq!(only.pages.len(), 1);
+    assert_eq!(only.current, 0);
+    assert!(only.pkg_key().is_none());
+    assert!(only.titles.is_empty());
+}
+
+#[test]
+fn move_tabs() {
+    let mut tabs = tabs(&["a", "b", "c"]);
+    tabs.move_current(true);
+    assert_eq!(names(&tabs), ["a", "b", "c"]);
+    assert_eq!(tabs.current, 2);
+
+    tabs.current = 0;
+    tabs.move_current(false);
+    assert_eq!(names(&tabs), ["a", "b", "c"]);
+    assert_eq!(tabs.current, 0);
+
+    tabs.move_current(true);
+    assert_eq!(names(&tabs), ["b", "a", "c"]);
+    assert_eq!(current(&tabs), "a");
+    assert_eq!(tabs.titles[1].0, " a v0.1.0 ");
+}
+
+#[test]
+fn click_titles() {
+    let mut tabs = tabs(&["a", "b", "c"]);
+    let cols: Vec<_> = tabs.titles.iter().map(|t| [t.1, t.2]).collect();
+    assert_eq!(cols, [[0, 10], [11, 21], [22, 32]]);
+
+    assert!(tabs.click(11, 0));
+    assert_eq!(current(&tabs), "b");
+    // the gap between titles and the space after them don't switch tabs
+    assert!(tabs.click(10, 0));
+    assert!(tabs.click(40, 0));
+    assert_eq!(current(&tabs), "b");
+    assert!(tabs.click(0, 0));
+    assert_eq!(current(&tabs), "a");
+    // not on the tab bar
+    assert!(!tabs.click(22, 1));
+    assert_eq!(current(&tabs), "a");
+}
+
+#[test]
+fn share_other_docs() {
+    let mut tabs = tabs(&["a", "b", "c"]);
+    let docs: LoadedDocs = ["a", "b", "c", "d"]
+        .map(|name| (key(name), CrateDoc::default()))
+        .into();
+    tabs.set_other_docs(docs.clone());
+    // each page holds the same docs instead of a copy
+    assert_eq!(Rc::strong_count(&docs), 4);
+}
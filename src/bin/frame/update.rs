@@ -1,4 +1,4 @@
-use super::{help::Help, Focus, Frame};
+use super::{help::Help, tabs::Tabs, Focus, Frame};
 use crate::{dashboard::DashBoard, event::Event, page::Page, ui::ScrollOffset};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
@@ -10,12 +10,30 @@ impl Frame {
             Event::Resize(_, _) => {}
             Event::MouseDoubleClick(x, y) => self.update_for_double_click((x, y)),
             Event::DocCompiled(info) => self.dash_board.ui().receive_compiled_doc(*info),
+            Event::DocFailed(pkg_key, _) => self.dash_board.ui().receive_failed_doc(&pkg_key),
             Event::CrateDoc(pkg_key) => {
                 let ui = &self.dash_board.ui();
-                if let Some(doc) = ui.get_loaded_doc(&pkg_key) {
-                    match Page::new(*pkg_key, doc, ui.get_full_area()) {
-                        Ok(page) => {
-                            self.page = page;
+                let jump = self.pending_jump.take();
+                let jump = jump.filter(|jump| jump.is_in_pkg(pkg_key.name()));
+                // the tab to go back from the followed item
+                let from = jump.as_ref().and_then(|_| self.page.tab_visit());
+                // keep states of the opened doc
+                if self.page.switch_to(&pkg_key) {
+                    if let Some(jump) = jump {
+                        self.page.jump_to_external(&jump, from);
+                    }
+                    self.switch_to_page();
+                } else if let Some(doc) = ui.get_loaded_doc(&pkg_key) {
+                    let pkg_dir = ui.pkg_dir(&pkg_key);
+                    let area = Tabs::page_area(ui.get_full_area());
+                    match Page::new(*pkg_key, doc, area) {
+                        Ok(mut page) => {
+                            page.set_pkg_dir(pkg_dir);
+                            self.page.open(page);
+                            self.page.set_other_docs(ui.loaded_docs());
+                            if let Some(jump) = jump {
+                                self.page.jump_to_external(&jump, from);
+                            }
                             self.switch_to_page();
                         }
                         Err(err) => error!("Failed to construct a Page:\n{err}"),
@@ -23,7 +41,18 @@ impl Frame {
                 }
             }
             Event::Downgraded(pkg_key) => self.page.drop(&pkg_key),
+            Event::ApiDiff(diff) => {
+                let (old, new, diff) = *diff;
+                self.dash_board.ui().receive_api_diff(&old, &new, diff);
+            }
+            Event::ProjectDeps(deps) => self.dash_board.ui().receive_project_deps(deps),
         };
+        if matches!(self.focus, Focus::Page) && self.page.is_empty() {
+            self.focus = Focus::DashBoard;
+        }
+        self.list_candidates();
+        self.follow_external_item();
+        self.page.switch_tab_in_history();
     }
 
     fn update_for_key(&mut self, key_event: KeyEvent) {
@@ -56,6 +85,10 @@ impl Frame {
             Focus::Page => update_page(&mut self.page, &key_event),
             Focus::Help => update_help(self.get_help(), &key_event),
         };
+        // all tabs are closed
+        if matches!(self.focus, Focus::Page) && self.page.is_empty() {
+            self.focus = Focus::DashBoard;
+        }
     }
 
     fn update_for_mouse(&mut self, event: MouseEvent) {
@@ -66,6 +99,32 @@ impl Frame {
                 }
             }
             Focus::Page => match event.kind {
+                MouseEventKind::ScrollDown if self.page.is_searching() => {
+                    self.page.search_scroll(true);
+                }
+                MouseEventKind::ScrollUp if self.page.is_searching() => {
+                    self.page.search_scroll(false);
+                }
+                MouseEventKind::ScrollDown if self.page.is_listing_candidates() => {
+                    if let Some(candidates) = self.page.candidates_results() {
+                        candidates.scroll_down(ScrollOffset::Fixed(5));
+                    }
+                }
+                MouseEventKind::ScrollUp if self.page.is_listing_candidates() => {
+                    if let Some(candidates) = self.page.candidates_results() {
+                        candidates.scroll_up(ScrollOffset::Fixed(5));
+                    }
+                }
+                MouseEventKind::ScrollDown if self.page.is_listing_history() => {
+                    if let Some(history) = self.page.history_results() {
+                        history.scroll_down(ScrollOffset::Fixed(5));
+                    }
+                }
+                MouseEventKind::ScrollUp if self.page.is_listing_history() => {
+                    if let Some(history) = self.page.history_results() {
+                        history.scroll_up(ScrollOffset::Fixed(5));
+                    }
+                }
                 MouseEventKind::ScrollDown => {
                     self.page.scrolldown(ScrollOffset::Fixed(5));
                 }
@@ -74,8 +133,13 @@ impl Frame {
                 }
                 MouseEventKind::Down(MouseButton::Left) => {
                     let (x, y) = (event.column, event.row);
-                    self.page.set_current_panel(y, x);
+                    if !self.page.click(x, y) {
+                        self.page.set_current_panel(y, x);
+                    }
                 }
+                // terminals don't report the side buttons, thus use right/middle ones
+                MouseEventKind::Down(MouseButton::Right) => self.page.history_back(),
+                MouseEventKind::Down(MouseButton::Middle) => self.page.history_forward(),
                 _ => (),
             },
             Focus::Help => {
@@ -117,6 +181,9 @@ fn update_dash_board(dash: &mut DashBoard, key_event: &KeyEvent) {
             KeyCode::Char('c') => ui.clear_input(),
             KeyCode::Char('s') => ui.switch_sort(),
             KeyCode::Char('f') => ui.switch_search_source(),
+            KeyCode::Char('d') => ui.api_diff(),
+            KeyCode::Char('p') => ui.compile_project_deps(),
+            KeyCode::Char('t') => ui.import_sysroot_docs(),
             _ => (),
         }
         return;
@@ -133,12 +200,46 @@ fn update_dash_board(dash: &mut DashBoard, key_event: &KeyEvent) {
         KeyCode::Enter => ui.compile_or_load_doc(None),
         KeyCode::Tab => ui.switch_panel(),
         KeyCode::Delete => ui.downgrade(None),
-        KeyCode::Esc => ui.close_ver_feat(),
+        KeyCode::Esc => ui.close_popup(),
         _ => (),
     }
 }
 
-fn update_page(page: &mut Page, key_event: &KeyEvent) {
+fn update_page(tabs: &mut Tabs, key_event: &KeyEvent) {
+    let page: &mut Page = tabs;
+    if page.is_searching() {
+        update_page_search(page, key_event);
+        return;
+    }
+    if page.is_filtering() {
+        update_page_filter(page, key_event);
+        return;
+    }
+    if page.is_listing_candidates() {
+        update_page_candidates(page, key_event);
+        return;
+    }
+    if page.is_listing_history() {
+        update_page_history(page, key_event);
+        return;
+    }
+    if key_event.modifiers == KeyModifiers::ALT {
+        match key_event.code {
+            KeyCode::Left => page.history_back(),
+            KeyCode::Right => page.history_forward(),
+            _ => (),
+        }
+        return;
+    }
+    match key_event.code {
+        KeyCode::Char(']') => return tabs.next(),
+        KeyCode::Char('[') => return tabs.previous(),
+        KeyCode::Char('}') => return tabs.move_current(true),
+        KeyCode::Char('{') => return tabs.move_current(false),
+        KeyCode::Char('x') => return tabs.close_current(),
+        _ => (),
+    }
+    let page: &mut Page = tabs;
     match key_event.code {
         KeyCode::Down | KeyCode::Char('j') => page.move_forward_cursor(),
         KeyCode::Up | KeyCode::Char('k') => page.move_backward_cursor(),
@@ -156,11 +257,107 @@ fn update_page(page: &mut Page, key_event: &KeyEvent) {
         KeyCode::Char('0') => page.outline_fold_expand_zero_level(),
         KeyCode::Char('1') => page.outline_fold_expand_to_first_level_modules(),
         KeyCode::Enter => page.outline_fold_expand_toggle(),
+        KeyCode::Char('D') => page.outline_toggle_deprecated(),
+        KeyCode::Char('F') => page.open_feature_filter(),
+        KeyCode::Char('c') => page.request_candidates(),
         KeyCode::Char('d') => page.toggle_sytect(),
+        KeyCode::Char('s') => page.toggle_source(),
+        KeyCode::Char('e') => page.open_in_editor(),
+        KeyCode::Char('n') => page.select_item_link(true),
+        KeyCode::Char('N') => page.select_item_link(false),
+        KeyCode::Char('g') => page.jump_to_selected_item_link(),
+        KeyCode::Char('f') => page.open_search(),
+        KeyCode::Char('<') | KeyCode::Backspace => page.history_back(),
+        KeyCode::Char('>') => page.history_forward(),
+        KeyCode::Char('r') => page.open_history(),
         _ => {}
     };
 }
 
+fn update_page_search(page: &mut Page, key_event: &KeyEvent) {
+    if key_event.modifiers == KeyModifiers::CONTROL {
+        match key_event.code {
+            KeyCode::Char('c') => page.search_clear_input(),
+            KeyCode::Char('f') => page.search_switch_source(),
+            _ => (),
+        }
+        return;
+    }
+    match key_event.code {
+        KeyCode::Char(ch) => page.search_push_char(ch),
+        KeyCode::Backspace => page.search_pop_char(),
+        KeyCode::Enter => page.jump_to_searched_item(None),
+        KeyCode::Esc => page.close_search(),
+        _ => {
+            let Some(results) = page.search_results() else {
+                return;
+            };
+            match key_event.code {
+                KeyCode::Up => results.move_backward_cursor(),
+                KeyCode::Down => results.move_forward_cursor(),
+                KeyCode::Home => results.scroll_home(),
+                KeyCode::End => results.scroll_end(),
+                KeyCode::PageUp => results.scroll_up(ScrollOffset::HalfScreen),
+                KeyCode::PageDown => results.scroll_down(ScrollOffset::HalfScreen),
+                _ => (),
+            }
+        }
+    }
+}
+
+fn update_page_filter(page: &mut Page, key_event: &KeyEvent) {
+    match key_event.code {
+        KeyCode::Down | KeyCode::Char('j') => page.filter_move_cursor(true),
+        KeyCode::Up | KeyCode::Char('k') => page.filter_move_cursor(false),
+        KeyCode::Char(' ') | KeyCode::Enter => page.filter_toggle(false),
+        KeyCode::Char('a') => page.filter_toggle(true),
+        KeyCode::Esc | KeyCode::Char('F') => page.close_feature_filter(),
+        _ => (),
+    }
+}
+
+fn update_page_candidates(page: &mut Page, key_event: &KeyEvent) {
+    match key_event.code {
+        KeyCode::Enter => page.jump_to_candidate(None),
+        KeyCode::Esc | KeyCode::Char('c') => page.close_candidates(),
+        _ => {
+            let Some(candidates) = page.candidates_results() else {
+                return;
+            };
+            match key_event.code {
+                KeyCode::Down | KeyCode::Char('j') => candidates.move_forward_cursor(),
+                KeyCode::Up | KeyCode::Char('k') => candidates.move_backward_cursor(),
+                KeyCode::Home => candidates.scroll_home(),
+                KeyCode::End => candidates.scroll_end(),
+                KeyCode::PageUp => candidates.scroll_up(ScrollOffset::HalfScreen),
+                KeyCode::PageDown => candidates.scroll_down(ScrollOffset::HalfScreen),
+                _ => (),
+            }
+        }
+    }
+}
+
+fn update_page_history(page: &mut Page, key_event: &KeyEvent) {
+    match key_event.code {
+        KeyCode::Enter => page.jump_to_history(None),
+        KeyCode::Esc | KeyCode::Char('r') => page.close_history(),
+        _ => {
+            let Some(history) = page.history_results() else {
+                return;
+            };
+            match key_event.code {
+                KeyCode::Down | KeyCode::Char('j') => history.move_forward_cursor(),
+                KeyCode::Up | KeyCode::Char('k') => history.move_backward_cursor(),
+                KeyCode::Home => history.scroll_home(),
+                KeyCode::End => history.scroll_end(),
+                KeyCode::PageUp => history.scroll_up(ScrollOffset::HalfScreen),
+                KeyCode::PageDown => history.scroll_down(ScrollOffset::HalfScreen),
+                _ => (),
+            }
+        }
+    }
+}
+
 fn update_help(help: &mut Help, event: &KeyEvent) {
     let help = help.scroll_text();
     match event.code {
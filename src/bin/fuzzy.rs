@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use nucleo_matcher::{
-    pattern::{Atom, CaseMatching, Normalization},
+    pattern::{Atom, AtomKind, CaseMatching, Normalization},
     *,
 };
 use std::{cell::RefCell, rc::Rc};
@@ -32,10 +32,20 @@ impl Fuzzy {
         self.fuzzy(|f| f.parse(pattern));
     }
 
+    /// Match the pattern as a contiguous sequence of characters instead of fuzzy matching.
+    pub fn parse_substring(&self, pattern: &str) {
+        self.fuzzy(|f| f.parse_substring(pattern));
+    }
+
     pub fn score(&self, text: &str) -> Option<u16> {
         self.fuzzy(|f| f.score(text)).flatten()
     }
 
+    /// Like `score`, but also returns the char indices of matched text.
+    pub fn indices(&self, text: &str, indices: &mut Vec<u32>) -> Option<u16> {
+        self.fuzzy(|f| f.indices(text, indices)).flatten()
+    }
+
     pub fn match_list<T: AsRef<str>, U: From<T>>(
         &self,
         texts: impl IntoIterator<Item = T>,
@@ -65,6 +75,17 @@ impl FuzzyInner {
         self.pat = Atom::parse(pattern, CaseMatching::Smart, Normalization::Smart);
     }
 
+    fn parse_substring(&mut self, pattern: &str) {
+        let (case, norm) = (CaseMatching::Smart, Normalization::Smart);
+        self.pat = Atom::new(pattern, case, norm, AtomKind::Substring, false);
+    }
+
+    fn indices(&mut self, source_text: &str, indices: &mut Vec<u32>) -> Option<u16> {
+        let text = Utf32Str::new(source_text, &mut self.buf);
+        indices.clear();
+        self.pat.indices(text, &mut self.matcher, indices)
+    }
+
     fn score(&mut self, source_text: &str) -> Option<u16> {
         let text = Utf32Str::new(source_text, &mut self.buf);
         self.pat.score(text, &mut self.matcher)
@@ -1,6 +1,7 @@
 use crate::{
     color::{PKG_NAME, PKG_VERSION},
-    Result,
+    database::{Features, PkgWithFeatures},
+    err, Result,
 };
 use itertools::Itertools;
 use ratatui::prelude::Style;
@@ -8,6 +9,7 @@ use regex::Regex;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashSet,
     fs,
     path::{Path, PathBuf},
     time::SystemTime,
@@ -100,6 +102,25 @@ impl LocalRegistry {
         Ok([latest, all])
     }
 
+    /// Add pkgs outside the registry, e.g. workspace members, in front of registry pkgs.
+    pub fn extend_front(&mut self, pkgs: &[PkgInfo]) {
+        self.pkgs.splice(0..0, pkgs.iter().cloned());
+    }
+
+    /// Add pkgs outside the registry, and keep all pkgs sorted by name and version
+    /// for binary search.
+    pub fn extend_sorted(&mut self, pkgs: &[PkgInfo]) {
+        self.pkgs.extend_from_slice(pkgs);
+        self.pkgs
+            .sort_by(|a, b| (&*a.name, &a.version).cmp(&(&*b.name, &b.version)));
+    }
+
+    /// Whether the pkg is in the registry src dir, i.e. not a pkg added via local path.
+    pub fn is_in_registry(&self, pkg: &PkgInfo) -> bool {
+        // an empty path means no registry is found, and every path starts with it
+        !pkg.is_path && !self.path.as_os_str().is_empty() && pkg.path.starts_with(&self.path)
+    }
+
     pub fn len(&self) -> usize {
         self.pkgs.len()
     }
@@ -109,10 +130,60 @@ impl LocalRegistry {
     }
 }
 
+/// Appended to the version in serialized data of pkgs added via local paths to tell
+/// them from registry pkgs with the same name and version. It can't occur in a semver,
+/// and keeps the data layout unchanged, so caches of registry pkgs remain valid.
+const PATH_MARKER: &str = " [path]";
+
+fn mark_path(ver: &str, is_path: bool) -> XString {
+    if is_path {
+        [ver, PATH_MARKER].into_iter().collect()
+    } else {
+        ver.into()
+    }
+}
+
+fn unmark_path(ver: XString) -> (XString, bool) {
+    match ver.strip_suffix(PATH_MARKER) {
+        Some(ver) => (ver.into(), true),
+        None => (ver, false),
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawNameVersion", into = "RawNameVersion")]
 pub struct PkgNameVersion {
     name: XString,
     version: XString,
+    /// Added via local path instead of from the registry.
+    is_path: bool,
+}
+
+/// The serialized layout of PkgNameVersion.
+#[derive(Serialize, Deserialize)]
+struct RawNameVersion {
+    name: XString,
+    version: XString,
+}
+
+impl From<RawNameVersion> for PkgNameVersion {
+    fn from(RawNameVersion { name, version }: RawNameVersion) -> Self {
+        let (version, is_path) = unmark_path(version);
+        PkgNameVersion {
+            name,
+            version,
+            is_path,
+        }
+    }
+}
+
+impl From<PkgNameVersion> for RawNameVersion {
+    fn from(pkg: PkgNameVersion) -> Self {
+        RawNameVersion {
+            version: mark_path(&pkg.version, pkg.is_path),
+            name: pkg.name,
+        }
+    }
 }
 
 impl PkgNameVersion {
@@ -122,7 +193,11 @@ impl PkgNameVersion {
 
     pub fn doc_db_file_name(&self) -> XString {
         let mut name = self.name.clone();
-        name.extend(["-", &*self.version, ".db"]);
+        name.extend(["-", &*self.version]);
+        if self.is_path {
+            name.push_str("-path");
+        }
+        name.push_str(".db");
         name
     }
 
@@ -134,14 +209,28 @@ impl PkgNameVersion {
         &self.version
     }
 
+    /// Added via local path instead of from the registry.
+    pub fn is_path(&self) -> bool {
+        self.is_path
+    }
+
     /// An empty pkg for temporary use.
     pub fn empty_state() -> Self {
-        let (name, version) = Default::default();
-        PkgNameVersion { name, version }
+        Default::default()
+    }
+
+    #[cfg(test)]
+    pub fn new(name: &str, version: &str) -> Self {
+        PkgNameVersion {
+            name: name.into(),
+            version: version.into(),
+            is_path: false,
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawPkgInfo", into = "RawPkgInfo")]
 pub struct PkgInfo {
     /// Pkg name from dir name.
     name: XString,
@@ -153,6 +242,44 @@ pub struct PkgInfo {
     path: PathBuf,
     /// The last modified time for pkg dir.
     modified: SystemTime,
+    /// Added via local path instead of from the registry, e.g. a workspace member.
+    is_path: bool,
+}
+
+/// The serialized layout of PkgInfo, where `is_path` is marked in ver_str.
+#[derive(Serialize, Deserialize)]
+struct RawPkgInfo {
+    name: XString,
+    ver_str: XString,
+    version: Version,
+    path: PathBuf,
+    modified: SystemTime,
+}
+
+impl From<RawPkgInfo> for PkgInfo {
+    fn from(raw: RawPkgInfo) -> Self {
+        let (ver_str, is_path) = unmark_path(raw.ver_str);
+        PkgInfo {
+            name: raw.name,
+            ver_str,
+            version: raw.version,
+            path: raw.path,
+            modified: raw.modified,
+            is_path,
+        }
+    }
+}
+
+impl From<PkgInfo> for RawPkgInfo {
+    fn from(pkg: PkgInfo) -> Self {
+        RawPkgInfo {
+            ver_str: mark_path(&pkg.ver_str, pkg.is_path),
+            name: pkg.name,
+            version: pkg.version,
+            path: pkg.path,
+            modified: pkg.modified,
+        }
+    }
 }
 
 impl Default for PkgInfo {
@@ -164,11 +291,24 @@ impl Default for PkgInfo {
             version: Version::new(0, 0, 0),
             path,
             modified: SystemTime::now(),
+            is_path: false,
         }
     }
 }
 
 impl PkgInfo {
+    /// A pkg in local registry from its dir name like `name-version`.
+    #[cfg(test)]
+    pub fn from_dir_name(name_ver: &str) -> Self {
+        let (name, ver_str, version) = get_pkg_name(name_ver).unwrap();
+        PkgInfo {
+            name,
+            ver_str,
+            version,
+            ..Default::default()
+        }
+    }
+
     fn new(pkg_path: PathBuf) -> Option<Self> {
         let modified = pkg_path.metadata().ok()?.modified().ok()?;
         let (name, ver, version) = get_pkg_name(pkg_path.file_name()?.to_str()?)?;
@@ -178,6 +318,7 @@ impl PkgInfo {
             version,
             path: pkg_path,
             modified,
+            is_path: false,
         })
     }
 
@@ -205,14 +346,226 @@ impl PkgInfo {
         PkgNameVersion {
             name: self.name.clone(),
             version: self.ver_str.clone(),
+            is_path: self.is_path,
         }
     }
 
+    /// Added via local path instead of from the registry.
+    pub fn is_path(&self) -> bool {
+        self.is_path
+    }
+
     /// This is not the same as PartialEq/Eq (`==`), because this method only
-    /// compares with name and version.
+    /// compares with name, version and whether it's from local path.
     pub fn is_same_pkg(&self, pkg: &Self) -> bool {
-        self.name == pkg.name && self.ver_str == pkg.ver_str
+        self.name == pkg.name && self.ver_str == pkg.ver_str && self.is_path == pkg.is_path
+    }
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+    workspace_members: Vec<String>,
+    /// Only exists without `--no-deps`.
+    resolve: Option<Resolve>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    id: String,
+    name: XString,
+    version: XString,
+    manifest_path: PathBuf,
+    /// None for pkgs from local paths, e.g. workspace members and path dependencies.
+    source: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Resolve {
+    nodes: Vec<Node>,
+}
+
+#[derive(Deserialize)]
+struct Node {
+    id: String,
+    deps: Vec<NodeDep>,
+    /// Features enabled for this pkg in the dependency graph.
+    features: Vec<XString>,
+}
+
+#[derive(Deserialize)]
+struct NodeDep {
+    pkg: String,
+}
+
+/// Run `cargo metadata` on the manifest, or the project in current dir if manifest is None.
+fn cargo_metadata(manifest: Option<&Path>, no_deps: bool) -> Result<Metadata> {
+    let mut cmd = std::process::Command::new("cargo");
+    cmd.args(["metadata", "--format-version", "1"]);
+    if no_deps {
+        cmd.arg("--no-deps");
+    } else {
+        // dependencies must be downloaded in local registry to be compiled
+        cmd.arg("--offline");
+    }
+    if let Some(manifest) = manifest {
+        cmd.arg("--manifest-path").arg(manifest);
     }
+    let output = cmd.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let path = manifest.unwrap_or(Path::new("current dir"));
+        return Err(err!(
+            "Failed to run cargo metadata on {}:\n{stderr}",
+            path.display()
+        ));
+    }
+    Ok(serde_json::from_slice(&output.stdout)?)
+}
+
+impl Package {
+    fn into_pkg_info(self) -> Result<PkgInfo> {
+        let mut path = self.manifest_path;
+        path.pop();
+        Ok(PkgInfo {
+            version: self.version.parse()?,
+            name: self.name,
+            ver_str: self.version,
+            modified: path.metadata()?.modified()?,
+            path,
+            is_path: self.source.is_none(),
+        })
+    }
+}
+
+/// Workspace members from `cargo metadata`, which are compiled like pkgs in local registry.
+///
+/// Docs of a member are cached by its name and version marked as a path pkg, so they
+/// don't collide with a registry pkg of the same name and version, and rebuilding
+/// the doc after source changes replaces the stale one.
+pub fn workspace_members(manifest: &Path) -> Result<Vec<PkgInfo>> {
+    let metadata = cargo_metadata(Some(manifest), true)?;
+    let mut pkgs = metadata
+        .packages
+        .into_iter()
+        .map(Package::into_pkg_info)
+        .collect::<Result<Vec<_>>>()?;
+    pkgs.sort_unstable_by(|a, b| (&*a.name, &a.version).cmp(&(&*b.name, &b.version)));
+    info!(
+        "Found {} workspace members in {}",
+        pkgs.len(),
+        manifest.display()
+    );
+    Ok(pkgs)
+}
+
+/// Direct and transitive dependencies of workspace members with exact versions and features
+/// resolved by cargo, i.e. what the project actually compiles against.
+pub fn project_dependencies(manifest: Option<&Path>) -> Result<Vec<PkgWithFeatures>> {
+    let metadata = cargo_metadata(manifest, false)?;
+    let nodes = metadata.resolve.map(|r| r.nodes).unwrap_or_default();
+    let members = &metadata.workspace_members;
+    // walk the resolve graph from workspace members
+    let mut deps = HashSet::<&str>::new();
+    let mut stack: Vec<&str> = members.iter().map(|m| &**m).collect();
+    while let Some(id) = stack.pop() {
+        let Some(node) = nodes.iter().find(|node| node.id == id) else {
+            continue;
+        };
+        for dep in &node.deps {
+            if !members.contains(&dep.pkg) && deps.insert(&dep.pkg) {
+                stack.push(&dep.pkg);
+            }
+        }
+    }
+    let mut pkgs = Vec::with_capacity(deps.len());
+    for pkg in metadata.packages {
+        if !deps.contains(&*pkg.id) {
+            continue;
+        }
+        let Some(node) = nodes.iter().find(|node| node.id == pkg.id) else {
+            continue;
+        };
+        pkgs.push(PkgWithFeatures {
+            features: Features::resolved(&node.features),
+            info: pkg.into_pkg_info()?,
+        });
+    }
+    pkgs.sort_unstable_by(|a, b| a.info.name.cmp(&b.info.name));
+    info!("Found {} project dependencies", pkgs.len());
+    Ok(pkgs)
+}
+
+/// Crates whose json docs are shipped by the `rust-docs-json` component of nightly toolchain.
+pub const SYSROOT_CRATES: &[&str] = &["std", "core", "alloc", "proc_macro"];
+
+/// A json doc shipped by the toolchain, which is imported instead of compiled.
+pub struct SysrootDoc {
+    /// The pkg dir points to the source of the `rust-src` component, thus spans
+    /// in the doc can be found when the component is installed.
+    pub info: PkgInfo,
+    /// `share/doc/rust/json/{crate}.json` in sysroot.
+    pub json: PathBuf,
+}
+
+/// Json docs of std crates in the nightly sysroot.
+///
+/// The version is that of rustc with the commit date, e.g. `1.78.0-nightly.2024-02-04`,
+/// because json docs from different nightlies are likely incompatible.
+pub fn sysroot_docs() -> Result<Vec<SysrootDoc>> {
+    let rustc = |args: &[&str]| -> Result<String> {
+        let output = std::process::Command::new("rustc")
+            .arg("+nightly")
+            .args(args)
+            .output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(err!("Failed to run `rustc +nightly {args:?}`:\n{stderr}"));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+    let sysroot = PathBuf::from(rustc(&["--print", "sysroot"])?);
+    let json_dir = sysroot.join("share/doc/rust/json");
+    if !json_dir.exists() {
+        return Err(err!(
+            "`{}` doesn't exist. Install the json docs via \
+             `rustup component add --toolchain nightly rust-docs-json`.",
+            json_dir.display()
+        ));
+    }
+
+    // rustc 1.78.0-nightly (256b6fb19 2024-02-06)
+    let version = rustc(&["-V"])?;
+    let mut words = version.split_whitespace().skip(1);
+    let ver = words
+        .next()
+        .ok_or_else(|| err!("Unknown rustc version `{version}`"))?;
+    let ver_str: XString = match words.nth(1) {
+        Some(date) => [ver, ".", date.trim_end_matches(')')].into_iter().collect(),
+        None => ver.into(),
+    };
+    let version: Version = ver_str.parse()?;
+    let path = sysroot.join("lib/rustlib/src/rust");
+    let modified = json_dir.metadata()?.modified()?;
+
+    let docs: Vec<_> = SYSROOT_CRATES
+        .iter()
+        .map(|name| (*name, json_dir.join(format!("{name}.json"))))
+        .filter(|(_, json)| json.exists())
+        .map(|(name, json)| SysrootDoc {
+            info: PkgInfo {
+                name: name.into(),
+                ver_str: ver_str.clone(),
+                version: version.clone(),
+                path: path.clone(),
+                modified,
+                is_path: false,
+            },
+            json,
+        })
+        .collect();
+    info!("Found {} json docs in {}", docs.len(), json_dir.display());
+    Ok(docs)
 }
 
 thread_local! {
@@ -272,3 +625,26 @@ fn local_registry_pkgs() -> Result<()> {
     dbg!(pkgs.iter().map(|p| &p.path).collect::<Vec<_>>());
     Ok(())
 }
+
+#[test]
+fn path_pkg_keys() -> Result<()> {
+    use bincode::{config::standard, serde::*};
+    let encode = |t| encode_to_vec(t, standard());
+    let name_ver = |is_path| PkgNameVersion {
+        name: "a".into(),
+        version: "0.1.0".into(),
+        is_path,
+    };
+    // registry pkgs keep the layout before `is_path` is added
+    let raw = RawNameVersion {
+        name: "a".into(),
+        version: "0.1.0".into(),
+    };
+    assert_eq!(encode(name_ver(false))?, encode_to_vec(raw, standard())?);
+    let path = encode(name_ver(true))?;
+    assert_ne!(encode(name_ver(false))?, path);
+    let decoded: PkgNameVersion = decode_from_slice(&path, standard())?.0;
+    assert_eq!(decoded, name_ver(true));
+    assert_eq!(decoded.ver_str(), "0.1.0");
+    Ok(())
+}
@@ -31,7 +31,7 @@ pub fn data_dir() -> Result<PathBuf> {
     let mut dir = dirs::data_local_dir().ok_or_else(|| err!("Can't find the config_local_dir"))?;
     dir.push("term-rustdoc");
     if !dir.exists() {
-        fs::create_dir(&dir)?;
+        fs::create_dir_all(&dir)?;
     }
     Ok(dir)
 }
@@ -1,6 +1,8 @@
+mod cli;
 mod color;
 mod dashboard;
 mod database;
+mod editor;
 mod event;
 mod frame;
 mod fuzzy;
@@ -17,6 +19,17 @@ use self::frame::Frame;
 use color_eyre::eyre::{eyre as err, Result, WrapErr};
 
 fn main() -> Result<()> {
+    let (manifest, workspace) = match cli::Args::parse()? {
+        cli::Args::Tui {
+            manifest,
+            workspace,
+        } => (manifest, workspace),
+        cli::Args::Command(cmd) => {
+            logger::init()?;
+            return cmd.run();
+        }
+    };
+
     tui::install_hooks()?;
     logger::init()?;
 
@@ -25,7 +38,7 @@ fn main() -> Result<()> {
 
     let full = tui.size()?;
     let sender = tui.events.get_sender();
-    let dash_board = dashboard::DashBoard::new(full, fuzz, sender)?;
+    let dash_board = dashboard::DashBoard::new(full, fuzz, sender, manifest, &workspace)?;
     let mut frame = Frame::new(dash_board);
 
     // Start the main loop.
@@ -34,6 +47,11 @@ fn main() -> Result<()> {
         tui.draw(&mut frame)?;
         // Handle events.
         frame.consume_event(tui.events.next()?);
+        if let Some(loc) = frame.take_editor_request() {
+            if let Err(err) = tui.edit(&loc) {
+                frame.editor_failed(&err.to_string());
+            }
+        }
     }
 
     Ok(())
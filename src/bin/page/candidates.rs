@@ -0,0 +1,293 @@
+use super::{ExternalJump, Page, Panel};
+use crate::{
+    color::BG_CURSOR_LINE,
+    database::PkgKey,
+    ui::{render_line, LineState, Popup, PopupClick, PopupContent, Scroll, Surround},
+};
+use ratatui::{
+    layout::Position,
+    prelude::{Buffer, Color, Modifier, Rect, Style},
+};
+use rustdoc_types::ItemEnum;
+use term_rustdoc::{
+    tree::{Candidate, CrateDoc, ID},
+    util::{xformat, XString},
+};
+
+/// A popup to list concrete types that satisfy trait bounds of generic parameters
+/// on an item, or implement the trait if the item is a trait.
+#[derive(Default)]
+pub(super) struct Candidates {
+    inner: Scroll<CandidateLines>,
+    /// The item that candidates are listed for.
+    title: XString,
+}
+
+#[derive(Default)]
+pub struct CandidateLines {
+    lines: Vec<Line>,
+}
+
+impl std::ops::Deref for CandidateLines {
+    type Target = [Line];
+
+    fn deref(&self) -> &Self::Target {
+        &self.lines
+    }
+}
+
+pub enum Line {
+    /// Bounds like `T: Read + Send` or `impl Trait`.
+    Group(XString),
+    Candidate {
+        name: XString,
+        /// The pkg name if the candidate is from another doc.
+        pkg: Option<XString>,
+        jump: Option<Jump>,
+    },
+    /// No candidate is found for the group.
+    Empty,
+}
+
+pub enum Jump {
+    /// The item is in the current doc or reexported from another crate.
+    Id(ID),
+    /// The item is in another loaded doc.
+    External(ExternalJump),
+}
+
+impl LineState for Line {
+    type State = XString;
+
+    fn state(&self) -> Self::State {
+        match self {
+            Line::Group(text) | Line::Candidate { name: text, .. } => text.clone(),
+            Line::Empty => XString::default(),
+        }
+    }
+
+    fn is_identical(&self, state: &Self::State) -> bool {
+        self.state() == *state
+    }
+}
+
+impl CandidateLines {
+    /// Candidates are from the current doc and other loaded docs.
+    fn new(id: &str, doc: &CrateDoc, others: &[(PkgKey, CrateDoc)]) -> Self {
+        let mut lines = Vec::new();
+        let mut push_group = |group: XString, local: Vec<Candidate>, traits: &[XString]| {
+            lines.push(Line::Group(group));
+            let len = lines.len();
+            lines.extend(local.into_iter().map(|c| Line::Candidate {
+                name: c.name,
+                pkg: None,
+                jump: c.id.map(Jump::Id),
+            }));
+            for (key, other) in others {
+                let candidates = other.types_implementing(traits);
+                lines.extend(candidates.into_iter().map(|c| {
+                    Line::Candidate {
+                        jump: c
+                            .id
+                            .and_then(|id| ExternalJump::in_pkg(key.name(), other, &id))
+                            .map(Jump::External),
+                        name: c.name,
+                        pkg: Some(key.name().into()),
+                    }
+                }));
+            }
+            if lines.len() == len {
+                lines.push(Line::Empty);
+            }
+        };
+        if let Some(ItemEnum::Trait(_)) = doc.get_item(id).map(|item| &item.inner) {
+            let name = doc.name(id);
+            // other docs find implementors by the full path of the trait
+            push_group(
+                xformat!("impl {name}"),
+                doc.implementors(id),
+                &[doc.path(id)],
+            );
+        }
+        for param in doc.bounded_params(id) {
+            let local = doc.types_implementing(&param.bounds);
+            push_group(param.display(), local, &param.bounds);
+        }
+        CandidateLines { lines }
+    }
+}
+
+impl Candidates {
+    fn new(id: &str, doc: &CrateDoc, others: &[(PkgKey, CrateDoc)]) -> Self {
+        Candidates {
+            inner: Scroll {
+                lines: CandidateLines::new(id, doc, others),
+                ..Default::default()
+            },
+            title: xformat!(" Candidates For {} ", doc.name(id)),
+        }
+    }
+
+    fn get_jump(&self, y: Option<u16>) -> Option<&Jump> {
+        let line = y.map_or_else(
+            || self.inner.get_line_of_current_cursor(),
+            |y| self.inner.get_line_on_screen(y),
+        )?;
+        match line {
+            Line::Candidate { jump, .. } => jump.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl PopupContent for Candidates {
+    const SIZE: [u16; 2] = [60, 70];
+
+    fn set_inner(&mut self, inner: Rect) {
+        self.inner.area = inner;
+    }
+
+    fn render(&self, border: &Surround, buf: &mut Buffer) {
+        border.render_only_top_left_text(buf, &self.title, 0);
+
+        let text = &self.inner;
+        let Rect { x, mut y, .. } = text.area;
+        let width = text.area.width as usize;
+        if text.lines.is_empty() {
+            let hint = "No generic parameter with trait bounds or trait is found.";
+            buf.set_stringn(x, y, hint, width, Style::new());
+            return;
+        }
+        let Some(lines) = text.visible_lines() else {
+            return;
+        };
+        if text.get_line_of_current_cursor().is_some() {
+            text.highlight_current_line(buf, |cell| {
+                cell.set_bg(BG_CURSOR_LINE);
+            });
+        }
+        let gray = Style::new().fg(Color::DarkGray);
+        for line in lines {
+            match line {
+                Line::Group(group) => {
+                    let style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+                    render_line([(group.as_str(), style)], buf, x, y, width);
+                }
+                Line::Candidate { name, pkg, jump } => {
+                    let style = if jump.is_some() { Style::new() } else { gray };
+                    let mut line = vec![("  ", style), (name.as_str(), style)];
+                    if let Some(pkg) = pkg {
+                        line.extend([(" @ ", gray), (pkg.as_str(), gray)]);
+                    }
+                    render_line(line, buf, x, y, width);
+                }
+                Line::Empty => {
+                    render_line([("  (none)", gray)], buf, x, y, width);
+                }
+            }
+            y += 1;
+        }
+    }
+}
+
+/// Candidates popup.
+impl Page {
+    /// Ask for candidates of the item under cursor in outline. Candidates are collected
+    /// once other loaded docs are given via [`Page::open_candidates`].
+    pub fn request_candidates(&mut self) {
+        if self.outline.display_ref().get_id().is_some() {
+            self.candidates = None;
+            self.current = Some(Panel::Candidates);
+        }
+    }
+
+    pub fn needs_candidates(&self) -> bool {
+        matches!(self.current, Some(Panel::Candidates)) && self.candidates.is_none()
+    }
+
+    /// Collect candidates from the current doc and other loaded docs.
+    pub fn open_candidates(&mut self, loaded: &[(PkgKey, CrateDoc)]) {
+        let outline = self.outline.display_ref();
+        let (Some(id), Some(key)) = (outline.get_id(), &self.pkg_key) else {
+            self.close_candidates();
+            return;
+        };
+        let doc = outline.lines.doc_ref();
+        let others: Vec<_> = loaded.iter().filter(|(k, _)| k != key).cloned().collect();
+        let candidates = Popup::new(Candidates::new(id, doc, &others), self.area);
+        info!(
+            "Found {} candidate lines for {}",
+            candidates.inner.total_len(),
+            doc.name(id)
+        );
+        self.candidates = Some(Box::new(candidates));
+    }
+
+    pub fn close_candidates(&mut self) {
+        self.candidates = None;
+        self.current = Some(Panel::Outline);
+    }
+
+    pub fn is_listing_candidates(&self) -> bool {
+        matches!(self.current, Some(Panel::Candidates)) && self.candidates.is_some()
+    }
+
+    pub fn candidates_results(&mut self) -> Option<&mut Scroll<CandidateLines>> {
+        self.candidates.as_deref_mut().map(|c| &mut c.inner)
+    }
+
+    pub(super) fn update_candidates_area(&mut self, full: Rect) {
+        if let Some(candidates) = self.candidates.as_deref_mut() {
+            candidates.update_area(full);
+        }
+    }
+
+    pub(super) fn render_candidates(&self, buf: &mut Buffer) {
+        if let Some(candidates) = self
+            .candidates
+            .as_deref()
+            .filter(|_| self.is_listing_candidates())
+        {
+            candidates.render(buf);
+        }
+    }
+
+    /// Respond to left click when listing candidates by jumping to the clicked one.
+    pub(super) fn candidates_click(&mut self, position: Position) {
+        let Some(candidates) = self.candidates.as_deref() else {
+            return;
+        };
+        match candidates.click(position) {
+            PopupClick::Outside => self.close_candidates(),
+            PopupClick::Inner => self.jump_to_candidate(Some(position.y)),
+            PopupClick::Other => (),
+        }
+    }
+
+    /// Jump to the candidate under cursor, or on the given row position in screen.
+    /// A candidate from another doc will be followed after the doc is loaded.
+    pub fn jump_to_candidate(&mut self, y: Option<u16>) {
+        let Some(candidates) = self.candidates.take() else {
+            return;
+        };
+        let Some(jump) = candidates.get_jump(y) else {
+            self.candidates = Some(candidates);
+            return;
+        };
+        self.close_candidates();
+        match jump {
+            Jump::Id(id) => {
+                self.record_history();
+                if !self.outline.is_module_tree() {
+                    self.navi.reset_navi_outline();
+                    self.outline.reset_to_module_tree();
+                }
+                self.jump_to_id(id);
+            }
+            Jump::External(jump) => {
+                info!(?jump, "jump to the candidate in another doc");
+                self.external = Some(jump.clone());
+            }
+        }
+    }
+}
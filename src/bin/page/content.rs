@@ -80,7 +80,10 @@ impl ContentInner {
     }
 
     pub fn jumpable_id(&self, x: u16, y: u16) -> Option<ID> {
-        self.decl.display.jumpable_id(x, y)
+        self.decl
+            .display
+            .jumpable_id(x, y)
+            .or_else(|| self.md.jumpable_id(x, y))
     }
 }
 
@@ -185,7 +188,7 @@ impl LineState for DeclarationLine {
 }
 
 impl Declaration {
-    fn update_decl(&mut self, id: &str, map: &IDMap, width: u16) {
+    fn update_decl(&mut self, id: &str, map: &IDMap, _width: u16) {
         let lines = DeclarationLines::new(id, map);
         if lines.is_empty() {
             self.display.scroll_text().lines = Default::default();
@@ -0,0 +1,88 @@
+use super::{Page, Visit};
+use rustdoc_types::ItemKind;
+use term_rustdoc::{tree::IDMap, util::XString};
+
+/// An item defined in another crate, which is followed from the current Page.
+#[derive(Debug, Clone)]
+pub struct ExternalJump {
+    krate: XString,
+    path: Vec<String>,
+    kind: ItemKind,
+}
+
+impl ExternalJump {
+    fn new(map: &IDMap, id: &str) -> Option<Self> {
+        let (krate, item) = map.external_item(id)?;
+        Some(ExternalJump {
+            krate: krate.into(),
+            path: item.path.clone(),
+            kind: item.kind.clone(),
+        })
+    }
+
+    /// An item in the doc of another pkg.
+    pub(super) fn in_pkg(pkg_name: &str, map: &IDMap, id: &str) -> Option<Self> {
+        let item = map.get_path(id)?;
+        Some(ExternalJump {
+            krate: pkg_name.replace('-', "_").into(),
+            path: item.path.clone(),
+            kind: item.kind.clone(),
+        })
+    }
+
+    /// Crate name in rustdoc, i.e. the pkg name with `-` replaced by `_`.
+    pub fn krate(&self) -> &str {
+        &self.krate
+    }
+
+    pub fn is_in_pkg(&self, pkg_name: &str) -> bool {
+        pkg_name.replace('-', "_") == self.krate
+    }
+}
+
+impl Page {
+    /// Record the item to follow if it's defined in another crate.
+    /// Returns true if the item is recorded.
+    pub(super) fn set_external_jump(&mut self, id: &str) -> bool {
+        let map = self.outline.display_ref().lines.doc_ref();
+        let Some(jump) = ExternalJump::new(map, id) else {
+            return false;
+        };
+        info!(?jump, "the item is from another crate");
+        self.external = Some(jump);
+        true
+    }
+
+    /// The followed item from another crate, whose doc should be loaded to open a new Page.
+    pub fn take_external_jump(&mut self) -> Option<ExternalJump> {
+        self.external.take()
+    }
+
+    /// Jump to the item followed from the Page of another crate, whose visit is remembered
+    /// in history to go back to that tab.
+    pub fn jump_to_external(&mut self, jump: &ExternalJump, from: Option<Visit>) {
+        let map = self.outline.display_ref().lines.doc_ref();
+        match map.find_by_path(&jump.path, &jump.kind) {
+            Some(id) => {
+                self.record_history();
+                if let Some(from) = from {
+                    self.record_visit(from);
+                }
+                self.jump_to_id(&id);
+            }
+            None => error!(?jump, "the item is not found in the doc"),
+        }
+    }
+
+    /// Follow the item under the cursor in outline if it's from another crate,
+    /// like reexported external items.
+    pub fn follow_current_item(&mut self) {
+        let outline = self.outline.display_ref();
+        if let Some(id) = outline
+            .get_line_of_current_cursor()
+            .and_then(|l| l.id.clone())
+        {
+            self.set_external_jump(&id);
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use super::{Page, Panel};
+use crate::{
+    color::{BG_CURSOR_LINE, FG_FEATURES},
+    ui::{render_line, Popup, PopupClick, PopupContent, Surround},
+};
+use ratatui::{
+    layout::Position,
+    prelude::{Buffer, Rect, Style},
+};
+use term_rustdoc::util::{xformat, XString};
+
+/// A popup to choose a subset of features, and only items available with the subset
+/// are shown in outline.
+#[derive(Default)]
+pub(super) struct FeatureFilter {
+    /// Feature names and whether they're chosen.
+    features: Vec<(XString, bool)>,
+    /// Features in cfg attributes but not choosable, which are always considered enabled.
+    fixed: Vec<XString>,
+    cursor: usize,
+    /// The inner area of the popup border.
+    inner: Rect,
+}
+
+impl FeatureFilter {
+    /// All features are chosen at first, which means no item is filtered out.
+    fn new(features: Vec<XString>, fixed: Vec<XString>) -> Self {
+        FeatureFilter {
+            features: features.into_iter().map(|f| (f, true)).collect(),
+            fixed,
+            ..Default::default()
+        }
+    }
+
+    /// Enabled features, or None if all of them are chosen.
+    fn enabled(&self) -> Option<Box<[XString]>> {
+        if self.features.iter().all(|(_, chosen)| *chosen) {
+            return None;
+        }
+        let chosen = self.features.iter().filter(|(_, chosen)| *chosen);
+        let chosen = chosen.map(|(f, _)| f.clone());
+        Some(chosen.chain(self.fixed.iter().cloned()).collect())
+    }
+
+    fn toggle(&mut self, row: usize) {
+        if let Some((_, chosen)) = self.features.get_mut(row) {
+            *chosen = !*chosen;
+        }
+    }
+
+    /// Choose all features if any is not chosen, otherwise unchoose all.
+    fn toggle_all(&mut self) {
+        let all = !self.features.iter().all(|(_, chosen)| *chosen);
+        self.features
+            .iter_mut()
+            .for_each(|(_, chosen)| *chosen = all);
+    }
+
+    fn move_cursor(&mut self, forward: bool) {
+        let len = self.features.len();
+        if len == 0 {
+            return;
+        }
+        self.cursor = if forward {
+            (self.cursor + 1) % len
+        } else {
+            (self.cursor + len - 1) % len
+        };
+    }
+
+    /// The row of feature on the screen position.
+    fn row(&self, position: Position) -> Option<usize> {
+        let row = position.y.checked_sub(self.inner.y)? as usize;
+        (self.inner.contains(position) && row < self.features.len()).then_some(row)
+    }
+}
+
+impl PopupContent for FeatureFilter {
+    const SIZE: [u16; 2] = [40, 50];
+
+    fn set_inner(&mut self, inner: Rect) {
+        self.inner = inner;
+    }
+
+    fn render(&self, border: &Surround, buf: &mut Buffer) {
+        border.render_only_top_left_text(buf, " Filter Items By Features ", 0);
+
+        let Rect {
+            x,
+            mut y,
+            width,
+            height,
+        } = self.inner;
+        let width = width as usize;
+        if self.features.is_empty() {
+            let text = "No features to choose from.";
+            buf.set_stringn(x, y, text, width, Style::new());
+        }
+        // FIXME: no scrolling for now, since a crate rarely has too many features
+        let visible = self.features.iter().take(height as usize).enumerate();
+        for (row, (feature, chosen)) in visible {
+            let check = if *chosen { "[x] " } else { "[ ] " };
+            let mut style = Style::new().fg(FG_FEATURES);
+            if row == self.cursor {
+                style = style.bg(BG_CURSOR_LINE);
+                buf.set_style(Rect::new(x, y, width as u16, 1), style);
+            }
+            render_line(
+                [(check, style), (feature.as_str(), style)],
+                buf,
+                x,
+                y,
+                width,
+            );
+            y += 1;
+        }
+
+        let chosen = self.features.iter().filter(|(_, chosen)| *chosen).count();
+        let text = xformat!(" Chosen {chosen} / Total {} ", self.features.len());
+        border.render_only_bottom_right_text(buf, &text);
+    }
+}
+
+/// Feature filter popup.
+impl Page {
+    /// Features are from the PkgKey of the doc. If no feature is explicitly given
+    /// when compiling the doc, use the features in cfg attributes of items.
+    /// Features in cfg attributes but not in PkgKey are always considered enabled.
+    pub fn open_feature_filter(&mut self) {
+        if !self.outline.is_module_tree() {
+            return;
+        }
+        if self.filter.is_none() {
+            let explicit = self.pkg_key.as_ref().map(|key| key.features().explicit());
+            let explicit = explicit.unwrap_or_default().to_vec();
+            let mut in_cfg = self.outline().lines.doc_ref().cfg_features();
+            let (features, fixed) = if explicit.is_empty() {
+                (in_cfg, Vec::new())
+            } else {
+                // e.g. features enabled by default
+                in_cfg.retain(|f| !explicit.contains(f));
+                (explicit, in_cfg)
+            };
+            let filter = FeatureFilter::new(features, fixed);
+            self.filter = Some(Box::new(Popup::new(filter, self.area)));
+        }
+        self.current = Some(Panel::Filter);
+    }
+
+    pub fn close_feature_filter(&mut self) {
+        self.current = Some(Panel::Outline);
+    }
+
+    pub fn is_filtering(&self) -> bool {
+        matches!(self.current, Some(Panel::Filter)) && self.filter.is_some()
+    }
+
+    pub(super) fn update_filter_area(&mut self, full: Rect) {
+        if let Some(filter) = self.filter.as_deref_mut() {
+            filter.update_area(full);
+        }
+    }
+
+    pub(super) fn render_filter(&self, buf: &mut Buffer) {
+        if let Some(filter) = self.filter.as_deref().filter(|_| self.is_filtering()) {
+            filter.render(buf);
+        }
+    }
+
+    pub fn filter_move_cursor(&mut self, forward: bool) {
+        if let Some(filter) = self.filter.as_deref_mut() {
+            filter.move_cursor(forward);
+        }
+    }
+
+    /// Choose or unchoose the feature under cursor, or all features.
+    pub fn filter_toggle(&mut self, all: bool) {
+        let Some(filter) = self.filter.as_deref_mut() else {
+            return;
+        };
+        if all {
+            filter.toggle_all();
+        } else {
+            let cursor = filter.cursor;
+            filter.toggle(cursor);
+        }
+        self.apply_feature_filter();
+    }
+
+    /// Respond to left click when filtering by toggling the clicked feature.
+    pub(super) fn filter_click(&mut self, position: Position) {
+        let Some(filter) = self.filter.as_deref_mut() else {
+            return;
+        };
+        match filter.click(position) {
+            PopupClick::Outside => self.close_feature_filter(),
+            PopupClick::Inner => {
+                if let Some(row) = filter.row(position) {
+                    filter.cursor = row;
+                    filter.toggle(row);
+                    self.apply_feature_filter();
+                }
+            }
+            PopupClick::Other => (),
+        }
+    }
+
+    fn apply_feature_filter(&mut self) {
+        if let Some(filter) = self.filter.as_deref() {
+            let features = filter.enabled();
+            self.outline().lines.filter_features(features);
+            self.update_after_folding_outline();
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use super::{navi::NaviAction, Page, Panel};
+use crate::{
+    color::BG_CURSOR_LINE,
+    database::PkgKey,
+    ui::{render_line, LineState, Popup, PopupClick, PopupContent, Scroll, Surround},
+};
+use ratatui::{
+    layout::Position,
+    prelude::{Buffer, Color, Modifier, Rect, Style},
+};
+use term_rustdoc::{
+    tree::Fold,
+    util::{xformat, XString},
+};
+
+/// The maximum of visits kept in each direction.
+const CAPACITY: usize = 100;
+
+/// States to bring a visited item back.
+pub struct Visit {
+    /// The selected item in module tree.
+    id: Option<XString>,
+    fold: Fold,
+    /// Start and cursor of module tree.
+    modules: (usize, u16),
+    /// The action applied to outline, with start and cursor of the inner item tree.
+    action: Option<(NaviAction, usize, u16)>,
+    /// Start of content.
+    content: usize,
+    /// Shown in the history popup.
+    title: XString,
+    /// The visit is in the tab of another doc, which is switched to instead of
+    /// restoring the states above.
+    tab: Option<PkgKey>,
+}
+
+impl Visit {
+    fn is_same(&self, other: &Visit) -> bool {
+        self.id == other.id
+            && self.action.map(|a| a.0) == other.action.map(|a| a.0)
+            && self.content == other.content
+            && self.tab == other.tab
+    }
+}
+
+/// Browser-like back/forward stacks of visited items.
+#[derive(Default)]
+pub(super) struct History {
+    /// The last one is the most recent visit.
+    back: Vec<Visit>,
+    /// The last one is the visit to go forward to.
+    forward: Vec<Visit>,
+    popup: Option<Box<Popup<HistoryPopup>>>,
+    /// The tab to switch to after traveling to a visit in it, and whether it's going back.
+    tab: Option<(PkgKey, bool)>,
+}
+
+impl std::fmt::Debug for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "History {{ back: {}, forward: {} }}",
+            self.back.len(),
+            self.forward.len()
+        )
+    }
+}
+
+impl History {
+    fn push(&mut self, visit: Visit) {
+        if self.back.last().is_some_and(|last| last.is_same(&visit)) {
+            return;
+        }
+        if self.back.len() == CAPACITY {
+            self.back.remove(0);
+        }
+        self.back.push(visit);
+        self.forward.clear();
+    }
+
+    /// Keep the visit in the other tab on the opposite side of traveling direction,
+    /// so traveling in reverse switches to that tab again.
+    fn push_tab(&mut self, visit: Visit, back: bool, current: &Visit) {
+        let (ahead, behind) = if back {
+            (&mut self.back, &mut self.forward)
+        } else {
+            (&mut self.forward, &mut self.back)
+        };
+        // states were kept here when leaving this tab, and they're still current
+        if ahead.last().is_some_and(|last| last.is_same(current)) {
+            ahead.pop();
+        }
+        if behind.len() == CAPACITY {
+            behind.remove(0);
+        }
+        behind.push(visit);
+    }
+
+    /// Go back if steps is negative, or forward if positive.
+    /// The current visit is kept on the other side of the target.
+    fn travel(&mut self, steps: isize, current: Visit) -> Option<Visit> {
+        let (from, to) = if steps < 0 {
+            (&mut self.back, &mut self.forward)
+        } else {
+            (&mut self.forward, &mut self.back)
+        };
+        let steps = steps.unsigned_abs();
+        if steps == 0 || steps > from.len() {
+            return None;
+        }
+        to.push(current);
+        for _ in 1..steps {
+            to.extend(from.pop());
+        }
+        if to.len() > CAPACITY {
+            to.drain(..to.len() - CAPACITY);
+        }
+        from.pop()
+    }
+}
+
+/// A popup to list visits in history.
+#[derive(Default)]
+struct HistoryPopup {
+    inner: Scroll<HistoryLines>,
+}
+
+#[derive(Default)]
+pub struct HistoryLines {
+    lines: Vec<HistoryLine>,
+}
+
+impl std::ops::Deref for HistoryLines {
+    type Target = [HistoryLine];
+
+    fn deref(&self) -> &Self::Target {
+        &self.lines
+    }
+}
+
+pub struct HistoryLine {
+    title: XString,
+    /// Negative for going back, positive for going forward, and zero for the current one.
+    steps: isize,
+}
+
+impl LineState for HistoryLine {
+    type State = isize;
+
+    fn state(&self) -> Self::State {
+        self.steps
+    }
+
+    fn is_identical(&self, state: &Self::State) -> bool {
+        self.steps == *state
+    }
+}
+
+impl HistoryPopup {
+    /// Forward visits are on top with the farthest first, then the current one,
+    /// and back visits with the most recent first.
+    fn new(history: &History, current: XString, full: Rect) -> Popup<Self> {
+        let forward = history
+            .forward
+            .iter()
+            .zip((1..=history.forward.len() as isize).rev());
+        let forward = forward.map(|(v, steps)| (v.title.clone(), steps));
+        let back = history.back.iter().rev().zip(1..);
+        let back = back.map(|(v, steps)| (v.title.clone(), -steps));
+        let lines = forward
+            .chain([(current, 0)])
+            .chain(back)
+            .map(|(title, steps)| HistoryLine { title, steps })
+            .collect();
+        let popup = HistoryPopup {
+            inner: Scroll {
+                lines: HistoryLines { lines },
+                ..Default::default()
+            },
+        };
+        let mut popup = Popup::new(popup, full);
+        // place the cursor on the current visit
+        let pos = history.forward.len();
+        let inner = &mut popup.inner;
+        inner.start = pos.saturating_sub(inner.area.height as usize / 2);
+        inner.set_cursor((pos - inner.start) as u16);
+        popup
+    }
+
+    fn get_steps(&self, y: Option<u16>) -> Option<isize> {
+        let line = y.map_or_else(
+            || self.inner.get_line_of_current_cursor(),
+            |y| self.inner.get_line_on_screen(y),
+        )?;
+        Some(line.steps)
+    }
+}
+
+impl PopupContent for HistoryPopup {
+    const SIZE: [u16; 2] = [60, 50];
+
+    fn set_inner(&mut self, inner: Rect) {
+        self.inner.area = inner;
+    }
+
+    fn render(&self, border: &Surround, buf: &mut Buffer) {
+        border.render_only_top_left_text(buf, " History ", 0);
+
+        let text = &self.inner;
+        let Some(lines) = text.visible_lines() else {
+            return;
+        };
+        if text.get_line_of_current_cursor().is_some() {
+            text.highlight_current_line(buf, |cell| {
+                cell.set_bg(BG_CURSOR_LINE);
+            });
+        }
+        let Rect { x, mut y, .. } = text.area;
+        let width = text.area.width as usize;
+        let gray = Style::new().fg(Color::DarkGray);
+        for line in lines {
+            let (mark, style) = match line.steps {
+                0 => (
+                    "● ",
+                    Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+                steps if steps > 0 => ("  ", gray),
+                _ => ("  ", Style::new()),
+            };
+            render_line(
+                [(mark, style), (line.title.as_str(), style)],
+                buf,
+                x,
+                y,
+                width,
+            );
+            y += 1;
+        }
+    }
+}
+
+/// Navigation history.
+impl Page {
+    pub(super) fn visit(&self) -> Visit {
+        let modules = self.outline.modules_ref();
+        let id = modules.get_id().map(XString::from);
+        let action = if self.outline.is_module_tree() {
+            None
+        } else {
+            let inner = self.outline.display_ref();
+            let action = self.navi.current_action();
+            action.map(|a| (a, inner.start, inner.cursor.y))
+        };
+        let mut title = match &id {
+            Some(id) => modules.lines.doc_ref().path(id),
+            None => XString::new_inline("(no item)"),
+        };
+        if let Some((action, ..)) = action {
+            title = xformat!("{title} » {}", action.text());
+        }
+        Visit {
+            id,
+            fold: modules.lines.fold_state(),
+            modules: (modules.start, modules.cursor.y),
+            action,
+            content: self.content.inner.md_ref().start,
+            title,
+            tab: None,
+        }
+    }
+
+    /// The current states to switch back to this tab from another one.
+    pub fn tab_visit(&self) -> Option<Visit> {
+        let tab = self.pkg_key.clone()?;
+        Some(Visit {
+            tab: Some(tab),
+            ..self.visit()
+        })
+    }
+
+    /// Remember the current states before jumping to another item.
+    pub(super) fn record_history(&mut self) {
+        if self.pkg_key.is_some() {
+            let visit = self.visit();
+            self.history.push(visit);
+        }
+    }
+
+    /// Remember the states taken before a change like applying another navi action,
+    /// or the visit in the tab where a jump to this tab comes from.
+    pub(super) fn record_visit(&mut self, visit: Visit) {
+        if self.pkg_key.is_some() {
+            self.history.push(visit);
+        }
+    }
+
+    /// Take the tab traveled to, which is switched to by Tabs in the frame.
+    pub fn take_tab_in_history(&mut self) -> Option<(PkgKey, bool)> {
+        self.history.tab.take()
+    }
+
+    /// Remember the visit in the tab traveled from.
+    pub fn keep_tab_in_history(&mut self, visit: Visit, back: bool) {
+        let current = self.visit();
+        self.history.push_tab(visit, back, &current);
+    }
+
+    fn restore(&mut self, visit: Visit) {
+        info!(title = %visit.title, "restore the visit in history");
+        if !self.outline.is_module_tree() {
+            self.navi.reset_navi_outline();
+            self.outline.reset_to_module_tree();
+        }
+        self.outline().lines.restore_fold(visit.fold);
+        self.update_after_folding_outline();
+
+        // lines may differ due to filters changed since the visit, thus locate the item by id
+        let (start, y) = visit.modules;
+        let pos = visit
+            .id
+            .as_deref()
+            .and_then(|id| self.position_in_outline(id));
+        let pos = pos.unwrap_or(start + y as usize);
+        let height = self.outline().area.height as usize;
+        let start = if (start..start + height).contains(&pos) {
+            start
+        } else {
+            pos.saturating_sub(6)
+        };
+        self.outline().start = start;
+        self.outline().set_cursor((pos - start) as u16);
+        self.update_content();
+
+        if let Some((action, start, y)) = visit.action {
+            if self.navi.set_action(action) {
+                self.outline.action(action);
+                self.update_area_inner(self.area);
+                self.outline().start = start;
+                self.outline().set_cursor(y);
+                self.update_content();
+            }
+        }
+
+        let total_len = self.content.inner.md_ref().total_len();
+        self.content().start = visit.content.min(total_len.saturating_sub(1));
+    }
+
+    /// Go back if steps is negative, or forward if positive.
+    fn travel(&mut self, steps: isize) {
+        let current = self.visit();
+        let Some(visit) = self.history.travel(steps, current) else {
+            return;
+        };
+        match visit.tab {
+            Some(tab) => self.history.tab = Some((tab, steps < 0)),
+            None => self.restore(visit),
+        }
+    }
+
+    pub fn history_back(&mut self) {
+        self.travel(-1);
+    }
+
+    pub fn history_forward(&mut self) {
+        self.travel(1);
+    }
+
+    pub fn open_history(&mut self) {
+        let current = self.visit().title;
+        let popup = HistoryPopup::new(&self.history, current, self.area);
+        self.history.popup = Some(Box::new(popup));
+        self.current = Some(Panel::History);
+    }
+
+    pub fn close_history(&mut self) {
+        self.history.popup = None;
+        self.current = Some(Panel::Outline);
+    }
+
+    pub fn is_listing_history(&self) -> bool {
+        matches!(self.current, Some(Panel::History)) && self.history.popup.is_some()
+    }
+
+    pub fn history_results(&mut self) -> Option<&mut Scroll<HistoryLines>> {
+        self.history.popup.as_deref_mut().map(|p| &mut p.inner)
+    }
+
+    pub(super) fn update_history_area(&mut self, full: Rect) {
+        if let Some(popup) = self.history.popup.as_deref_mut() {
+            popup.update_area(full);
+        }
+    }
+
+    pub(super) fn render_history(&self, buf: &mut Buffer) {
+        if let Some(popup) = self
+            .history
+            .popup
+            .as_deref()
+            .filter(|_| self.is_listing_history())
+        {
+            popup.render(buf);
+        }
+    }
+
+    /// Respond to left click when listing history by going to the clicked visit.
+    pub(super) fn history_click(&mut self, position: Position) {
+        let Some(popup) = self.history.popup.as_deref() else {
+            return;
+        };
+        match popup.click(position) {
+            PopupClick::Outside => self.close_history(),
+            PopupClick::Inner => self.jump_to_history(Some(position.y)),
+            PopupClick::Other => (),
+        }
+    }
+
+    /// Go to the visit under cursor, or on the given row position in screen.
+    pub fn jump_to_history(&mut self, y: Option<u16>) {
+        let Some(steps) = self.history.popup.as_deref().and_then(|p| p.get_steps(y)) else {
+            return;
+        };
+        self.close_history();
+        self.travel(steps);
+    }
+}
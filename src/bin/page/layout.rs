@@ -74,6 +74,11 @@ impl Page {
             a_navi,
         ));
 
+        self.update_search_area(full);
+        self.update_filter_area(full);
+        self.update_candidates_area(full);
+        self.update_history_area(full);
+
         // auto update content when screen size changes
         self.update_content();
     }
@@ -1,16 +1,30 @@
+pub use self::{external::ExternalJump, history::Visit, source::Location};
+
 use self::{
     navi::{NaviAction, Navigation},
     panel::Panel,
 };
 use crate::{
-    database::PkgKey,
-    ui::{scrollable::ScrollTreeLines, Surround},
+    database::{LoadedDocs, PkgKey},
+    ui::{scrollable::ScrollTreeLines, Popup, Surround},
     Result,
 };
 use ratatui::prelude::{Buffer, Rect, Widget};
-use term_rustdoc::tree::{CrateDoc, ID};
+use std::path::PathBuf;
+use term_rustdoc::{
+    tree::{CrateDoc, ID},
+    util::XString,
+};
 
+/// concrete types for generic parameters and traits
+mod candidates;
 mod content;
+/// follow items defined in other crates
+mod external;
+/// filter items in outline by features
+mod filter;
+/// back/forward navigation history
+mod history;
 mod layout;
 mod navi;
 mod outline;
@@ -19,6 +33,10 @@ mod page_fold;
 /// scroll up/down behavior and with what offset
 mod page_scroll;
 mod panel;
+/// fuzzy search items in the whole crate
+mod search;
+/// source code of items
+mod source;
 
 #[derive(Default, Debug)]
 pub struct Page {
@@ -26,7 +44,19 @@ pub struct Page {
     content: Content,
     navi: Navigation,
     current: Option<Panel>,
+    search: Option<Box<Popup<search::ItemSearch>>>,
+    filter: Option<Box<Popup<filter::FeatureFilter>>>,
+    candidates: Option<Box<Popup<candidates::Candidates>>>,
+    history: history::History,
     pkg_key: Option<PkgKey>,
+    /// The followed item from another crate.
+    external: Option<ExternalJump>,
+    /// The local pkg dir where the doc was compiled.
+    pkg_dir: Option<PathBuf>,
+    /// The title of source file if the content shows source code instead of doc.
+    source: Option<XString>,
+    /// The item location to open in an editor.
+    editor: Option<Location>,
     area: Rect,
 }
 
@@ -43,6 +73,14 @@ impl Page {
             area,
             pkg_key: Some(pkg_key),
             navi: Default::default(),
+            search: None,
+            filter: None,
+            candidates: None,
+            history: Default::default(),
+            external: None,
+            pkg_dir: None,
+            source: None,
+            editor: None,
         };
         page.update_area_inner(area);
         info!(?area, "Page ready");
@@ -70,8 +108,21 @@ impl Page {
             .unwrap_or(false)
         {
             *self = Page::default();
+        } else {
+            self.outline.inner.drop_other_doc(pkg_key);
         }
     }
+
+    pub fn pkg_key(&self) -> Option<&PkgKey> {
+        self.pkg_key.as_ref()
+    }
+
+    /// Docs loaded in database except the one for this Page.
+    pub fn set_other_docs(&mut self, docs: LoadedDocs) {
+        self.outline
+            .inner
+            .set_other_docs(docs, self.pkg_key.clone());
+    }
 }
 
 impl Widget for &mut Page {
@@ -80,8 +131,15 @@ impl Widget for &mut Page {
         self.update_area(area);
         self.outline.render(buf);
         self.content.border.render(buf);
+        if let Some(title) = &self.source {
+            self.content.border.render_only_top_left_text(buf, title, 0);
+        }
         self.content.inner.render(buf);
         self.navi.render(buf, self.content.inner.md_ref());
+        self.render_search(buf);
+        self.render_filter(buf);
+        self.render_candidates(buf);
+        self.render_history(buf);
         debug!("Page rendered");
     }
 }
@@ -121,6 +179,10 @@ impl Outline {
         self.inner.display_ref()
     }
 
+    fn modules_ref(&self) -> &ScrollTreeLines {
+        self.inner.modules_ref()
+    }
+
     fn update_area(&mut self, border: Surround) {
         self.inner.update_area(border.inner());
         self.border = border;
@@ -76,6 +76,14 @@ impl Navigation {
     pub fn previous_action(&mut self) -> Option<NaviAction> {
         self.display.outline.previous_action()
     }
+
+    pub fn current_action(&self) -> Option<NaviAction> {
+        self.display.outline.current_action()
+    }
+
+    pub fn set_action(&mut self, action: NaviAction) -> bool {
+        self.display.outline.set_action(action)
+    }
 }
 
 #[derive(Default)]
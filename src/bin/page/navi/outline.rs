@@ -69,6 +69,8 @@ fn lines(kind: Kind) -> &'static [NaviAction] {
         Kind::Enum => ENUM,
         Kind::Trait => TRAIT,
         Kind::Module => MODULE,
+        Kind::Function => FUNCTION,
+        Kind::Primitive => PRIMITIVE,
     }
 }
 
@@ -77,18 +79,34 @@ const STRUCT: &'static [NaviAction] = &[
     NaviAction::Item,
     NaviAction::StructInner,
     NaviAction::ITABImpls,
+    NaviAction::Constructors,
+    NaviAction::Consumers,
     NaviAction::BackToHome,
 ];
 const ENUM: &'static [NaviAction] = &[
     NaviAction::Item,
     NaviAction::EnumInner,
     NaviAction::ITABImpls,
+    NaviAction::Constructors,
+    NaviAction::Consumers,
+    NaviAction::BackToHome,
+];
+const FUNCTION: &'static [NaviAction] = &[
+    NaviAction::Item,
+    NaviAction::ArgsTypes,
+    NaviAction::ReturnType,
+    NaviAction::BackToHome,
+];
+const PRIMITIVE: &'static [NaviAction] = &[
+    NaviAction::Item,
+    NaviAction::ITABImpls,
     NaviAction::BackToHome,
 ];
 const TRAIT: &'static [NaviAction] = &[
     NaviAction::Item,
     NaviAction::TraitAssociated,
     NaviAction::TraitImplementors,
+    NaviAction::TraitHierarchy,
     NaviAction::BackToHome,
 ];
 
@@ -98,20 +116,30 @@ pub enum NaviAction {
     EnumInner,
     TraitAssociated,
     TraitImplementors,
+    TraitHierarchy,
     ITABImpls,
+    Constructors,
+    Consumers,
+    ArgsTypes,
+    ReturnType,
     Item,
     #[default]
     BackToHome,
 }
 
 impl NaviAction {
-    fn text(self) -> &'static str {
+    pub fn text(self) -> &'static str {
         match self {
             NaviAction::StructInner => "Fields",
             NaviAction::EnumInner => "Varaints",
             NaviAction::TraitAssociated => "Associated",
             NaviAction::TraitImplementors => "Implementors",
+            NaviAction::TraitHierarchy => "Hierarchy",
             NaviAction::ITABImpls => "Impls",
+            NaviAction::Constructors => "Constructors",
+            NaviAction::Consumers => "Used As Arguments",
+            NaviAction::ArgsTypes => "Args Types",
+            NaviAction::ReturnType => "Return Type",
             NaviAction::Item => "Current Item",
             NaviAction::BackToHome => "Back To Home",
         }
@@ -123,7 +151,7 @@ impl NaviAction {
 }
 
 pub fn height() -> u16 {
-    [STRUCT.len(), ENUM.len(), TRAIT.len()]
+    [STRUCT.len(), ENUM.len(), TRAIT.len(), FUNCTION.len()]
         .into_iter()
         .max()
         .unwrap_or(0) as u16
@@ -131,7 +159,7 @@ pub fn height() -> u16 {
 }
 
 pub fn width() -> u16 {
-    [STRUCT, ENUM, TRAIT]
+    [STRUCT, ENUM, TRAIT, FUNCTION]
         .map(|val| val.iter().map(|s| s.len()))
         .into_iter()
         .flatten()
@@ -218,6 +246,23 @@ impl NaviOutline {
         self.display.get_line_of_current_cursor().copied()
     }
 
+    /// The action under cursor, which is the one applied to outline.
+    pub fn current_action(&self) -> Option<NaviAction> {
+        self.display.get_line_of_current_cursor().copied()
+    }
+
+    /// Move the cursor to the action. Returns false if the action isn't for the item.
+    pub fn set_action(&mut self, action: NaviAction) -> bool {
+        match self.display.lines.iter().position(|&a| a == action) {
+            Some(pos) => {
+                self.display.start = 0;
+                self.display.set_cursor(pos as u16);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn previous_action(&mut self) -> Option<NaviAction> {
         if self.display.cursor.y == 0 && self.display.start == 0 {
             self.display.move_bottom_cursor();
@@ -1,7 +1,10 @@
 use super::navi::NaviAction;
-use crate::ui::scrollable::ScrollTreeLines;
+use crate::{
+    database::{LoadedDocs, PkgKey},
+    ui::scrollable::ScrollTreeLines,
+};
 use ratatui::prelude::{Buffer, Rect};
-use term_rustdoc::tree::{CrateDoc, TreeLines, ID};
+use term_rustdoc::tree::{CrateDoc, DocTree, IDMap, TreeLines, ID};
 
 #[derive(Default)]
 pub struct OutlineInner {
@@ -51,6 +54,11 @@ impl OutlineInner {
         }
     }
 
+    /// The module tree even if an inner item tree is displayed.
+    pub fn modules_ref(&self) -> &ScrollTreeLines {
+        &self.modules
+    }
+
     pub fn update_area(&mut self, area: Rect) {
         self.modules.area = area;
         self.setu.update_area(area);
@@ -70,6 +78,18 @@ impl OutlineInner {
         self.setu.outer_item = id;
     }
 
+    /// Docs of other crates to look up items like Deref targets in.
+    /// The doc of `own` is skipped if it's among the loaded docs.
+    pub fn set_other_docs(&mut self, docs: LoadedDocs, own: Option<PkgKey>) {
+        self.setu.others = docs;
+        self.setu.own = own;
+    }
+
+    pub fn drop_other_doc(&mut self, pkg_key: &PkgKey) {
+        let others = self.setu.others.iter().filter(|(key, _)| key != pkg_key);
+        self.setu.others = others.cloned().collect();
+    }
+
     pub fn action(&mut self, action: NaviAction) {
         match action {
             NaviAction::BackToHome => self.back_to_home(),
@@ -108,6 +128,10 @@ pub enum OutlineKind {
 pub struct Setu {
     outer_item: ID,
     display: ScrollTreeLines,
+    /// Loaded docs shared among tabs.
+    others: LoadedDocs,
+    /// The key of the doc for this outline, which is excluded from `others`.
+    own: Option<PkgKey>,
 }
 
 impl Setu {
@@ -117,17 +141,36 @@ impl Setu {
 
     pub fn update_lines(&mut self, modules: &ScrollTreeLines, action: NaviAction) -> Option<()> {
         let doc = modules.lines.doc_ref();
+        let others: Vec<&IDMap> = (self.others.iter())
+            .filter(|(key, _)| Some(key) != self.own.as_ref())
+            .map(|(_, doc)| &**doc)
+            .collect();
+        // An item from another crate has details in the doc of that crate if it's loaded.
+        let external = doc.find_in_others(&self.outer_item, &others);
         // If id is not valid, lines won't be updated.
         self.display.lines = TreeLines::try_new_with(doc, |map| {
-            let id = &self.outer_item;
+            let (map, id) = match &external {
+                Some((other, id)) => (*other, id),
+                None => (&**map, &self.outer_item),
+            };
             let dmod = map.dmodule();
-            match action {
-                NaviAction::ITABImpls => dmod.impl_tree(id, map),
+            let tree = match action {
+                NaviAction::ITABImpls => dmod.impl_tree_with_deref(id, map, &others),
                 NaviAction::Item => dmod.item_inner_tree(id, map),
                 NaviAction::TraitAssociated => dmod.associated_item_tree(id, map),
                 NaviAction::TraitImplementors => dmod.implementor_tree(id, map),
+                NaviAction::TraitHierarchy => dmod.trait_hierarchy_tree(id, map),
                 NaviAction::StructInner | NaviAction::EnumInner => dmod.field_tree(id, map),
+                NaviAction::Constructors => dmod.constructor_tree(id, map),
+                NaviAction::Consumers => dmod.consumer_tree(id, map),
+                NaviAction::ArgsTypes => dmod.args_tree(id, map),
+                NaviAction::ReturnType => dmod.return_tree(id, map),
                 _ => dmod.item_inner_tree(id, map),
+            };
+            if external.is_some() {
+                tree.map(DocTree::without_ids)
+            } else {
+                tree
             }
         })?;
         if self.display.total_len() == 0 {
@@ -10,6 +10,16 @@ impl Page {
         self.update_after_folding_outline();
     }
 
+    /// Expand folded modules on the way to the item to show it.
+    pub fn outline_fold_expand_to(&mut self, id: &str) {
+        if !self.outline.is_module_tree() {
+            return;
+        }
+        if self.outline().lines.expand_to_item(id) {
+            self.update_after_folding_outline();
+        }
+    }
+
     pub fn outline_fold_expand_current_module_only(&mut self) {
         if !self.outline.is_module_tree() {
             return;
@@ -46,7 +56,15 @@ impl Page {
         }
     }
 
-    fn update_after_folding_outline(&mut self) {
+    pub fn outline_toggle_deprecated(&mut self) {
+        if !self.outline.is_module_tree() {
+            return;
+        }
+        self.outline().lines.toggle_deprecated();
+        self.update_after_folding_outline();
+    }
+
+    pub(super) fn update_after_folding_outline(&mut self) {
         self.update_area_inner(self.area);
 
         let outline = self.outline();
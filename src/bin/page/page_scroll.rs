@@ -123,13 +123,8 @@ impl Page {
     /// update content's StyledLines and Headings aftet setting the cursor
     pub fn update_content(&mut self) {
         if let Some(id) = self.outline.display().get_id() {
-            if let Some(headings) = self.content.update_doc(id) {
-                // Only reset start after the update.
-                // TODO: would it be better to remember the
-                // view position if doc is able to be cached?
-                // self.content.update_content(id);
-                self.navi.heading().update_headings(headings);
-            }
+            let id = id.to_owned();
+            self.show_doc(&id);
         } else {
             self.content.inner.reset_doc();
             self.navi.heading().lines = Default::default();
@@ -137,6 +132,18 @@ impl Page {
         self.update_navi();
     }
 
+    /// Update content's StyledLines and Headings for the given item.
+    pub(super) fn show_doc(&mut self, id: &str) {
+        self.source = None;
+        if let Some(headings) = self.content.update_doc(id) {
+            // Only reset start after the update.
+            // TODO: would it be better to remember the
+            // view position if doc is able to be cached?
+            // self.content.update_content(id);
+            self.navi.heading().update_headings(headings);
+        }
+    }
+
     fn update_navi(&mut self) {
         // update navi only when in Module tree
         if let Some(doc) = self.content.inner.md_ref().doc_ref() {
@@ -184,13 +191,15 @@ impl Page {
                 }
             }
         }
-        let iter = &mut outline.lines.iter();
-        if let Some(pos) = iter.position(|l| {
-            l.id.as_deref()
-                // .map(|src| src == id)
-                .map(|src| map.is_same_id(src, id))
-                .unwrap_or(false)
-        }) {
+        // the item may be reexported in this crate, so try jumping to it in outline first,
+        // and follow it to another crate only if it's not shown here
+        let mut pos = self.position_in_outline(id);
+        if pos.is_none() && !self.set_external_jump(id) && self.outline.is_module_tree() {
+            // the local item may be hidden in folded modules
+            self.outline_fold_expand_to(id);
+            pos = self.position_in_outline(id);
+        }
+        if let Some(pos) = pos {
             let start = pos.saturating_sub(6);
             let y = (pos - start) as u16;
             self.outline().start = start;
@@ -200,8 +209,37 @@ impl Page {
                 "succeed to jump to {:?}",
                 self.outline().lines.doc_ref().path(id)
             );
-        } else {
+        } else if self.external.is_none() {
+            let map = self.outline.display_ref().lines.doc_ref();
             error!(?id, path = %map.path(id), "unable to jump to");
         }
     }
+
+    pub(super) fn position_in_outline(&self, id: &str) -> Option<usize> {
+        let outline = self.outline.display_ref();
+        let map = outline.lines.doc_ref();
+        outline.lines.iter().position(|l| {
+            l.id.as_deref()
+                // .map(|src| src == id)
+                .map(|src| map.is_same_id(src, id))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Select the next (or previous if `forward` is false) local item link in content panel.
+    pub fn select_item_link(&mut self, forward: bool) {
+        self.content().select_item_link(forward);
+    }
+
+    /// Jump to the item that selected item link points to.
+    /// If no link is selected, follow the current item when it's from another crate.
+    pub fn jump_to_selected_item_link(&mut self) {
+        if let Some((id, _)) = self.content().selected_item_link() {
+            let id = id.clone();
+            self.record_history();
+            self.jump_to_id(&id);
+        } else {
+            self.follow_current_item();
+        }
+    }
 }
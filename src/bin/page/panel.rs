@@ -1,3 +1,4 @@
+use super::navi::{NaviAction, Navigation};
 use crate::color::{NEW, SET};
 
 #[derive(Debug)]
@@ -5,6 +6,14 @@ pub enum Panel {
     Outline,
     Content,
     Navigation,
+    /// Item search popup.
+    Search,
+    /// Feature filter popup.
+    Filter,
+    /// Candidates popup.
+    Candidates,
+    /// History popup.
+    History,
 }
 
 impl super::Page {
@@ -27,6 +36,22 @@ impl super::Page {
             }};
         }
         let position = (x, y).into();
+        if self.is_searching() {
+            self.search_click(position);
+            return;
+        }
+        if self.is_filtering() {
+            self.filter_click(position);
+            return;
+        }
+        if self.is_listing_candidates() {
+            self.candidates_click(position);
+            return;
+        }
+        if self.is_listing_history() {
+            self.history_click(position);
+            return;
+        }
         // Block area covers border and its inner
         self.current = if self.outline.border.area().contains(position) {
             self.outline().set_cursor(y);
@@ -34,6 +59,7 @@ impl super::Page {
             set!(outline)
         } else if self.content.border.area().contains(position) {
             if let Some(id) = self.content.jumpable_id(x, y) {
+                self.record_history();
                 self.jump_to_id(&id);
             }
             set!(content)
@@ -41,7 +67,7 @@ impl super::Page {
             if self.heading_jump(y) {
                 // succeed to jump to a heading, thus focus on content panel
                 set!(content)
-            } else if let Some(action) = self.navi.update_outline(y) {
+            } else if let Some(action) = self.navi_action(|navi| navi.update_outline(y)) {
                 self.outline.action(action);
                 self.update_area_inner(self.area);
                 set!(outline)
@@ -54,8 +80,22 @@ impl super::Page {
         info!(?self.current);
     }
 
+    /// Pick a navi action, and remember the states before it in history if it changes.
+    fn navi_action(
+        &mut self,
+        pick: impl FnOnce(&mut Navigation) -> Option<NaviAction>,
+    ) -> Option<NaviAction> {
+        let before = self.visit();
+        let previous = self.navi.current_action();
+        let action = pick(&mut self.navi)?;
+        if previous != Some(action) {
+            self.record_visit(before);
+        }
+        Some(action)
+    }
+
     pub fn set_next_action(&mut self) {
-        let next_action = self.navi.next_action();
+        let next_action = self.navi_action(|navi| navi.next_action());
         debug!(?next_action);
         if let Some(action) = next_action {
             self.outline.action(action);
@@ -65,7 +105,7 @@ impl super::Page {
     }
 
     pub fn set_previous_action(&mut self) {
-        let next_action = self.navi.previous_action();
+        let next_action = self.navi_action(|navi| navi.previous_action());
         debug!(?next_action);
         if let Some(action) = next_action {
             self.outline.action(action);
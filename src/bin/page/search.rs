@@ -0,0 +1,583 @@
+use super::{Page, Panel};
+use crate::{
+    color::{BG_CURSOR_LINE, SEARCH_MATCH},
+    fuzzy::Fuzzy,
+    ui::{
+        render_line, JoinedRows, LineState, Popup, PopupClick, PopupContent, Scroll, ScrollOffset,
+        Surround,
+    },
+};
+use ratatui::{
+    layout::Position,
+    prelude::{Buffer, Color, Constraint, Layout, Rect, Style},
+    widgets::{Block, Borders},
+};
+use std::collections::HashSet;
+use term_rustdoc::{
+    tree::{CrateDoc, ItemPath},
+    type_name::{FnSignature, SignatureQuery, TraitImpls},
+    util::{hashmap, xformat},
+};
+
+/// A popup to fuzzy search items by full paths or search documentation in the whole crate.
+#[derive(Default)]
+pub(super) struct ItemSearch {
+    input: String,
+    input_border: Surround,
+    inner: Scroll<ItemPaths>,
+}
+
+#[derive(Clone, Copy, Default, Debug)]
+enum Source {
+    /// Fuzzy match item paths.
+    #[default]
+    ItemPaths,
+    /// Match a phrase in documentation of items.
+    Docs,
+    /// Match functions and methods by type signatures like `&str -> Result<_, Error>`.
+    Signatures,
+}
+
+#[derive(Default)]
+pub struct ItemPaths {
+    all: Box<[ItemPath]>,
+    /// Index of items that have unique docs and the docs rendered in plain text, which are
+    /// built at the first full-text search.
+    ///
+    /// Reexported items share the same docs with the original ones, thus only the first
+    /// item with the docs is searched.
+    documented: Option<Box<[(usize, JoinedRows)]>>,
+    /// Function signatures, which are built at the first signature search.
+    signatures: Option<Signatures>,
+    doc: CrateDoc,
+    hits: Vec<Hit>,
+    fuzzy: Option<Fuzzy>,
+    source: Source,
+}
+
+impl ItemPaths {
+    fn new(doc: &CrateDoc) -> Self {
+        let all: Box<[_]> = doc.dmodule().item_paths(doc).into();
+        info!("Found {} items to search in {doc:?}", all.len());
+        ItemPaths {
+            hits: (0..all.len()).map(Hit::item).collect(),
+            all,
+            doc: doc.clone(),
+            fuzzy: Some(Fuzzy::new()),
+            ..Default::default()
+        }
+    }
+
+    fn update_search(&mut self, pattern: &str) {
+        if pattern.is_empty() {
+            self.force_all();
+            return;
+        }
+        match self.source {
+            Source::ItemPaths => self.update_path_search(pattern),
+            Source::Docs => self.update_doc_search(pattern),
+            Source::Signatures => self.update_signature_search(pattern),
+        }
+    }
+
+    fn update_path_search(&mut self, pattern: &str) {
+        struct Ele<'s>(&'s str, usize);
+        impl AsRef<str> for Ele<'_> {
+            fn as_ref(&self) -> &str {
+                self.0
+            }
+        }
+        impl From<Ele<'_>> for Hit {
+            fn from(value: Ele<'_>) -> Self {
+                Hit::item(value.1)
+            }
+        }
+
+        if let Some(fuzzy) = &self.fuzzy {
+            fuzzy.parse(pattern);
+            let iter = self.all.iter().enumerate();
+            let iter = iter.map(|(idx, item)| Ele(&item.path, idx));
+            fuzzy.match_list(iter, &mut self.hits);
+        }
+    }
+
+    fn update_doc_search(&mut self, pattern: &str) {
+        let ItemPaths {
+            all,
+            documented,
+            doc,
+            hits,
+            fuzzy: Some(fuzzy),
+            ..
+        } = self
+        else {
+            return;
+        };
+        let documented = documented.get_or_insert_with(|| {
+            let mut seen = HashSet::new();
+            let iter = all.iter().enumerate().filter_map(|(idx, item)| {
+                let text = doc.get_doc(&item.id).filter(|text| !text.is_empty())?;
+                let unique = seen.insert(text.as_ptr());
+                // match against the rendered text, since a phrase in markdown source can
+                // be separated by line breaks or markups
+                unique.then(|| (idx, JoinedRows::doc(doc, &item.id)))
+            });
+            iter.collect()
+        });
+        fuzzy.parse_substring(pattern);
+        hits.clear();
+        let mut indices = Vec::new();
+        for (item, rows) in documented.iter() {
+            let text = rows.text();
+            let Some(score) = fuzzy.indices(text, &mut indices).filter(|s| *s > 0) else {
+                continue;
+            };
+            let matched = matched_range(text, &indices);
+            let nth = rows.count_before(&text[matched[0]..matched[1]], matched[0]);
+            hits.push(Hit {
+                item: *item,
+                doc: Some(DocHit {
+                    nth,
+                    matched,
+                    score,
+                }),
+                sig: None,
+            });
+        }
+        // stable sort to keep the order of module tree for the same score
+        hits.sort_by_key(|hit| std::cmp::Reverse(hit.doc.map_or(0, |hit| hit.score)));
+    }
+
+    fn update_signature_search(&mut self, pattern: &str) {
+        let ItemPaths {
+            all,
+            signatures,
+            doc,
+            hits,
+            ..
+        } = self;
+        let Signatures { sigs, impls } =
+            signatures.get_or_insert_with(|| Signatures::new(doc, all));
+        hits.clear();
+        let Some(query) = SignatureQuery::parse(pattern) else {
+            return;
+        };
+        let mut scores = Vec::new();
+        for (sig, (item, fn_sig)) in sigs.iter().enumerate() {
+            if let Some(score) = fn_sig.score(&query, impls) {
+                hits.push(Hit {
+                    item: *item,
+                    doc: None,
+                    sig: Some(sig),
+                });
+                scores.push(score);
+            }
+        }
+        // stable sort to keep the order of module tree for the same score
+        let mut sorted: Vec<_> = scores.into_iter().zip(hits.drain(..)).collect();
+        sorted.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        hits.extend(sorted.into_iter().map(|(_, hit)| hit));
+    }
+
+    /// clear the filter result and fill with all items back
+    fn force_all(&mut self) {
+        self.hits.clear();
+        self.hits.extend((0..self.all.len()).map(Hit::item));
+    }
+
+    fn switch_source(&mut self) {
+        self.source = match self.source {
+            Source::ItemPaths => Source::Docs,
+            Source::Docs => Source::Signatures,
+            Source::Signatures => Source::ItemPaths,
+        };
+    }
+
+    /// The signature for a hit from signature search.
+    fn signature(&self, hit: &Hit) -> Option<&FnSignature> {
+        let sigs = &self.signatures.as_ref()?.sigs;
+        Some(&sigs.get(hit.sig?)?.1)
+    }
+
+    /// The rendered rows in the doc for a hit from full-text search, with the matched range
+    /// relative to the rows.
+    fn doc_line(&self, hit: &Hit) -> Option<(&str, [usize; 2])> {
+        let doc = hit.doc?;
+        let documented = self.documented.as_deref()?;
+        let pos = documented
+            .binary_search_by_key(&hit.item, |(idx, _)| *idx)
+            .ok()?;
+        Some(documented[pos].1.rows_text(doc.matched))
+    }
+
+    /// The matched text in the doc and the count of its occurrences before.
+    fn doc_matched(&self, hit: &Hit) -> Option<(String, usize)> {
+        let (text, [start, end]) = self.doc_line(hit)?;
+        Some((text.get(start..end)?.to_owned(), hit.doc?.nth))
+    }
+}
+
+struct Signatures {
+    /// Index of item paths and the signature of the function.
+    sigs: Vec<(usize, FnSignature)>,
+    impls: TraitImpls,
+}
+
+impl Signatures {
+    fn new(doc: &CrateDoc, all: &[ItemPath]) -> Self {
+        let mut index = hashmap(all.len());
+        index.extend(all.iter().enumerate().map(|(idx, item)| (&item.id, idx)));
+        let (sigs, impls) = doc.dmodule().fn_signatures(doc);
+        let sigs: Vec<_> = sigs
+            .into_iter()
+            .filter_map(|(id, sig)| Some((*index.get(&id)?, sig)))
+            .collect();
+        info!(
+            "Found {} function signatures to search in {doc:?}",
+            sigs.len()
+        );
+        Signatures { sigs, impls }
+    }
+}
+
+/// Byte range in the text from char indices of matched text.
+fn matched_range(text: &str, indices: &[u32]) -> [usize; 2] {
+    let (Some(&first), Some(&last)) = (indices.first(), indices.last()) else {
+        return [0, 0];
+    };
+    let mut chars = text
+        .char_indices()
+        .map(|(pos, c)| (pos, pos + c.len_utf8()));
+    let start = chars.nth(first as usize).map_or(0, |(pos, _)| pos);
+    let end = match last.checked_sub(first) {
+        Some(0) => text[start..]
+            .chars()
+            .next()
+            .map_or(start, |c| start + c.len_utf8()),
+        Some(n) => chars.nth(n as usize - 1).map_or(text.len(), |(_, end)| end),
+        None => start,
+    };
+    [start, end]
+}
+
+impl std::ops::Deref for ItemPaths {
+    type Target = [Hit];
+
+    fn deref(&self) -> &Self::Target {
+        &self.hits
+    }
+}
+
+/// A search result.
+#[derive(Clone, Copy)]
+pub struct Hit {
+    /// Index of item paths.
+    item: usize,
+    /// Only exists for full-text search.
+    doc: Option<DocHit>,
+    /// Index of signatures, only exists for signature search.
+    sig: Option<usize>,
+}
+
+impl Hit {
+    fn item(item: usize) -> Self {
+        Hit {
+            item,
+            doc: None,
+            sig: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DocHit {
+    /// The count of occurrences of the matched text before it in the doc.
+    nth: usize,
+    /// Byte range of matched text in the rendered doc.
+    matched: [usize; 2],
+    score: u16,
+}
+
+impl LineState for Hit {
+    type State = usize;
+
+    fn state(&self) -> Self::State {
+        self.item
+    }
+
+    fn is_identical(&self, state: &Self::State) -> bool {
+        self.item == *state
+    }
+}
+
+impl ItemSearch {
+    fn new(doc: &CrateDoc) -> Self {
+        ItemSearch {
+            inner: Scroll {
+                lines: ItemPaths::new(doc),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn update_search(&mut self) {
+        self.inner.lines.update_search(&self.input);
+        self.inner.start = 0;
+        self.inner.cursor.y = 0;
+    }
+
+    fn switch_source(&mut self) {
+        self.inner.lines.switch_source();
+        self.update_search();
+    }
+
+    /// The item and the matched text in its doc if any.
+    fn get_item(&self, y: Option<u16>) -> Option<(ItemPath, Option<(String, usize)>)> {
+        let hit = y.map_or_else(
+            || self.inner.get_line_of_current_cursor(),
+            |y| self.inner.get_line_on_screen(y),
+        )?;
+        let lines = &self.inner.lines;
+        Some((lines.all[hit.item].clone(), lines.doc_matched(hit)))
+    }
+
+    fn render_input(&self, buf: &mut Buffer) {
+        self.input_border.render(buf);
+        let title = match self.inner.lines.source {
+            Source::ItemPaths => " Search Items By Path ",
+            Source::Docs => " Search Items By Documentation ",
+            Source::Signatures => " Search Functions By Signature ",
+        };
+        self.input_border.render_only_top_left_text(buf, title, 0);
+
+        let Rect { x, y, width, .. } = self.input_border.inner();
+        let width = width.saturating_sub(1) as usize;
+        let mut text = self.input.as_str();
+        // show end half if the input exceeds the width
+        text = &text[text.len().saturating_sub(width)..];
+        let (x, _) = buf.set_stringn(x, y, text, width, Style::new());
+
+        // the last width is used as cursor
+        let cursor = Style {
+            bg: Some(Color::Green),
+            ..Default::default()
+        };
+        buf.set_stringn(x, y, " ", 1, cursor);
+    }
+
+    fn render_list(&self, border: &Surround, buf: &mut Buffer) {
+        let text = &self.inner;
+        if let Some(lines) = text.visible_lines() {
+            let Rect { x, mut y, .. } = text.area;
+            let width = text.area.width as usize;
+            let items = &text.lines;
+            if text.get_line_of_current_cursor().is_some() {
+                text.highlight_current_line(buf, |cell| {
+                    cell.set_bg(BG_CURSOR_LINE);
+                });
+            }
+            for hit in lines {
+                let item = &items.all[hit.item];
+                let style = item.tag.style();
+                let mut line = vec![
+                    (item.tag.kind(), style),
+                    (" ", Style::new()),
+                    (&*item.path, Style::new()),
+                ];
+                if let Some((doc, matched)) = items.doc_line(hit) {
+                    line.extend(snippet(doc, matched));
+                }
+                if let Some(sig) = items.signature(hit) {
+                    line.extend([(" │ ", Style::new()), (sig.display(), Style::new())]);
+                }
+                render_line(line, buf, x, y, width);
+                y += 1;
+            }
+        }
+
+        // write the match result to the border bottom line
+        let text = xformat!(
+            " Got {} / Total {} ",
+            self.inner.total_len(),
+            self.inner.lines.all.len()
+        );
+        border.render_only_bottom_right_text(buf, &text);
+    }
+}
+
+impl PopupContent for ItemSearch {
+    const SIZE: [u16; 2] = [70, 70];
+
+    /// The input box is on top of the bordered list.
+    fn layout(&mut self, outer: Rect) -> Rect {
+        let [input, list] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(outer);
+        self.input_border = Surround::new(Block::new().borders(Borders::ALL), input);
+        list
+    }
+
+    fn set_inner(&mut self, inner: Rect) {
+        self.inner.area = inner;
+    }
+
+    fn render(&self, border: &Surround, buf: &mut Buffer) {
+        self.render_input(buf);
+        self.render_list(border, buf);
+    }
+}
+
+/// A snippet of matched line with the matched text highlighted.
+fn snippet(line: &str, [start, end]: [usize; 2]) -> [(&str, Style); 5] {
+    // the maximum count of chars before the matched text
+    const BEFORE: usize = 16;
+    let prefix = &line[..start];
+    let prefix = match prefix.char_indices().rev().nth(BEFORE) {
+        Some((pos, _)) => &prefix[pos..],
+        None => prefix.trim_start(),
+    };
+    let ellipsis = if prefix.len() < line[..start].trim_start().len() {
+        "…"
+    } else {
+        ""
+    };
+    let style = Style::new().fg(Color::Gray);
+    [
+        (" │ ", style),
+        (ellipsis, style),
+        (prefix, style),
+        (&line[start..end], SEARCH_MATCH),
+        (&line[end..], style),
+    ]
+}
+
+/// Item search popup.
+impl Page {
+    pub fn open_search(&mut self) {
+        let Some(doc) = self.content.inner.md_ref().doc_ref() else {
+            return;
+        };
+        if self.search.is_none() {
+            self.search = Some(Box::new(Popup::new(ItemSearch::new(doc), self.area)));
+        }
+        self.current = Some(Panel::Search);
+    }
+
+    pub fn close_search(&mut self) {
+        self.current = Some(Panel::Outline);
+    }
+
+    pub fn is_searching(&self) -> bool {
+        matches!(self.current, Some(Panel::Search)) && self.search.is_some()
+    }
+
+    fn search(&mut self) -> Option<&mut Popup<ItemSearch>> {
+        self.search.as_deref_mut()
+    }
+
+    pub(super) fn update_search_area(&mut self, full: Rect) {
+        if let Some(search) = self.search() {
+            search.update_area(full);
+        }
+    }
+
+    pub(super) fn render_search(&self, buf: &mut Buffer) {
+        if let Some(search) = self.search.as_deref().filter(|_| self.is_searching()) {
+            search.render(buf);
+        }
+    }
+
+    pub fn search_push_char(&mut self, ch: char) {
+        if let Some(search) = self.search() {
+            search.input.push(ch);
+            search.update_search();
+        }
+    }
+
+    pub fn search_pop_char(&mut self) {
+        if let Some(search) = self.search() {
+            search.input.pop();
+            search.update_search();
+        }
+    }
+
+    pub fn search_switch_source(&mut self) {
+        if let Some(search) = self.search() {
+            search.switch_source();
+        }
+    }
+
+    pub fn search_clear_input(&mut self) {
+        if let Some(search) = self.search() {
+            search.input.clear();
+            search.update_search();
+        }
+    }
+
+    pub fn search_results(&mut self) -> Option<&mut Scroll<ItemPaths>> {
+        self.search().map(|search| &mut search.inner)
+    }
+
+    pub fn search_scroll(&mut self, down: bool) {
+        if let Some(results) = self.search_results() {
+            if down {
+                results.scroll_down(ScrollOffset::Fixed(5));
+            } else {
+                results.scroll_up(ScrollOffset::Fixed(5));
+            }
+        }
+    }
+
+    /// Respond to left click when searching by jumping to the clicked item.
+    pub(super) fn search_click(&mut self, position: Position) {
+        let Some(search) = self.search.as_deref() else {
+            return;
+        };
+        match search.click(position) {
+            PopupClick::Outside => self.close_search(),
+            PopupClick::Inner => self.jump_to_searched_item(Some(position.y)),
+            PopupClick::Other => (),
+        }
+    }
+
+    /// Jump to the item under cursor, or on the given row position in screen.
+    pub fn jump_to_searched_item(&mut self, y: Option<u16>) {
+        let Some((item, matched)) = self.search.as_deref().and_then(|s| s.get_item(y)) else {
+            return;
+        };
+        self.close_search();
+        info!(?item, ?matched, "jump to searched item");
+        self.record_history();
+        if !self.outline.is_module_tree() {
+            self.navi.reset_navi_outline();
+            self.outline.reset_to_module_tree();
+        }
+        match &item.owner {
+            Some(owner) => {
+                self.jump_to_id(owner);
+                // associated items are not in the outline, so only show the doc
+                self.show_doc(&item.id);
+            }
+            None => self.jump_to_id(&item.id),
+        }
+        if let Some((text, nth)) = matched {
+            self.scroll_content_to_text(&text, nth);
+        }
+    }
+
+    /// Scroll the content to the row where the nth occurrence of the text starts.
+    fn scroll_content_to_text(&mut self, text: &str, nth: usize) {
+        const MARGIN: usize = 3;
+        let content = self.content();
+        // fall back to the first occurrence if the doc is rendered with fewer occurrences
+        if let Some(row) = content
+            .find_row(text, nth)
+            .or_else(|| content.find_row(text, 0))
+        {
+            content.start = row.saturating_sub(MARGIN);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,95 @@
+use super::{matched_range, snippet};
+use crate::{color::SEARCH_MATCH, ui::JoinedRows};
+
+/// Texts of the snippet with the highlighted one in brackets.
+fn snippet_text(line: &str, range: [usize; 2]) -> String {
+    let texts = snippet(line, range).map(|(text, style)| {
+        if style == SEARCH_MATCH {
+            format!("[{text}]")
+        } else {
+            text.to_owned()
+        }
+    });
+    texts.concat()
+}
+
+#[test]
+fn matched_range_from_char_indices() {
+    let text = "Returns the number of bytes";
+    assert_eq!(matched_range(text, &[12, 13, 14, 15, 16, 17]), [12, 18]);
+    assert_eq!(&text[12..18], "number");
+    // a single char
+    assert_eq!(matched_range(text, &[0]), [0, 1]);
+    // no match
+    assert_eq!(matched_range(text, &[]), [0, 0]);
+
+    // char indices are converted to byte positions for multi-byte chars
+    let text = "迭代 an iterator";
+    let [start, end] = matched_range(text, &[3, 4]);
+    assert_eq!(&text[start..end], "an");
+    let [start, end] = matched_range(text, &[0, 1]);
+    assert_eq!(&text[start..end], "迭代");
+    // indices out of the text are clamped
+    assert_eq!(matched_range(text, &[13, 20]), [text.len() - 1, text.len()]);
+}
+
+#[test]
+fn snippet_before_matched_text() {
+    // short prefix is kept with leading whitespaces trimmed
+    assert_eq!(snippet_text("  find a key", [7, 8]), " │ find [a] key");
+    // long prefix is truncated to 17 chars with an ellipsis
+    let line = "Returns a reference to the value corresponding to the key.";
+    let start = line.find("value").unwrap();
+    assert_eq!(
+        snippet_text(line, [start, start + 5]),
+        " │ …reference to the [value] corresponding to the key."
+    );
+    // multi-byte chars are counted as chars
+    let line = "一二三四五六七八九十一二三四五六七八 match";
+    let start = line.find("match").unwrap();
+    assert_eq!(
+        snippet_text(line, [start, start + 5]),
+        " │ …三四五六七八九十一二三四五六七八 [match]"
+    );
+}
+
+#[test]
+fn phrases_across_rows() {
+    // a paragraph wrapped into rows in content area
+    let wrapped: JoinedRows = [
+        "Returns an iterator over",
+        "  the lines of a",
+        "",
+        "string, as",
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        wrapped.text(),
+        "Returns an iterator over the lines of a string, as"
+    );
+    assert_eq!(wrapped.find_row("iterator over the", 0), Some(0));
+    assert_eq!(wrapped.find_row("the  LINES", 0), Some(1));
+    assert_eq!(wrapped.find_row("a string", 0), Some(1));
+    assert_eq!(wrapped.find_row("string", 0), Some(3));
+    assert_eq!(wrapped.find_row("the", 1), None);
+    assert_eq!(wrapped.find_row("", 0), None);
+
+    // the same paragraph unwrapped in search, and occurrences are counted in the same way
+    let unwrapped: JoinedRows = ["Returns an iterator over the lines of a", "string, as"]
+        .into_iter()
+        .collect();
+    assert_eq!(unwrapped.text(), wrapped.text());
+    let pos = unwrapped.text().find("the").unwrap();
+    assert_eq!(unwrapped.count_before("THE", pos), 0);
+    let pos = unwrapped.text().find("a string").unwrap();
+    // `a` in `an` and `iterator`
+    assert_eq!(unwrapped.count_before("a", pos), 2);
+
+    // rows of the matched text
+    let (text, range) = unwrapped.rows_text([pos, pos + "a string".len()]);
+    assert_eq!(text, "Returns an iterator over the lines of a string, as");
+    assert_eq!(&text[range[0]..range[1]], "a string");
+    let pos = unwrapped.text().find("as").unwrap();
+    assert_eq!(unwrapped.rows_text([pos, pos + 2]), ("string, as", [8, 10]));
+}
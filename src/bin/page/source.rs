@@ -0,0 +1,104 @@
+use super::Page;
+use ratatui::style::{Color, Style};
+use std::path::{Path, PathBuf};
+use term_rustdoc::util::{xformat, XString};
+
+/// Source file and line (starting from 1) where an item is defined.
+#[derive(Debug)]
+pub struct Location {
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+/// Source code of items.
+impl Page {
+    pub fn set_pkg_dir(&mut self, dir: Option<PathBuf>) {
+        self.pkg_dir = dir;
+    }
+
+    /// Location of the item under cursor in outline. Relative paths in spans are
+    /// relative to the pkg dir where the doc was compiled.
+    pub(super) fn item_location(&self) -> Result<Location, XString> {
+        let outline = self.outline.display_ref();
+        let id = outline.get_id().ok_or("No item is under cursor.")?;
+        let map = outline.lines.doc_ref();
+        let span = map
+            .span(id)
+            .ok_or_else(|| xformat!("No source location for `{}`.", map.path(id)))?;
+        let file = source_file(&span.filename, self.pkg_dir.as_deref())?;
+        Ok(Location {
+            file,
+            line: span.begin.0,
+        })
+    }
+
+    /// Toggle between doc and source for the item under cursor in outline.
+    pub fn toggle_source(&mut self) {
+        if self.source.take().is_some() {
+            self.update_content();
+            return;
+        }
+        const MARGIN: usize = 3;
+        let shown = self.item_location().and_then(|loc| {
+            let row = loc.line.saturating_sub(MARGIN + 1);
+            match self.content().update_source(&loc.file, row) {
+                Ok(()) => Ok(xformat!(" {} ", loc.file.display())),
+                Err(err) => Err(xformat!(
+                    "Failed to read `{}`: {err}\n\
+                     The pkg source may have been cleaned up. \
+                     Fetch the pkg again to view the source.",
+                    loc.file.display()
+                )),
+            }
+        });
+        let title = match shown {
+            Ok(title) => title,
+            Err(msg) => {
+                self.show_message(&msg, " Source Unavailable ");
+                return;
+            }
+        };
+        self.navi.heading().lines = Default::default();
+        self.source = Some(title);
+    }
+
+    /// Show an error message in content panel, which is toggled back to doc like source.
+    pub fn show_message(&mut self, msg: &str, title: &str) {
+        self.content()
+            .update_message(msg, Style::new().fg(Color::LightRed));
+        self.navi.heading().lines = Default::default();
+        self.source = Some(title.into());
+    }
+
+    /// Request to open the item under cursor in outline in an editor.
+    pub fn open_in_editor(&mut self) {
+        match self.item_location() {
+            Ok(loc) => self.editor = Some(loc),
+            Err(msg) => self.show_message(&msg, " Editor Failed "),
+        }
+    }
+
+    pub fn take_editor_request(&mut self) -> Option<Location> {
+        self.editor.take()
+    }
+}
+
+/// The full path of a source file in spans, where relative paths are relative to the pkg dir.
+fn source_file(filename: &Path, pkg_dir: Option<&Path>) -> Result<PathBuf, XString> {
+    let dir = || pkg_dir.ok_or("The pkg dir is unknown.");
+    // std docs remap sources to `/rustc/{commit-hash}/library/...`, which are
+    // relative to the `rust-src` component
+    let remapped = filename.strip_prefix("/rustc/").ok().map(|path| {
+        let mut components = path.components();
+        components.next();
+        components.as_path()
+    });
+    Ok(match remapped {
+        Some(path) => dir()?.join(path),
+        None if filename.is_absolute() => filename.to_owned(),
+        None => dir()?.join(filename),
+    })
+}
+
+#[cfg(test)]
+mod tests;
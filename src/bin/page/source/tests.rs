@@ -0,0 +1,74 @@
+use super::source_file;
+use crate::ui::scrollable::ScrollText;
+use std::{fs, path::Path};
+
+#[test]
+fn source_file_in_pkg_dir() {
+    let dir = Path::new("/home/.cargo/registry/src/index/serde-1.0.197");
+    let file = |name: &str, dir| source_file(Path::new(name), dir).map(|p| p.display().to_string());
+
+    // relative to the pkg dir
+    assert_eq!(
+        file("src/de/mod.rs", Some(dir)).unwrap(),
+        "/home/.cargo/registry/src/index/serde-1.0.197/src/de/mod.rs"
+    );
+    // absolute paths are kept
+    assert_eq!(
+        file("/tmp/build/out.rs", Some(dir)).unwrap(),
+        "/tmp/build/out.rs"
+    );
+    assert_eq!(
+        file("/tmp/build/out.rs", None).unwrap(),
+        "/tmp/build/out.rs"
+    );
+    assert_eq!(
+        file("src/lib.rs", None).unwrap_err(),
+        "The pkg dir is unknown."
+    );
+}
+
+#[test]
+fn source_file_remapped_in_rust_src() {
+    // the pkg dir of std docs is the source of `rust-src` component
+    let dir = Path::new("/sysroot/lib/rustlib/src/rust");
+    let file = |name: &str, dir| source_file(Path::new(name), dir).map(|p| p.display().to_string());
+
+    assert_eq!(
+        file(
+            "/rustc/256b6fb19a2c018eaad4806d2369d1f6a71fbf4d/library/core/src/option.rs",
+            Some(dir)
+        )
+        .unwrap(),
+        "/sysroot/lib/rustlib/src/rust/library/core/src/option.rs"
+    );
+    assert_eq!(
+        file("/rustc/256b6fb19/library/std/src/lib.rs", None).unwrap_err(),
+        "The pkg dir is unknown."
+    );
+    // only the leading `/rustc/` is remapped
+    assert_eq!(
+        file("/home/rustc/src/lib.rs", Some(dir)).unwrap(),
+        "/home/rustc/src/lib.rs"
+    );
+}
+
+#[test]
+fn highlighted_source_cached_per_file() {
+    let dir = std::env::temp_dir().join(format!("term-rustdoc-source-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let [a, b] = ["a.rs", "b.rs"].map(|name| dir.join(name));
+    fs::write(&a, "fn a() {}\n\nfn a2() {}\n").unwrap();
+    fs::write(&b, "struct B;\n").unwrap();
+
+    let mut text = ScrollText::default();
+    text.update_source(&a, 2).unwrap();
+    assert_eq!((text.total_len(), text.start), (3, 2));
+    text.update_source(&b, 5).unwrap();
+    assert_eq!((text.total_len(), text.start), (1, 0));
+
+    // cached files are shown without being read again
+    fs::remove_dir_all(&dir).unwrap();
+    text.update_source(&a, 0).unwrap();
+    assert_eq!((text.total_len(), text.start), (3, 0));
+    assert!(text.update_source(&dir.join("c.rs"), 0).is_err());
+}
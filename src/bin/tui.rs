@@ -1,4 +1,4 @@
-use crate::{event::EventHandler, Frame, Result};
+use crate::{editor, err, event::EventHandler, page::Location, Frame, Result};
 use color_eyre::eyre;
 use crossterm::{
     cursor,
@@ -33,6 +33,27 @@ impl Tui {
     pub fn size(&self) -> Result<Rect> {
         Ok(self.terminal.size()?)
     }
+
+    /// Suspend the TUI to open the location in an editor, and restore the TUI
+    /// when the editor exits.
+    pub fn edit(&mut self, loc: &Location) -> Result<()> {
+        let mut cmd = editor::command(&loc.file, loc.line)?;
+        self.events.pause()?;
+        restore_terminal()?;
+        let status = cmd.status();
+        let entered = enter_terminal();
+        // keep reading events even if the terminal fails to be entered, otherwise no input
+        // is responded anymore
+        self.events.resume();
+        entered?;
+        // redraw the whole screen since the editor has messed it up
+        self.terminal.clear()?;
+        let status = status.map_err(|e| err!("Failed to launch {cmd:?}: {e}"))?;
+        if !status.success() {
+            return Err(err!("{cmd:?} exited with {status}"));
+        }
+        Ok(())
+    }
 }
 
 impl Drop for Tui {
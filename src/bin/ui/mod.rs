@@ -1,9 +1,13 @@
+/// A bordered popup in the center, drawn over panels.
+mod popup;
 /// Scrollable widget
 pub mod scrollable;
 /// A block with area. Use the inner area to draw the real content.
 mod surround;
 
+pub use popup::{Popup, PopupClick, PopupContent};
 pub use scrollable::{
-    render_line, LineState, MarkdownAndHeading, Scroll, ScrollMarkdown, ScrollOffset, Scrollable,
+    render_line, JoinedRows, LineState, MarkdownAndHeading, Scroll, ScrollMarkdown, ScrollOffset,
+    Scrollable,
 };
 pub use surround::Surround;
@@ -0,0 +1,106 @@
+use super::Surround;
+use crate::frame::centered_rect;
+use ratatui::{
+    layout::Position,
+    prelude::{Buffer, Rect, Widget},
+    widgets::{Block, Borders, Clear},
+};
+use std::ops::{Deref, DerefMut};
+
+/// The content shown in a [`Popup`].
+pub trait PopupContent {
+    /// Width and height in percentages of the full area.
+    const SIZE: [u16; 2];
+
+    /// Split the outer area of the popup, and return the area for the bordered block.
+    fn layout(&mut self, outer: Rect) -> Rect {
+        outer
+    }
+
+    /// Place the content in the inner area of the border.
+    fn set_inner(&mut self, _inner: Rect) {}
+
+    /// Render the content after the popup area is cleared and the border is drawn.
+    fn render(&self, border: &Surround, buf: &mut Buffer);
+}
+
+/// A popup in the center of the full area, which is drawn over panels with a bordered block.
+///
+/// Popup derefs to its content.
+#[derive(Default)]
+pub struct Popup<T> {
+    content: T,
+    outer: Rect,
+    border: Surround,
+}
+
+/// Where a left click lands on a popup.
+pub enum PopupClick {
+    /// Out of the popup, which usually closes it.
+    Outside,
+    /// In the inner area of the border.
+    Inner,
+    /// On the border or other parts of the popup.
+    Other,
+}
+
+impl<T> std::fmt::Debug for Popup<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = std::any::type_name::<T>();
+        let name = name.rsplit("::").next().unwrap_or(name);
+        write!(f, "Popup<{name}>")
+    }
+}
+
+impl<T> Deref for Popup<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.content
+    }
+}
+
+impl<T> DerefMut for Popup<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.content
+    }
+}
+
+impl<T: PopupContent> Popup<T> {
+    pub fn new(content: T, full: Rect) -> Self {
+        let mut popup = Popup {
+            content,
+            outer: Rect::default(),
+            border: Surround::default(),
+        };
+        popup.update_area(full);
+        popup
+    }
+
+    pub fn update_area(&mut self, full: Rect) {
+        let [width, height] = T::SIZE;
+        let outer = centered_rect(full, width, height);
+        if self.outer != outer {
+            self.outer = outer;
+            let area = self.content.layout(outer);
+            self.border = Surround::new(Block::new().borders(Borders::ALL), area);
+            self.content.set_inner(self.border.inner());
+        }
+    }
+
+    pub fn click(&self, position: Position) -> PopupClick {
+        if !self.outer.contains(position) {
+            PopupClick::Outside
+        } else if self.border.inner().contains(position) {
+            PopupClick::Inner
+        } else {
+            PopupClick::Other
+        }
+    }
+
+    pub fn render(&self, buf: &mut Buffer) {
+        Clear.render(self.outer, buf);
+        self.border.render(buf);
+        self.content.render(&self.border, buf);
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
     heading::Headings,
-    parse::{self, Blocks},
+    parse::{self, Blocks, IntraDocLink, IntraDocLinks},
+    region::SelectedRegion,
     StyledText,
 };
 use crate::{
@@ -8,13 +9,23 @@ use crate::{
     Result,
 };
 use ratatui::style::Style;
-use std::{fmt, ops::Deref};
-use term_rustdoc::{tree::CrateDoc, util::XString};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt, fs, io,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+use term_rustdoc::{
+    tree::{CrateDoc, IdToID, ID},
+    util::XString,
+};
 use unicode_width::UnicodeWidthStr;
 
 /// Scrollable text area for displaying markdown.
 pub type ScrollText = Scroll<StyledLines>;
 
+#[derive(Clone)]
 pub struct StyledLine {
     line: Vec<StyledText>,
     /// the total width of a line
@@ -102,6 +113,10 @@ pub struct StyledLines {
     lines: Vec<StyledLine>,
     blocks: Blocks,
     doc: Option<CrateDoc>,
+    /// The index of selected local item link in `blocks`.
+    selected_item_link: Option<usize>,
+    /// Highlighted lines of source files, kept to toggle source without highlighting again.
+    sources: HashMap<PathBuf, Vec<StyledLine>>,
 }
 
 impl fmt::Debug for StyledLines {
@@ -132,10 +147,12 @@ impl StyledLines {
     ///
     /// The Headings can still be empty because heading jumping isn't supported in syntect case.
     pub fn update_doc(&mut self, id: &str, width: Option<f64>) -> Option<Headings> {
-        if let Some(doc) = &self.doc {
-            if let Some(doc) = doc.get_doc(id) {
+        if let Some(map) = &self.doc {
+            if let Some(doc) = doc_with_notice(map, id).as_deref() {
+                self.selected_item_link = None;
                 return if let Some(width) = width {
-                    let (lines, blocks, headings) = parse::parse_doc(doc, width);
+                    let intra_doc_links = intra_doc_links(map, id);
+                    let (lines, blocks, headings) = parse::parse_doc(doc, intra_doc_links, width);
                     self.lines = lines;
                     self.blocks = blocks;
                     Some(headings)
@@ -153,11 +170,50 @@ impl StyledLines {
     /// FIXME: cache queried doc to save parsing
     pub fn reset_doc(&mut self) {
         self.lines = Vec::new();
+        self.blocks = Blocks::default();
+        self.selected_item_link = None;
     }
 
     pub fn toggle_sytect(&mut self) {
         self.syntect = !self.syntect;
     }
+
+    fn set_plain_lines(&mut self, lines: Vec<StyledLine>) {
+        self.reset_doc();
+        self.lines = lines;
+    }
+}
+
+/// The doc of the item with notices put before it.
+fn doc_with_notice<'a>(map: &'a CrateDoc, id: &str) -> Option<Cow<'a, str>> {
+    match (notice(map, id), map.get_doc(id)) {
+        (Some(notice), doc) => Some(Cow::Owned(notice + doc.unwrap_or_default())),
+        (None, doc) => doc.map(Cow::Borrowed),
+    }
+}
+
+/// Blockquotes as notices of deprecation and required features put before the doc.
+fn notice(map: &CrateDoc, id: &str) -> Option<String> {
+    let mut notice = String::new();
+    if let Some(deprecation) = map.deprecation(id) {
+        notice.push_str("> **Deprecated**");
+        if let Some(since) = &deprecation.since {
+            notice.push_str(" since ");
+            notice.push_str(since);
+        }
+        if let Some(note) = &deprecation.note {
+            notice.push_str(": ");
+            // keep multiline notes inside the blockquote
+            notice.push_str(&note.replace('\n', "\n> "));
+        }
+        notice.push_str("\n\n");
+    }
+    if let Some(cfg) = map.cfg(id) {
+        notice.push_str("> Available on **");
+        notice.push_str(&cfg.describe());
+        notice.push_str("** only.\n\n");
+    }
+    (!notice.is_empty()).then_some(notice)
 }
 
 impl ScrollText {
@@ -183,7 +239,208 @@ impl ScrollText {
         self.lines.update_doc(id, width)
     }
 
+    /// Show highlighted source code of the file with the given row (starting from 0) on the top.
+    /// The file is read and highlighted only the first time it's shown.
+    pub fn update_source(&mut self, file: &Path, row: usize) -> io::Result<()> {
+        let lines = match self.lines.sources.get(file) {
+            Some(lines) => lines.clone(),
+            None => {
+                let lines = parse::rust_source(&fs::read_to_string(file)?);
+                let cached = self.lines.sources.entry(file.to_owned()).or_insert(lines);
+                cached.clone()
+            }
+        };
+        self.lines.set_plain_lines(lines);
+        self.start = row.min(self.total_len().saturating_sub(1));
+        Ok(())
+    }
+
+    /// Show a message in place of the doc, with a line for each `\n`.
+    pub fn update_message(&mut self, msg: &str, style: Style) {
+        let lines = msg
+            .lines()
+            .map(|text| {
+                let mut line = StyledLine::new();
+                line.push(text, style);
+                line
+            })
+            .collect();
+        self.lines.set_plain_lines(lines);
+        self.start = 0;
+    }
+
     pub fn doc_ref(&self) -> Option<&CrateDoc> {
         self.lines.doc.as_ref()
     }
+
+    /// The row where the nth (starting from 0) occurrence of the text starts.
+    /// The text can be wrapped across rows.
+    pub fn find_row(&self, text: &str, nth: usize) -> Option<usize> {
+        JoinedRows::new(&self.lines).find_row(text, nth)
+    }
+
+    /// The local item link on the screen position.
+    pub fn jumpable_id(&self, x: u16, y: u16) -> Option<ID> {
+        if self.lines.syntect || !self.area.contains((x, y).into()) {
+            return None;
+        }
+        let row = self.start + (y - self.area.y) as usize;
+        let col = (x - self.area.x) as usize;
+        self.lines.blocks.links.item_link_at(row, col).cloned()
+    }
+
+    /// Select the next (or previous if `forward` is false) local item link in visual range.
+    /// The selection cycles in visual range.
+    pub fn select_item_link(&mut self, forward: bool) {
+        let (start, end) = (self.start, self.start + self.area.height as usize);
+        let links = self.lines.blocks.links.item_links();
+        let visible = |&(idx, (_, region)): &(usize, &(ID, SelectedRegion))| {
+            (start..end).contains(&region.row_start()).then_some(idx)
+        };
+        let mut iter = links.iter().enumerate().filter_map(|x| visible(&x));
+        let selected = match (self.lines.selected_item_link, forward) {
+            (Some(current), true) => iter
+                .clone()
+                .find(|idx| *idx > current)
+                .or_else(|| iter.next()),
+            (Some(current), false) => iter
+                .clone()
+                .rev()
+                .find(|idx| *idx < current)
+                .or_else(|| iter.next_back()),
+            (None, true) => iter.next(),
+            (None, false) => iter.next_back(),
+        };
+        self.lines.selected_item_link = selected;
+    }
+
+    pub fn selected_item_link(&self) -> Option<(&ID, &SelectedRegion)> {
+        let idx = self.lines.selected_item_link?;
+        let (id, region) = self.lines.blocks.links.item_links().get(idx)?;
+        Some((id, region))
+    }
+}
+
+fn intra_doc_links(doc: &CrateDoc, id: &str) -> IntraDocLinks {
+    let Some(item) = doc.get_item(id) else {
+        return IntraDocLinks::default();
+    };
+    // reexported items use docs from the original items
+    let item = match &item.inner {
+        rustdoc_types::ItemEnum::Import(import) => {
+            match import.id.as_ref().and_then(|id| doc.get_item(&id.0)) {
+                Some(item) => item,
+                None => return IntraDocLinks::default(),
+            }
+        }
+        _ => item,
+    };
+    item.links
+        .iter()
+        .map(|(dest, id)| {
+            let link = match doc.get_item(&id.0) {
+                Some(item) if item.crate_id == 0 => IntraDocLink::Local(id.to_ID()),
+                _ => IntraDocLink::External(doc.path(&id.0)),
+            };
+            (dest.as_str().into(), link)
+        })
+        .collect()
+}
+
+/// Rendered rows joined by whitespaces with whitespaces collapsed, thus a phrase can be
+/// found even if it's wrapped across rows. Texts are matched ASCII case-insensitively.
+#[derive(Default)]
+pub struct JoinedRows {
+    text: String,
+    /// Byte positions in the text where rows start.
+    starts: Vec<usize>,
+}
+
+/// Rows are rendered without wrapping for searching, and wrapped in content area,
+/// but they are joined into the same text.
+const UNWRAPPED: f64 = 10_000.0;
+
+impl JoinedRows {
+    pub fn new(lines: &[StyledLine]) -> Self {
+        let rows = lines.iter().map(|line| {
+            let texts = line.iter_text_style().map(|(s, _)| s);
+            texts.collect::<String>()
+        });
+        rows.collect()
+    }
+
+    /// The doc of the item rendered in the same way as in content area, but unwrapped.
+    pub fn doc(map: &CrateDoc, id: &str) -> Self {
+        let Some(doc) = doc_with_notice(map, id) else {
+            return JoinedRows::default();
+        };
+        let (lines, ..) = parse::parse_doc(&doc, intra_doc_links(map, id), UNWRAPPED);
+        JoinedRows::new(&lines)
+    }
+
+    fn push_row(&mut self, row: &str) {
+        let mut words = row.split_whitespace().peekable();
+        if words.peek().is_some() && !self.text.is_empty() {
+            self.text.push(' ');
+        }
+        self.starts.push(self.text.len());
+        if let Some(word) = words.next() {
+            self.text.push_str(word);
+        }
+        for word in words {
+            self.text.push(' ');
+            self.text.push_str(word);
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The row that the byte position is in.
+    pub fn row(&self, pos: usize) -> usize {
+        self.starts
+            .partition_point(|&start| start <= pos)
+            .saturating_sub(1)
+    }
+
+    /// The text from the row start of the first position to the row end of the second,
+    /// and the range of the positions relative to the text.
+    pub fn rows_text(&self, [start, end]: [usize; 2]) -> (&str, [usize; 2]) {
+        let row_start = self.starts.get(self.row(start)).map_or(0, |&pos| pos);
+        let row_end = self.starts.get(self.row(end) + 1);
+        let row_end = row_end.map_or(self.text.len(), |&next| next);
+        let text = self.text[row_start..row_end].trim_end();
+        (text, [start - row_start, end - row_start])
+    }
+
+    /// Byte positions of occurrences of the text.
+    fn positions(&self, text: &str) -> Vec<usize> {
+        let pat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if pat.is_empty() {
+            return Vec::new();
+        }
+        let pat = pat.to_ascii_lowercase();
+        let lower = self.text.to_ascii_lowercase();
+        lower.match_indices(&pat).map(|(pos, _)| pos).collect()
+    }
+
+    /// The row where the nth (starting from 0) occurrence of the text starts.
+    pub fn find_row(&self, text: &str, nth: usize) -> Option<usize> {
+        self.positions(text).get(nth).map(|&pos| self.row(pos))
+    }
+
+    /// The count of occurrences of the text that start before the position.
+    pub fn count_before(&self, text: &str, pos: usize) -> usize {
+        self.positions(text).partition_point(|&p| p < pos)
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for JoinedRows {
+    fn from_iter<T: IntoIterator<Item = S>>(rows: T) -> Self {
+        let mut joined = JoinedRows::default();
+        rows.into_iter()
+            .for_each(|row| joined.push_row(row.as_ref()));
+        joined
+    }
 }
@@ -18,7 +18,7 @@ impl MarkdownAndHeading {
             md = "too narrow to show anything";
         }
         let [md_area, head_area] = split_area(area);
-        let (lines, _, headings) = parse_doc(md, md_area.width as f64);
+        let (lines, _, headings) = parse_doc(md, Default::default(), md_area.width as f64);
         let mut heading = ScrollHeading::default();
         heading.update_headings(headings);
         heading.area = head_area;
@@ -16,7 +16,7 @@ mod wrapped;
 mod ingerated;
 
 pub use self::{
-    fallback::ScrollText,
+    fallback::{JoinedRows, ScrollText},
     heading::{Headings, ScrollHeading},
     ingerated::{MarkdownAndHeading, ScrollMarkdown},
     wrapped::StyledText,
@@ -1,4 +1,4 @@
-use super::{line::Line, word::Word};
+use super::{line::Line, table::Table, word::Word};
 use super::{segment_words, MetaTag};
 use ratatui::style::{Color, Modifier, Style};
 use std::fmt;
@@ -15,6 +15,8 @@ pub struct Block {
     lines: Vec<Line>,
     links: Vec<usize>,
     footnotes: Vec<XString>,
+    /// A table is laid out according to the width when writing lines, thus stored alone.
+    table: Option<Table>,
 }
 
 impl FromIterator<Line> for Block {
@@ -23,6 +25,7 @@ impl FromIterator<Line> for Block {
             lines: Vec::from_iter(iter),
             links: Vec::new(),
             footnotes: Vec::new(),
+            table: None,
         }
     }
 }
@@ -35,6 +38,7 @@ impl FromIterator<Word> for Block {
             lines,
             links: Vec::new(),
             footnotes: Vec::new(),
+            table: None,
         }
     }
 }
@@ -56,6 +60,9 @@ impl fmt::Display for Block {
         for line in &self.lines {
             writeln!(f, "{line}")?;
         }
+        if let Some(table) = &self.table {
+            write!(f, "{table}")?;
+        }
         Ok(())
     }
 }
@@ -150,6 +157,15 @@ impl Block {
     pub fn push_code_block(&mut self, code: Block) {
         self.lines.extend(code.lines);
     }
+
+    pub fn set_table(&mut self, table: Table) {
+        self.table = Some(table);
+    }
+
+    /// Merge all lines into one, which is used for inline contents like a table cell.
+    pub fn into_words(self) -> Vec<Word> {
+        self.lines.into_iter().flat_map(|line| line.words).collect()
+    }
 }
 
 impl Block {
@@ -165,16 +181,20 @@ impl Block {
         &self.footnotes
     }
 
+    pub fn table(&self) -> Option<&Table> {
+        self.table.as_ref()
+    }
+
     pub fn push_link(&mut self, idx: usize) {
         // if the same idx exists, no need to store again
-        if !self.links.iter().any(|l| *l == idx) {
+        if !self.links.contains(&idx) {
             self.links.push(idx);
         }
     }
 
     pub fn push_footnote(&mut self, key: XString) {
         // if the same key exists, no need to store again
-        if !self.footnotes.iter().any(|f| *f == key) {
+        if !self.footnotes.contains(&key) {
             self.footnotes.push(key);
         }
     }
@@ -1,12 +1,17 @@
-use super::{element::LINK, Block, Line, LinkTag, MetaTag, Word};
+use super::{
+    element::LINK, table::Table, Block, IntraDocLink, IntraDocLinks, Line, LinkTag, MetaTag, Word,
+};
 use crate::ui::scrollable::markdown::{
     fallback::StyledLine,
     heading::Headings,
     region::{LinkedRegions, SelectedRegion},
 };
 use ratatui::style::{Color, Style};
-use std::fmt;
-use term_rustdoc::util::{hashmap, xformat, HashMap, XString};
+use std::{fmt, rc::Rc};
+use term_rustdoc::{
+    tree::ID,
+    util::{hashmap, xformat, HashMap, XString},
+};
 use textwrap::wrap_algorithms::{wrap_optimal_fit, Penalties};
 
 /// The whole documentation for an item.
@@ -38,13 +43,15 @@ impl Blocks {
 }
 
 impl Blocks {
-    pub fn new() -> Blocks {
+    pub fn new(intra_doc_links: Rc<IntraDocLinks>) -> Blocks {
         Blocks {
             blocks: Vec::with_capacity(16),
             links: Links {
                 heading: Vec::new(),
                 links: Vec::with_capacity(8),
                 footnotes: hashmap(1),
+                intra_doc_links,
+                item_links: Vec::new(),
             },
         }
     }
@@ -55,12 +62,16 @@ impl Blocks {
         self.links.heading.shrink_to_fit();
         self.links.links.shrink_to_fit();
         self.links.footnotes.shrink_to_fit();
+        self.links.item_links.shrink_to_fit();
     }
 
     pub fn write_styled_lines(&mut self, width: f64) -> Vec<StyledLine> {
         let mut writer = WriteLines::new(width);
         for block in &self.blocks {
-            writer.write_lines(block.lines());
+            match block.table() {
+                Some(table) => writer.write_table(table),
+                None => writer.write_lines(block.lines()),
+            }
             if !block.links().is_empty() {
                 writer.write_empty_line();
                 for &idx in block.links() {
@@ -136,6 +147,12 @@ impl WriteLines {
         }
     }
 
+    fn write_table(&mut self, table: &Table) {
+        for line in table.write_lines(self.width as usize) {
+            self.write_line(&line);
+        }
+    }
+
     fn write_line(&mut self, words: &[Word]) {
         self.lines.push(Word::words_to_line(
             words,
@@ -150,6 +167,7 @@ impl WriteLines {
 
     fn split(mut self, links: &mut Links) -> Vec<StyledLine> {
         links.set_heading_regions(self.regions.take_headings());
+        links.item_links = self.regions.take_item_links();
         self.lines
     }
 }
@@ -162,6 +180,10 @@ pub struct Links {
     // FIXME: replace this HashMap with Vec<(XString, Block)>,
     // and use the index as key/id like push_link returns.
     footnotes: HashMap<XString, Block>,
+    /// Resolved intra-doc links from the item.
+    intra_doc_links: Rc<IntraDocLinks>,
+    /// Regions of local item links in the wrapped lines, which are jumpable.
+    item_links: Vec<(ID, SelectedRegion)>,
 }
 
 impl Links {
@@ -195,6 +217,21 @@ impl Links {
         }
     }
 
+    pub fn intra_doc_link(&self, dest: &str) -> Option<&IntraDocLink> {
+        self.intra_doc_links.get(dest)
+    }
+
+    /// Local item links in the order of appearance.
+    pub fn item_links(&self) -> &[(ID, SelectedRegion)] {
+        &self.item_links
+    }
+
+    /// Find the local item link that covers the position in wrapped lines.
+    pub fn item_link_at(&self, row: usize, col: usize) -> Option<&ID> {
+        let mut iter = self.item_links.iter();
+        iter.find_map(|(id, region)| region.contains(row, col).then_some(id))
+    }
+
     pub fn get_footnote(&self, key: &str) -> Option<&Block> {
         self.footnotes.get(key)
     }
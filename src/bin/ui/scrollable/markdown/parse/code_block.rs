@@ -101,9 +101,3 @@ pub fn other(lang: &str, code: &str) -> Block {
         gen_parse_code! { #inner code ps ts syntax }
     })
 }
-
-gen_parse_code!(markdown);
-
-pub fn md_table(table: &str) -> Block {
-    markdown(table)
-}
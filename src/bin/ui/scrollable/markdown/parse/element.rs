@@ -1,7 +1,12 @@
-use super::{segment_str, Block, Color, LinkTag, Links, MetaTag, Modifier, Style, Word};
+use super::{
+    segment_str, Block, Color, IntraDocLink, LinkTag, Links, MetaTag, Modifier, Style, Word,
+};
 use pulldown_cmark::{Event, Tag};
 use std::ops::Range;
-use term_rustdoc::util::{ToCompactString, XString};
+use term_rustdoc::{
+    tree::ID,
+    util::{ToCompactString, XString},
+};
 
 macro_rules! ele {
     ($iter:ident, $tag:ident, $range:ident) => {
@@ -114,13 +119,26 @@ where
         // }
     }
 
-    /// TODO: support local/external crate item links
+    /// Intra-doc links to local items are rendered as jumpable texts, while other links
+    /// (including those to external items) are rendered as reference links.
     pub fn parse_link(self, link: &str) {
+        match self.links.intra_doc_link(link) {
+            Some(IntraDocLink::Local(id)) => {
+                let id = id.clone();
+                self.parse_item_link(id);
+            }
+            Some(IntraDocLink::External(path)) => {
+                let path = path.clone();
+                self.parse_reference_link(&path);
+            }
+            None => self.parse_reference_link(link),
+        }
+    }
+
+    /// `[text][idx]` with the link shown below the block.
+    fn parse_reference_link(self, link: &str) {
         let Element {
-            mut iter,
-            block,
-            links,
-            ..
+            iter, block, links, ..
         } = self;
         let idx = links.push_link(link.into());
         block.push_link(idx);
@@ -133,48 +151,24 @@ where
             trailling_whitespace: false,
         };
         block.push_a_word(alink(XString::new_inline("[")));
-        while let Some((event, range)) = iter.next() {
-            match event {
-                Event::Text(words) => {
-                    block.push_words(&words, style, tag.clone());
-                }
-                Event::Code(code) => {
-                    parse_intra_code_in_link(&code, block);
-                }
-                Event::Start(Tag::Emphasis) => {
-                    let style = style.add_modifier(Modifier::ITALIC);
-                    // we use for-loop here to discard further nested styles
-                    for (event, _) in ele!(iter, Emphasis, range) {
-                        if let Event::Text(words) = event {
-                            block.push_words(&words, style, tag.clone());
-                        }
-                    }
-                }
-                Event::Start(Tag::Strong) => {
-                    let style = style.add_modifier(Modifier::BOLD);
-                    for (event, _) in ele!(iter, Strong, range) {
-                        if let Event::Text(words) = event {
-                            block.push_words(&words, style, tag.clone());
-                        }
-                    }
-                }
-                Event::Start(Tag::Strikethrough) => {
-                    let style = style.add_modifier(Modifier::CROSSED_OUT);
-                    for (event, _) in ele!(iter, Strikethrough, range) {
-                        if let Event::Text(words) = event {
-                            block.push_words(&words, style, tag.clone());
-                        }
-                    }
-                }
-                _ => (),
-            }
-        }
+        link_text(iter, block, style, &tag);
         block.push_a_word(alink(XString::new_inline("]")));
         block.push_a_word(alink(XString::new_inline("[")));
         block.push_a_word(alink(idx.to_compact_string()));
         block.push_a_word(alink(XString::new_inline("]")));
     }
 
+    /// Only the link text is shown, and it can be jumped to the item.
+    fn parse_item_link(self, id: ID) {
+        let Element { iter, block, .. } = self;
+        link_text(
+            iter,
+            block,
+            ITEM_LINK,
+            &MetaTag::Link(LinkTag::LocalItemLink(id)),
+        );
+    }
+
     /// Images are like links, e.g. `![ref]` are valid syntax, or `![styled text](...)`.
     /// But when parsing them, don't show further styles and just truncate the img line if too long.
     pub fn parse_image(self, link: &str) {
@@ -262,6 +256,51 @@ where
     }
 }
 
+/// Texts in a link: nested styles are discarded.
+fn link_text<'doc>(
+    mut iter: impl Iterator<Item = EventRange<'doc>>,
+    block: &mut Block,
+    style: Style,
+    tag: &MetaTag,
+) {
+    while let Some((event, range)) = iter.next() {
+        match event {
+            Event::Text(words) => {
+                block.push_words(&words, style, tag.clone());
+            }
+            Event::Code(code) => {
+                parse_intra_code_in_link(&code, block, style, tag);
+            }
+            Event::Start(Tag::Emphasis) => {
+                let style = style.add_modifier(Modifier::ITALIC);
+                // we use for-loop here to discard further nested styles
+                for (event, _) in ele!(iter, Emphasis, range) {
+                    if let Event::Text(words) = event {
+                        block.push_words(&words, style, tag.clone());
+                    }
+                }
+            }
+            Event::Start(Tag::Strong) => {
+                let style = style.add_modifier(Modifier::BOLD);
+                for (event, _) in ele!(iter, Strong, range) {
+                    if let Event::Text(words) = event {
+                        block.push_words(&words, style, tag.clone());
+                    }
+                }
+            }
+            Event::Start(Tag::Strikethrough) => {
+                let style = style.add_modifier(Modifier::CROSSED_OUT);
+                for (event, _) in ele!(iter, Strikethrough, range) {
+                    if let Event::Text(words) = event {
+                        block.push_words(&words, style, tag.clone());
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
 pub const LINK: Style = Style {
     fg: Some(Color::Rgb(30, 144, 255)), // #1E90FF
     add_modifier: Modifier::empty(),
@@ -270,6 +309,15 @@ pub const LINK: Style = Style {
     sub_modifier: Modifier::empty(),
 };
 
+/// Links to local items.
+pub const ITEM_LINK: Style = Style {
+    fg: Some(Color::Rgb(64, 131, 214)), // #4083d6
+    add_modifier: Modifier::UNDERLINED,
+    bg: None,
+    underline_color: None,
+    sub_modifier: Modifier::empty(),
+};
+
 const INTRA_CODE: Style = Style {
     fg: Some(Color::Rgb(255, 184, 162)), // #FFB8A2
     bg: None,
@@ -303,20 +351,21 @@ pub fn parse_intra_code(code: &str, block: &mut Block) {
     block.push_a_word(end);
 }
 
-pub fn parse_intra_code_in_link(code: &str, block: &mut Block) {
-    fn word(s: &str, style: Style) -> Word {
+/// The code in a link will carry the link tag for the sake of jumping,
+/// while backticks are tagged with InlineCode.
+pub fn parse_intra_code_in_link(code: &str, block: &mut Block, style: Style, tag: &MetaTag) {
+    fn word(s: &str, style: Style, tag: MetaTag) -> Word {
         Word {
             word: s.into(),
             style,
-            tag: MetaTag::InlineCode,
+            tag,
             trailling_whitespace: false,
         }
     }
-    let tick = word("`", INTRA_CODE);
+    let tick = word("`", INTRA_CODE, MetaTag::InlineCode);
     block.push_a_word(tick.clone());
     segment_str(code, |s| {
-        let word = word(s, LINK);
-        block.push_a_word(word);
+        block.push_a_word(word(s, style, tag.clone()));
     });
     block.push_a_word(tick);
 }
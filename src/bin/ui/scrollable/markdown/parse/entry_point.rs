@@ -1,18 +1,25 @@
 use super::{
     code_block,
-    element::{Element, FOOTNOTE},
+    element::{Element, EventRange, FOOTNOTE},
     list::{self, parse_codeblock},
-    Block, Blocks, MetaTag, Word,
+    table, Block, Blocks, IntraDocLinks, MetaTag, Word,
 };
-use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+use pulldown_cmark::{BrokenLink, CodeBlockKind, CowStr, Event, Options, Parser, Tag};
+use std::rc::Rc;
 use term_rustdoc::util::{xformat, XString};
 
+#[cfg(test)]
 pub fn parse(doc: &str) -> Blocks {
+    parse_with_intra_doc_links(doc, IntraDocLinks::default())
+}
+
+pub fn parse_with_intra_doc_links(doc: &str, intra_doc_links: IntraDocLinks) -> Blocks {
     if doc.is_empty() {
         return Blocks::default();
     }
-    let mut blocks = Blocks::new();
-    let mut iter = markdown_iter(doc);
+    let intra_doc_links = Rc::new(intra_doc_links);
+    let mut blocks = Blocks::new(intra_doc_links.clone());
+    let mut iter = markdown_iter_with_intra_doc_links(doc, intra_doc_links);
     while let Some((event, range)) = iter.by_ref().next() {
         match event {
             Event::Start(Tag::Paragraph) => {
@@ -55,10 +62,12 @@ pub fn parse(doc: &str) -> Blocks {
                 tag: MetaTag::Rule,
                 ..Default::default()
             }])),
-            Event::Start(Tag::Table(_)) => {
-                // the table is rendered via original contents with syntect's highlights
-                blocks.push(code_block::md_table(&doc[range.clone()]));
-                let _ = ele!(iter, Table, range); // consume the whole table
+            Event::Start(Tag::Table(aligns)) => {
+                let mut block = Block::default();
+                let iter = ele!(iter, Table, range);
+                let table = table::parse(aligns, iter, &mut block, doc, blocks.links());
+                block.set_table(table);
+                blocks.push(block);
             }
             Event::Start(Tag::BlockQuote) => {
                 if let Some((Event::Start(Tag::Paragraph), range)) = iter.next() {
@@ -97,17 +106,32 @@ pub fn parse(doc: &str) -> Blocks {
     blocks
 }
 
+const OPTIONS: Options = Options::ENABLE_FOOTNOTES
+    .union(Options::ENABLE_STRIKETHROUGH)
+    .union(Options::ENABLE_TABLES)
+    .union(Options::ENABLE_TASKLISTS);
+
+#[cfg(test)]
 fn markdown_iter(
     doc: &str,
 ) -> pulldown_cmark::OffsetIter<'_, pulldown_cmark::DefaultBrokenLinkCallback> {
-    Parser::new_ext(
-        doc,
-        Options::ENABLE_FOOTNOTES
-            | Options::ENABLE_STRIKETHROUGH
-            | Options::ENABLE_TABLES
-            | Options::ENABLE_TASKLISTS,
-    )
-    .into_offset_iter()
+    Parser::new_ext(doc, OPTIONS).into_offset_iter()
+}
+
+/// Shortcut links like ``[`Vec`]`` have no definitions in markdown, thus are broken links
+/// for pulldown_cmark. But rustdoc resolves them as intra-doc links, so we treat them as
+/// normal links when they are found in `Item::links`.
+fn markdown_iter_with_intra_doc_links<'doc>(
+    doc: &'doc str,
+    intra_doc_links: Rc<IntraDocLinks>,
+) -> impl Iterator<Item = EventRange<'doc>> {
+    let callback = move |link: BrokenLink<'doc>| -> Option<(CowStr<'doc>, CowStr<'doc>)> {
+        let dest = &*link.reference;
+        intra_doc_links
+            .contains_key(dest)
+            .then(|| (link.reference.clone(), CowStr::Borrowed("")))
+    };
+    Parser::new_with_broken_link_callback(doc, OPTIONS, Some(callback)).into_offset_iter()
 }
 
 #[cfg(test)]
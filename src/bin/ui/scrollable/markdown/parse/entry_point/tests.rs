@@ -1,5 +1,7 @@
-use super::{markdown_iter, parse};
+use super::{markdown_iter, parse, parse_with_intra_doc_links};
+use crate::ui::scrollable::markdown::parse::{IntraDocLink, IntraDocLinks};
 use insta::{assert_debug_snapshot as snap, assert_display_snapshot as shot};
+use term_rustdoc::tree::IdToID;
 
 #[test]
 fn parse_markdown() {
@@ -101,3 +103,87 @@ fn parse_markdown_intra_code() {
     let doc = "A `code` in a line.";
     dbg!(markdown_iter(doc).collect::<Vec<_>>(), parse(doc));
 }
+
+#[test]
+fn parse_markdown_intra_doc_links() {
+    let doc = "See [`Foo`] and [`Vec`], or [foo](crate::Foo) and [unknown].";
+    let intra_doc_links: IntraDocLinks = [
+        ("`Foo`", IntraDocLink::Local("0:1".to_ID())),
+        ("crate::Foo", IntraDocLink::Local("0:1".to_ID())),
+        ("`Vec`", IntraDocLink::External("alloc::vec::Vec".into())),
+    ]
+    .into_iter()
+    .map(|(dest, link)| (dest.into(), link))
+    .collect();
+    let mut blocks = parse_with_intra_doc_links(doc, intra_doc_links);
+    shot!(blocks, @r###"
+    See `Foo` and [`Vec`][0], or foo and [unknown].
+
+    "###);
+
+    blocks.write_styled_lines(20.0);
+    snap!(
+        "parse_markdown_intra_doc_links-item_links",
+        blocks.links().item_links()
+    );
+}
+
+#[test]
+fn parse_markdown_table() {
+    let doc = "
+| Feature | Default | Description |
+| :------ | :-----: | ----------: |
+| `std` | yes | Enables [`Vec`] and *allocation* support. |
+| `serde` | no | Serialization via [serde]. |
+
+[serde]: https://serde.rs
+";
+    let intra_doc_links: IntraDocLinks = [("`Vec`", IntraDocLink::Local("0:1".to_ID()))]
+        .into_iter()
+        .map(|(dest, link)| (dest.into(), link))
+        .collect();
+    let mut blocks = parse_with_intra_doc_links(doc, intra_doc_links);
+    shot!(blocks, @r###"
+    | Feature | Default | Description |
+    | :-- | :-: | --: |
+    | `std` | yes | Enables `Vec` and allocation support. |
+    | `serde` | no | Serialization via [serde][0]. |
+
+    "###);
+
+    let text = |lines: &[crate::ui::scrollable::markdown::fallback::StyledLine]| {
+        let lines = lines.iter().map(|line| {
+            let line = line.iter_text_style().map(|(text, _)| text);
+            line.collect::<String>()
+        });
+        lines.collect::<Vec<_>>().join("\n")
+    };
+    shot!(text(&blocks.write_styled_lines(80.0)), @r###"
+    ┌─────────┬─────────┬───────────────────────────────────────┐
+    │ Feature │ Default │                           Description │
+    ╞═════════╪═════════╪═══════════════════════════════════════╡
+    │ `std`   │   yes   │ Enables `Vec` and allocation support. │
+    │ `serde` │   no    │         Serialization via [serde][0]. │
+    └─────────┴─────────┴───────────────────────────────────────┘
+
+    [0]: https://serde.rs
+    "###);
+    // cells are wrapped when the panel is narrow
+    shot!(text(&blocks.write_styled_lines(40.0)), @r###"
+    ┌─────────┬─────────┬──────────────────┐
+    │ Feature │ Default │      Description │
+    ╞═════════╪═════════╪══════════════════╡
+    │ `std`   │   yes   │    Enables `Vec` │
+    │         │         │   and allocation │
+    │         │         │         support. │
+    │ `serde` │   no    │    Serialization │
+    │         │         │  via [serde][0]. │
+    └─────────┴─────────┴──────────────────┘
+
+    [0]: https://serde.rs
+    "###);
+    snap!(
+        "parse_markdown_table-item_links",
+        blocks.links().item_links()
+    );
+}
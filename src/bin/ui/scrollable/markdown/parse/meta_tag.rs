@@ -1,7 +1,11 @@
-use term_rustdoc::{tree::ID, util::XString};
+use term_rustdoc::{
+    tree::ID,
+    util::{HashMap, XString},
+};
 
 /// Extra meaning not so relevant to style in current word.
 #[derive(Default, Clone, Debug)]
+#[allow(dead_code)]
 pub enum MetaTag {
     #[default]
     Normal,
@@ -34,7 +38,7 @@ pub enum MetaTag {
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
 pub enum LinkTag {
-    /// local crate item can be referred by item ID
+    /// local crate item can be referred by item ID, and is jumpable
     LocalItemLink(ID),
     /// points to a external crate item path (may be supported once multi-crate docs are ready)
     ExternalItemLink(usize),
@@ -49,3 +53,17 @@ pub enum LinkTag {
     /// broken link or invalid input tag
     Unknown,
 }
+
+/// Intra-doc links of an item, resolved from `Item::links` in rustdoc json.
+///
+/// The key is the link destination written in markdown, like `` `Vec` `` in ``[`Vec`]``
+/// or `crate::foo::Bar` in `[Bar](crate::foo::Bar)`.
+pub type IntraDocLinks = HashMap<XString, IntraDocLink>;
+
+#[derive(Clone, Debug)]
+pub enum IntraDocLink {
+    /// An item in the local crate.
+    Local(ID),
+    /// An item from an external crate, displayed as a reference link with its full path.
+    External(XString),
+}
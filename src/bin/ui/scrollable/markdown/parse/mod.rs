@@ -15,6 +15,7 @@ mod element;
 mod entry_point;
 mod list;
 mod meta_tag;
+mod table;
 
 mod block;
 mod blocks;
@@ -25,7 +26,7 @@ pub use self::{
     block::Block,
     blocks::{Blocks, Links},
     line::Line,
-    meta_tag::{LinkTag, MetaTag},
+    meta_tag::{IntraDocLink, IntraDocLinks, LinkTag, MetaTag},
     word::Word,
 };
 
@@ -62,19 +63,32 @@ pub fn segment_words(text: &str, mut f: impl FnMut(&str, bool)) {
     });
 }
 
-pub fn parse_doc(doc: &str, width: f64) -> (Vec<StyledLine>, Blocks, Headings) {
-    let mut blocks = entry_point::parse(doc);
+pub fn parse_doc(
+    doc: &str,
+    intra_doc_links: IntraDocLinks,
+    width: f64,
+) -> (Vec<StyledLine>, Blocks, Headings) {
+    let mut blocks = entry_point::parse_with_intra_doc_links(doc, intra_doc_links);
     let lines = blocks.write_styled_lines(width);
     let headings = blocks.links().to_heading();
     (lines, blocks, headings)
 }
 
 pub fn md(doc: &str) -> Vec<StyledLine> {
+    highlight(doc, "md")
+}
+
+/// Rust source code highlighted by syntect with no text wrapped.
+pub fn rust_source(code: &str) -> Vec<StyledLine> {
+    highlight(code, "rs")
+}
+
+fn highlight(text: &str, extension: &str) -> Vec<StyledLine> {
     let mut lines = Vec::with_capacity(128);
     SYNTHEME.with(|(ps, ts)| {
-        let syntax = ps.find_syntax_by_extension("md").unwrap();
+        let syntax = ps.find_syntax_by_extension(extension).unwrap();
         let mut h = HighlightLines::new(syntax, &ts.themes["base16-ocean.dark"]);
-        for line in LinesWithEndings::from(doc) {
+        for line in LinesWithEndings::from(text) {
             let mut styled_line = StyledLine::new();
             for (style, text) in h.highlight_line(line, ps).unwrap() {
                 styled_line.push(text, convert_style(style));
@@ -0,0 +1,266 @@
+//! Tables are rendered as box-drawn grids. Unlike other blocks, the layout of a table
+//! depends on the available width, so cells are only wrapped when writing styled lines.
+
+use super::{
+    element::{Element, EventRange},
+    Block, Links, Word,
+};
+use pulldown_cmark::{Alignment, Event, Tag};
+use ratatui::style::{Color, Modifier, Style};
+use std::fmt;
+use term_rustdoc::util::XString;
+use textwrap::{
+    core::Fragment,
+    wrap_algorithms::{wrap_optimal_fit, Penalties},
+};
+use unicode_width::UnicodeWidthChar;
+
+const BORDER: Style = Style {
+    fg: Some(Color::Rgb(112, 128, 144)), // #708090
+    bg: None,
+    underline_color: None,
+    add_modifier: Modifier::empty(),
+    sub_modifier: Modifier::empty(),
+};
+
+#[derive(Debug, Default)]
+pub struct Table {
+    aligns: Vec<Alignment>,
+    /// The first row is the header. Each cell is a line of words to be wrapped.
+    rows: Vec<Vec<Vec<Word>>>,
+}
+
+/// Parse cells in a table. Links and footnotes in cells are stored in the block.
+pub fn parse<'doc>(
+    aligns: Vec<Alignment>,
+    mut iter: impl Iterator<Item = EventRange<'doc>>,
+    block: &mut Block,
+    doc: &'doc str,
+    links: &mut Links,
+) -> Table {
+    let mut rows: Vec<Vec<Vec<Word>>> = Vec::with_capacity(8);
+    while let Some((event, range)) = iter.next() {
+        match event {
+            Event::Start(Tag::TableHead | Tag::TableRow) => rows.push(Vec::new()),
+            Event::Start(Tag::TableCell) => {
+                let mut cell = Block::default();
+                let iter = ele!(iter, TableCell, range);
+                Element::new(doc, &mut cell, links, iter).parse_paragraph();
+                cell.links().iter().for_each(|&idx| block.push_link(idx));
+                cell.footnotes()
+                    .iter()
+                    .for_each(|key| block.push_footnote(key.clone()));
+                let mut words = cell.into_words();
+                if rows.len() == 1 {
+                    // header
+                    for word in &mut words {
+                        word.style = word.style.add_modifier(Modifier::BOLD);
+                    }
+                }
+                match rows.last_mut() {
+                    Some(row) => row.push(words),
+                    None => rows.push(vec![words]),
+                }
+            }
+            _ => (),
+        }
+    }
+    Table { aligns, rows }
+}
+
+/// Width of words in a line, excluding the trailling whitespace of the last word.
+fn line_width(words: &[Word]) -> usize {
+    let width: f64 = words.iter().map(|w| w.width() + w.whitespace_width()).sum();
+    let last = words.last().map_or(0.0, |w| w.whitespace_width());
+    (width - last) as usize
+}
+
+fn max_word_width(words: &[Word]) -> usize {
+    words.iter().map(|w| w.width() as usize).max().unwrap_or(0)
+}
+
+/// Split words wider than the column into pieces so that every piece fits.
+fn split_long_words(words: &[Word], width: usize) -> Vec<Word> {
+    let mut splitted = Vec::with_capacity(words.len());
+    for word in words {
+        if (word.width() as usize) <= width {
+            splitted.push(word.clone());
+            continue;
+        }
+        let mut piece = XString::default();
+        let mut piece_width = 0;
+        for c in word.word.chars() {
+            let w = c.width().unwrap_or(0);
+            if piece_width + w > width && !piece.is_empty() {
+                splitted.push(Word {
+                    word: std::mem::take(&mut piece),
+                    trailling_whitespace: false,
+                    ..word.clone()
+                });
+                piece_width = 0;
+            }
+            piece.push(c);
+            piece_width += w;
+        }
+        splitted.push(Word {
+            word: piece,
+            ..word.clone()
+        });
+    }
+    splitted
+}
+
+fn spaces(n: usize) -> Word {
+    Word {
+        word: " ".repeat(n).into(),
+        ..Default::default()
+    }
+}
+
+fn bar() -> Word {
+    Word {
+        word: "│".into(),
+        style: BORDER,
+        ..Default::default()
+    }
+}
+
+fn border(left: &str, fill: &str, mid: &str, right: &str, widths: &[usize]) -> Vec<Word> {
+    let mut line = String::from(left);
+    for (idx, width) in widths.iter().enumerate() {
+        if idx != 0 {
+            line.push_str(mid);
+        }
+        line.push_str(&fill.repeat(width + 2));
+    }
+    line.push_str(right);
+    vec![Word {
+        word: line.into(),
+        style: BORDER,
+        ..Default::default()
+    }]
+}
+
+impl Table {
+    fn columns(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Decide the width of each column: use the natural widths if the table fits in,
+    /// otherwise shrink columns with longer texts first.
+    fn column_widths(&self, width: usize) -> Vec<usize> {
+        let ncol = self.columns();
+        let column = |f: fn(&[Word]) -> usize| -> Vec<usize> {
+            (0..ncol)
+                .map(|c| {
+                    let cells = self.rows.iter().filter_map(|row| row.get(c));
+                    cells.map(|cell| f(cell)).max().unwrap_or(0).max(1)
+                })
+                .collect()
+        };
+        let natural = column(line_width);
+        // each column takes two paddings and a separator, plus the leading border
+        let avail = width.saturating_sub(ncol * 3 + 1).max(ncol);
+        let natural_total: usize = natural.iter().sum();
+        if natural_total <= avail {
+            return natural;
+        }
+        let min: Vec<_> = column(max_word_width)
+            .into_iter()
+            .zip(&natural)
+            .map(|(min, &natural)| min.min(natural))
+            .collect();
+        let min_total: usize = min.iter().sum();
+        if min_total >= avail {
+            // even the longest words don't fit, so they will be splitted
+            return min.iter().map(|w| (w * avail / min_total).max(1)).collect();
+        }
+        let extra = avail - min_total;
+        let flex = natural_total - min_total;
+        min.iter()
+            .zip(&natural)
+            .map(|(min, natural)| min + (natural - min) * extra / flex)
+            .collect()
+    }
+
+    /// Lay out the table in the given width, and returns lines of words to be rendered.
+    pub fn write_lines(&self, width: usize) -> Vec<Vec<Word>> {
+        if self.columns() == 0 {
+            return Vec::new();
+        }
+        let widths = self.column_widths(width);
+        let penalties = Penalties::default();
+        let mut lines = Vec::with_capacity(self.rows.len() * 2 + 2);
+        lines.push(border("┌", "─", "┬", "┐", &widths));
+        for (idx, row) in self.rows.iter().enumerate() {
+            let cells: Vec<Vec<Vec<Word>>> = widths
+                .iter()
+                .enumerate()
+                .map(|(c, &width)| {
+                    let Some(cell) = row.get(c) else {
+                        return Vec::new();
+                    };
+                    let words = split_long_words(cell, width);
+                    match wrap_optimal_fit(&words, &[width as f64], &penalties) {
+                        Ok(wrapped) => wrapped.into_iter().map(<[Word]>::to_vec).collect(),
+                        Err(err) => {
+                            error!("failed to wrap the table cell to width {width}:{err}");
+                            vec![words]
+                        }
+                    }
+                })
+                .collect();
+            let height = cells.iter().map(Vec::len).max().unwrap_or(0).max(1);
+            for h in 0..height {
+                let mut line = vec![bar()];
+                for (c, &width) in widths.iter().enumerate() {
+                    let mut words = cells[c].get(h).cloned().unwrap_or_default();
+                    if let Some(last) = words.last_mut() {
+                        last.trailling_whitespace = false;
+                    }
+                    let rest = width.saturating_sub(line_width(&words));
+                    let (left, right) = match self.aligns.get(c) {
+                        Some(Alignment::Right) => (rest, 0),
+                        Some(Alignment::Center) => (rest / 2, rest - rest / 2),
+                        _ => (0, rest),
+                    };
+                    line.push(spaces(left + 1));
+                    line.extend(words);
+                    line.push(spaces(right + 1));
+                    line.push(bar());
+                }
+                lines.push(line);
+            }
+            if idx == 0 && self.rows.len() > 1 {
+                lines.push(border("╞", "═", "╪", "╡", &widths));
+            }
+        }
+        lines.push(border("└", "─", "┴", "┘", &widths));
+        lines
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows.iter().enumerate() {
+            for cell in row {
+                f.write_str("| ")?;
+                cell.iter().try_for_each(|word| word.fmt(f))?;
+                f.write_str(" ")?;
+            }
+            writeln!(f, "|")?;
+            if idx == 0 {
+                for align in &self.aligns {
+                    f.write_str(match align {
+                        Alignment::None => "| --- ",
+                        Alignment::Left => "| :-- ",
+                        Alignment::Center => "| :-: ",
+                        Alignment::Right => "| --: ",
+                    })?;
+                }
+                writeln!(f, "|")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use super::MetaTag;
+use super::{LinkTag, MetaTag};
 use crate::ui::scrollable::markdown::{fallback::StyledLine, region::LinkedRegions, StyledText};
 use ratatui::style::Style;
 use std::fmt::{self, Write};
@@ -44,9 +44,11 @@ impl Word {
         let mut line = StyledLine::from(
             iter.map(|word| {
                 let (text, tag) = word.into_text(start);
-                #[allow(clippy::single_match)]
                 match tag {
                     MetaTag::Heading(idx) => linked_regions.push_heading(idx, row, text.span()),
+                    MetaTag::Link(LinkTag::LocalItemLink(id)) => {
+                        linked_regions.push_item_link(id, row, text.span())
+                    }
                     _ => (),
                 }
                 start = text.span_end();
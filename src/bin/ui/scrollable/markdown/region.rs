@@ -5,7 +5,10 @@ use super::{
 };
 use smallvec::SmallVec;
 use std::cmp::Ordering;
-use term_rustdoc::util::{hashmap, HashMap, XString};
+use term_rustdoc::{
+    tree::ID,
+    util::{hashmap, HashMap, XString},
+};
 
 /// The selected texts will be rendered with original fg but grey bg.
 ///
@@ -23,6 +26,34 @@ impl SelectedRegion {
         self.row_start
     }
 
+    pub fn row_end(&self) -> usize {
+        self.row_end
+    }
+
+    /// The column range `[start, end)` in the given row, if the row lies in this region.
+    pub fn col_range(&self, row: usize, width: usize) -> Option<[usize; 2]> {
+        if row < self.row_start || row > self.row_end {
+            return None;
+        }
+        let start = if row == self.row_start {
+            self.col_start
+        } else {
+            0
+        };
+        let end = if row == self.row_end {
+            self.col_end
+        } else {
+            width
+        };
+        Some([start, end])
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        self.col_range(row, usize::MAX)
+            .map(|[start, end]| start <= col && col < end)
+            .unwrap_or(false)
+    }
+
     fn new_same_line(row: usize, col: ColumnSpan) -> Self {
         let [start, end] = col.span();
         SelectedRegion {
@@ -95,6 +126,7 @@ impl From<SelectedRegion> for TargetRegion {
 pub struct LinkedRegions {
     tag: HashMap<RegionTag, TargetRegion>,
     heading: Vec<(usize, SelectedRegion)>,
+    item_links: Vec<(ID, SelectedRegion)>,
 }
 
 impl LinkedRegions {
@@ -102,6 +134,7 @@ impl LinkedRegions {
         LinkedRegions {
             tag: hashmap(8),
             heading: Vec::with_capacity(8),
+            item_links: Vec::new(),
         }
     }
 
@@ -120,6 +153,24 @@ impl LinkedRegions {
     pub fn take_headings(&mut self) -> Vec<(usize, SelectedRegion)> {
         std::mem::take(&mut self.heading)
     }
+
+    /// A local item link may consist of multiple words, thus merge them into one region
+    /// when they're continuous in the same line. But a wrapped link will be split
+    /// into multiple regions, which is fine for jumping.
+    pub fn push_item_link(&mut self, id: ID, row: usize, col: ColumnSpan) {
+        let region = SelectedRegion::new_same_line(row, col);
+        if let Some((last_id, old)) = self.item_links.last_mut() {
+            if *last_id == id && old.row_end == row && old.col_end == region.col_start {
+                old.merge_continuous(region);
+                return;
+            }
+        }
+        self.item_links.push((id, region));
+    }
+
+    pub fn take_item_links(&mut self) -> Vec<(ID, SelectedRegion)> {
+        std::mem::take(&mut self.item_links)
+    }
 }
 
 pub fn region_tag(tag: MetaTag) -> Option<RegionTag> {
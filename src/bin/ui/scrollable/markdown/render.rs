@@ -1,10 +1,35 @@
 use super::fallback::{ScrollText, StyledLine};
-use crate::ui::scrollable::generics::render_line;
-use ratatui::prelude::{Buffer, Rect};
+use crate::{color::BG_CURSOR_LINE, ui::scrollable::generics::render_line};
+use ratatui::prelude::{Buffer, Rect, Style};
 
 impl ScrollText {
     pub fn render(&self, buf: &mut Buffer) {
         write_lines(&self.lines, self.start, self.area, buf);
+        self.highlight_selected_item_link(buf);
+    }
+
+    fn highlight_selected_item_link(&self, buf: &mut Buffer) {
+        let Some((_, region)) = self.selected_item_link() else {
+            return;
+        };
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.area;
+        let style = Style::new().bg(BG_CURSOR_LINE);
+        let row_end = self.start + height as usize;
+        for row in region.row_start().max(self.start)..=region.row_end().min(row_end - 1) {
+            if let Some([start, end]) = region.col_range(row, width as usize) {
+                let end = end.min(width as usize);
+                if start < end {
+                    let y = y + (row - self.start) as u16;
+                    let area = Rect::new(x + start as u16, y, (end - start) as u16, 1);
+                    buf.set_style(area, style);
+                }
+            }
+        }
     }
 }
 
@@ -3,6 +3,7 @@ use std::fmt;
 use term_rustdoc::{tree::Text, util::XString};
 use unicode_width::UnicodeWidthStr;
 
+#[derive(Clone)]
 pub struct StyledText {
     text: Text,
     span: ColumnSpan,
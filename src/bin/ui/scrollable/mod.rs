@@ -11,7 +11,9 @@ mod render;
 
 pub use self::generics::{render_line, LineState, Lines};
 pub use self::interaction::{ScrollOffset, Scrollable};
-pub use self::markdown::{Headings, MarkdownAndHeading, ScrollHeading, ScrollMarkdown, ScrollText};
+pub use self::markdown::{
+    Headings, JoinedRows, MarkdownAndHeading, ScrollHeading, ScrollMarkdown, ScrollText,
+};
 
 /// Scrollable tree view but stored in lines.
 pub type ScrollTreeLines = Scroll<TreeLines>;
@@ -33,7 +33,19 @@ fn render_line(line: &TreeLine, buf: &mut Buffer, x: u16, y: u16, width: usize)
     let [(glyph, g_style), (name, n_style)] = line.glyph_name();
     let (x_name, _) = buf.set_stringn(x, y, glyph, width, g_style);
     if let Some(remain) = width.checked_sub((x_name - x) as usize) {
-        buf.set_stringn(x_name, y, name, remain, n_style);
+        let (x_badges, _) = buf.set_stringn(x_name, y, name, remain, n_style);
+        render_badges(line, buf, x_badges, y, width - (x_badges - x) as usize);
+    }
+}
+
+fn render_badges(line: &TreeLine, buf: &mut Buffer, mut x: u16, y: u16, mut width: usize) {
+    for (badge, style) in line.badges() {
+        if width <= 1 {
+            return;
+        }
+        let (x_end, _) = buf.set_stringn(x + 1, y, badge, width - 1, style);
+        width = width.saturating_sub((x_end - x) as usize);
+        x = x_end;
     }
 }
 
@@ -45,6 +57,7 @@ fn render_current_line(line: &TreeLine, buf: &mut Buffer, x: u16, y: u16, width:
     n_style.bg = n_style.fg;
     n_style.fg = Some(Color::Black);
     if let Some(remain) = width.checked_sub((x_name - x) as usize) {
-        buf.set_stringn(x_name, y, name, remain, n_style);
+        let (x_badges, _) = buf.set_stringn(x_name, y, name, remain, n_style);
+        render_badges(line, buf, x_badges, y, width - (x_badges - x) as usize);
     }
 }
@@ -0,0 +1,114 @@
+use super::IDMap;
+use crate::util::{xformat, XString};
+use ratatui::style::{Color, Style};
+use rustdoc_types::{Deprecation, Item, ItemEnum};
+
+/// Notable attributes and qualifiers on an item, shown after the name in outline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Badges {
+    pub deprecated: bool,
+    pub non_exhaustive: bool,
+    pub must_use: bool,
+    pub unsafe_: bool,
+    pub const_: bool,
+}
+
+impl Badges {
+    pub fn new(item: &Item) -> Self {
+        let has_attr = |name: &str| item.attrs.iter().any(|attr| attr_name(attr) == name);
+        let (unsafe_, const_) = match &item.inner {
+            ItemEnum::Function(f) => (f.header.unsafe_, f.header.const_),
+            ItemEnum::Trait(t) => (t.is_unsafe, false),
+            ItemEnum::Impl(i) => (i.is_unsafe, false),
+            _ => (false, false),
+        };
+        Badges {
+            deprecated: item.deprecation.is_some(),
+            non_exhaustive: has_attr("non_exhaustive"),
+            must_use: has_attr("must_use"),
+            unsafe_,
+            const_,
+        }
+    }
+
+    /// Texts and styles of badges in order.
+    pub fn texts(&self) -> impl Iterator<Item = (&'static str, Style)> {
+        let fg = |color| Style::default().fg(color);
+        [
+            (self.deprecated, "deprecated", fg(Color::LightRed)),
+            (self.non_exhaustive, "non_exhaustive", fg(Color::LightBlue)),
+            (self.must_use, "must_use", fg(Color::LightGreen)),
+            (self.unsafe_, "unsafe", fg(Color::Red)),
+            (self.const_, "const", fg(Color::LightMagenta)),
+        ]
+        .into_iter()
+        .filter_map(|(show, text, style)| show.then_some((text, style)))
+    }
+
+    /// Unicode width of all badges with a leading whitespace for each.
+    pub fn width(&self) -> usize {
+        self.texts().map(|(text, _)| text.len() + 1).sum()
+    }
+}
+
+/// The name of an attribute like `#[must_use = "reason"]` is `must_use`.
+fn attr_name(attr: &str) -> &str {
+    let attr = attr.trim_start_matches("#[").trim_end_matches(']');
+    let end = attr.find(|c: char| !(c.is_alphanumeric() || c == '_'));
+    &attr[..end.unwrap_or(attr.len())]
+}
+
+/// Attributes and deprecation of an item. Reexported items refer to their source items.
+impl IDMap {
+    pub(super) fn source_item(&self, id: &str) -> Option<&Item> {
+        let item = self.get_item(id)?;
+        match &item.inner {
+            ItemEnum::Import(import) => import
+                .id
+                .as_ref()
+                .and_then(|id| self.get_item(&id.0))
+                .filter(|source| !matches!(source.inner, ItemEnum::Import(_))),
+            _ => Some(item),
+        }
+    }
+
+    pub fn badges(&self, id: &str) -> Badges {
+        self.source_item(id).map(Badges::new).unwrap_or_default()
+    }
+
+    pub fn is_deprecated(&self, id: &str) -> bool {
+        self.source_item(id)
+            .is_some_and(|item| item.deprecation.is_some())
+    }
+
+    pub fn deprecation(&self, id: &str) -> Option<&Deprecation> {
+        self.source_item(id)?.deprecation.as_ref()
+    }
+
+    /// Attributes shown above the declaration. Doc comments are excluded, and deprecation
+    /// is rendered back as an attribute.
+    pub fn attributes(&self, id: &str) -> Vec<XString> {
+        let Some(item) = self.source_item(id) else {
+            return Vec::new();
+        };
+        let mut attrs = Vec::with_capacity(item.attrs.len() + 1);
+        if let Some(Deprecation { since, note }) = &item.deprecation {
+            let args = [("since", since), ("note", note)]
+                .into_iter()
+                .filter_map(|(key, val)| val.as_ref().map(|val| xformat!("{key} = {val:?}")))
+                .collect::<Vec<_>>();
+            attrs.push(if args.is_empty() {
+                "#[deprecated]".into()
+            } else {
+                xformat!("#[deprecated({})]", args.join(", "))
+            });
+        }
+        attrs.extend(
+            item.attrs
+                .iter()
+                .filter(|attr| !matches!(attr_name(attr), "doc" | "deprecated"))
+                .map(|attr| XString::from(attr.as_str())),
+        );
+        attrs
+    }
+}
@@ -0,0 +1,170 @@
+use super::{
+    search::{impl_trait_names, trait_path},
+    DModule, IDMap, IdToID, ID,
+};
+use crate::{
+    type_name::{same_trait, style::short},
+    util::{xformat, XString},
+};
+use rustdoc_types::{
+    GenericBound, GenericParamDefKind, Generics, ItemEnum, TraitBoundModifier, Type, WherePredicate,
+};
+
+/// A generic type parameter with trait bounds, e.g. `T: Read + Send`.
+#[derive(Debug, Clone)]
+pub struct BoundedParam {
+    pub name: XString,
+    /// Full paths of traits if known, otherwise trait names.
+    pub bounds: Vec<XString>,
+}
+
+impl BoundedParam {
+    /// Bounds are shown in names, like `T: Read + Send`.
+    pub fn display(&self) -> XString {
+        let names = self
+            .bounds
+            .iter()
+            .map(|b| b.rsplit("::").next().unwrap_or(b));
+        xformat!("{}: {}", self.name, names.collect::<Vec<_>>().join(" + "))
+    }
+}
+
+/// A concrete type that satisfies some trait bounds.
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    /// Type name like `File` or `&[u8]`.
+    pub name: XString,
+    /// The id of the type if it's a path to an item, which is jumpable.
+    pub id: Option<ID>,
+}
+
+/// Concrete types for generic parameters and traits.
+impl IDMap {
+    /// Generic type parameters with trait bounds on the item, including bounds in where
+    /// clauses. `?Sized` bounds are skipped, and params without any bound are excluded.
+    pub fn bounded_params(&self, id: &str) -> Vec<BoundedParam> {
+        let Some(item) = self.source_item(id) else {
+            return Vec::new();
+        };
+        let generics = match &item.inner {
+            ItemEnum::Function(f) => &f.generics,
+            ItemEnum::Struct(s) => &s.generics,
+            ItemEnum::Enum(e) => &e.generics,
+            ItemEnum::Union(u) => &u.generics,
+            ItemEnum::Trait(t) => &t.generics,
+            ItemEnum::TypeAlias(t) => &t.generics,
+            ItemEnum::Impl(i) => &i.generics,
+            _ => return Vec::new(),
+        };
+        bounded_params(generics, self)
+    }
+
+    /// Types defined in this crate that implement all the traits. Traits are compared by
+    /// full paths if known, otherwise by names, thus traits from other crates can be used.
+    pub fn types_implementing(&self, traits: &[XString]) -> Vec<Candidate> {
+        let mut candidates = Vec::new();
+        if !traits.is_empty() {
+            self.dmodule()
+                .push_types_implementing(self, traits, &mut candidates);
+        }
+        candidates
+    }
+
+    /// Implementors of the trait defined in this crate, including types from other crates
+    /// and non-path types like `&[u8]`.
+    pub fn implementors(&self, trait_id: &str) -> Vec<Candidate> {
+        let Some(ItemEnum::Trait(trait_)) = self.source_item(trait_id).map(|item| &item.inner)
+        else {
+            return Vec::new();
+        };
+        let mut candidates: Vec<_> = trait_
+            .implementations
+            .iter()
+            .filter_map(|id| match &self.get_item(&id.0)?.inner {
+                ItemEnum::Impl(imp) => Some(Candidate {
+                    name: short(&imp.for_).into(),
+                    id: match &imp.for_ {
+                        Type::ResolvedPath(path) => Some(path.id.to_ID()),
+                        _ => None,
+                    },
+                }),
+                _ => None,
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.name.cmp(&b.name));
+        candidates.dedup_by(|a, b| a.name == b.name);
+        candidates
+    }
+}
+
+impl DModule {
+    fn push_types_implementing(
+        &self,
+        map: &IDMap,
+        traits: &[XString],
+        candidates: &mut Vec<Candidate>,
+    ) {
+        macro_rules! push {
+            ($( $field:ident ),+) => {$(
+                for item in &self.$field {
+                    let implemented = impl_trait_names(&item.impls, map);
+                    let implemented = |t: &XString| implemented.iter().any(|i| same_trait(i, t));
+                    if traits.iter().all(implemented) {
+                        candidates.push(Candidate {
+                            name: map.name(&item.id),
+                            id: Some(item.id.clone()),
+                        });
+                    }
+                }
+            )+};
+        }
+        push!(structs, unions, enums);
+        for m in &self.modules {
+            m.push_types_implementing(map, traits, candidates);
+        }
+    }
+}
+
+fn trait_paths<'a>(
+    bounds: &'a [GenericBound],
+    map: &'a IDMap,
+) -> impl 'a + Iterator<Item = XString> {
+    bounds.iter().filter_map(|bound| match bound {
+        GenericBound::TraitBound {
+            trait_, modifier, ..
+        } if !matches!(modifier, TraitBoundModifier::Maybe) => Some(trait_path(trait_, map)),
+        _ => None,
+    })
+}
+
+fn bounded_params(generics: &Generics, map: &IDMap) -> Vec<BoundedParam> {
+    let mut params: Vec<BoundedParam> = generics
+        .params
+        .iter()
+        .filter_map(|param| match &param.kind {
+            GenericParamDefKind::Type { bounds, .. } => Some(BoundedParam {
+                name: param.name.as_str().into(),
+                bounds: trait_paths(bounds, map).collect(),
+            }),
+            _ => None,
+        })
+        .collect();
+    for pred in &generics.where_predicates {
+        if let WherePredicate::BoundPredicate {
+            type_: Type::Generic(name),
+            bounds,
+            ..
+        } = pred
+        {
+            if let Some(param) = params.iter_mut().find(|p| p.name == *name) {
+                for bound in trait_paths(bounds, map) {
+                    if !param.bounds.contains(&bound) {
+                        param.bounds.push(bound);
+                    }
+                }
+            }
+        }
+    }
+    params.retain(|param| !param.bounds.is_empty());
+    params
+}
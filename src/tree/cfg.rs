@@ -0,0 +1,224 @@
+use super::IDMap;
+use crate::util::{xformat, XString};
+use std::fmt;
+
+/// A parsed predicate from `#[cfg(...)]` or `#[doc(cfg(...))]` on an item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cfg {
+    /// `feature = "name"`
+    Feature(XString),
+    /// Other predicates like `unix` or `target_os = "linux"` kept as they are.
+    Other(XString),
+    Not(Box<Cfg>),
+    All(Vec<Cfg>),
+    Any(Vec<Cfg>),
+}
+
+impl Cfg {
+    /// Parse the predicate in an attribute. Returns None if the attribute is not a cfg.
+    pub fn parse_attr(attr: &str) -> Option<Cfg> {
+        let attr = attr.trim().strip_prefix("#[")?.strip_suffix(']')?.trim();
+        let inner = match attr.strip_prefix("doc") {
+            Some(doc) => strip_parens(doc)?,
+            None => attr,
+        };
+        let pred = strip_parens(inner.trim().strip_prefix("cfg")?)?;
+        let tokens = tokenize(pred)?;
+        let mut tokens = tokens.as_slice();
+        let cfg = parse_pred(&mut tokens)?;
+        tokens.is_empty().then_some(cfg)
+    }
+
+    /// Feature names required or mentioned in the predicate.
+    pub fn features(&self) -> Vec<&str> {
+        fn collect<'a>(cfg: &'a Cfg, features: &mut Vec<&'a str>) {
+            match cfg {
+                Cfg::Feature(f) => features.push(f),
+                Cfg::Other(_) => (),
+                Cfg::Not(cfg) => collect(cfg, features),
+                Cfg::All(cfgs) | Cfg::Any(cfgs) => cfgs.iter().for_each(|c| collect(c, features)),
+            }
+        }
+        let mut features = Vec::new();
+        collect(self, &mut features);
+        features
+    }
+
+    /// Evaluate the predicate with only the given features enabled.
+    /// Predicates other than features are considered to be satisfied.
+    pub fn is_enabled<S: AsRef<str>>(&self, features: &[S]) -> bool {
+        match self {
+            Cfg::Feature(f) => features.iter().any(|s| s.as_ref() == f.as_str()),
+            Cfg::Other(_) => true,
+            Cfg::Not(cfg) => match **cfg {
+                // `not(unix)` can also be true on another platform
+                Cfg::Other(_) => true,
+                ref cfg => !cfg.is_enabled(features),
+            },
+            Cfg::All(cfgs) => cfgs.iter().all(|c| c.is_enabled(features)),
+            Cfg::Any(cfgs) => cfgs.iter().any(|c| c.is_enabled(features)),
+        }
+    }
+
+    /// A description like rustdoc's `Available on crate feature std only`.
+    pub fn describe(&self) -> XString {
+        let join = |cfgs: &[Cfg], sep: &str| {
+            let descs: Vec<_> = cfgs
+                .iter()
+                .map(|cfg| match cfg {
+                    Cfg::All(_) | Cfg::Any(_) => xformat!("({})", cfg.describe()),
+                    _ => cfg.describe(),
+                })
+                .collect();
+            XString::from(descs.join(sep))
+        };
+        match self {
+            Cfg::Feature(f) => xformat!("crate feature `{f}`"),
+            Cfg::Other(s) => xformat!("`{s}`"),
+            Cfg::Not(cfg) => xformat!("non-{}", cfg.describe()),
+            Cfg::All(cfgs) => join(cfgs, " and "),
+            Cfg::Any(cfgs) => join(cfgs, " or "),
+        }
+    }
+}
+
+/// A compact form used in outline, e.g. `std`, `std & alloc` or `!unix`.
+impl fmt::Display for Cfg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, cfgs: &[Cfg], sep: &str| {
+            for (idx, cfg) in cfgs.iter().enumerate() {
+                if idx != 0 {
+                    f.write_str(sep)?;
+                }
+                match cfg {
+                    Cfg::All(_) | Cfg::Any(_) => write!(f, "({cfg})")?,
+                    _ => write!(f, "{cfg}")?,
+                }
+            }
+            Ok(())
+        };
+        match self {
+            Cfg::Feature(s) | Cfg::Other(s) => f.write_str(s),
+            Cfg::Not(cfg) => match **cfg {
+                Cfg::All(_) | Cfg::Any(_) => write!(f, "!({cfg})"),
+                Cfg::Other(ref s) if s.contains(' ') => write!(f, "!({s})"),
+                ref cfg => write!(f, "!{cfg}"),
+            },
+            Cfg::All(cfgs) => join(f, cfgs, " & "),
+            Cfg::Any(cfgs) => join(f, cfgs, " | "),
+        }
+    }
+}
+
+fn strip_parens(s: &str) -> Option<&str> {
+    s.trim().strip_prefix('(')?.strip_suffix(')')
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'s> {
+    Ident(&'s str),
+    Str(&'s str),
+    Eq,
+    Comma,
+    Open,
+    Close,
+}
+
+fn tokenize(s: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = s.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let (token, len) = match c {
+            '=' => (Token::Eq, 1),
+            ',' => (Token::Comma, 1),
+            '(' => (Token::Open, 1),
+            ')' => (Token::Close, 1),
+            '"' => {
+                let end = rest[1..].find('"')? + 1;
+                (Token::Str(&rest[1..end]), end + 1)
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let end = rest
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                (Token::Ident(&rest[..end]), end)
+            }
+            _ => return None,
+        };
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+    Some(tokens)
+}
+
+fn parse_pred(tokens: &mut &[Token]) -> Option<Cfg> {
+    let &[Token::Ident(name), ref rest @ ..] = *tokens else {
+        return None;
+    };
+    *tokens = rest;
+    match (name, rest) {
+        (_, [Token::Eq, Token::Str(val), rest @ ..]) => {
+            *tokens = rest;
+            Some(match name {
+                "feature" => Cfg::Feature((*val).into()),
+                _ => Cfg::Other(xformat!("{name} = {val:?}")),
+            })
+        }
+        ("all" | "any" | "not", [Token::Open, rest @ ..]) => {
+            *tokens = rest;
+            let mut cfgs = Vec::new();
+            loop {
+                match tokens {
+                    [Token::Close, rest @ ..] => {
+                        *tokens = rest;
+                        break;
+                    }
+                    [Token::Comma, rest @ ..] => *tokens = rest,
+                    _ => cfgs.push(parse_pred(tokens)?),
+                }
+            }
+            Some(match name {
+                "all" => Cfg::All(cfgs),
+                "any" => Cfg::Any(cfgs),
+                _ if cfgs.len() == 1 => Cfg::Not(Box::new(cfgs.pop()?)),
+                _ => return None,
+            })
+        }
+        _ => Some(Cfg::Other(name.into())),
+    }
+}
+
+/// Conditional compilation on items.
+impl IDMap {
+    /// The cfg predicate on an item. Multiple cfg attributes are combined as `all`.
+    pub fn cfg(&self, id: &str) -> Option<Cfg> {
+        let item = self.source_item(id)?;
+        let mut cfgs: Vec<_> = item
+            .attrs
+            .iter()
+            .filter_map(|a| Cfg::parse_attr(a))
+            .collect();
+        cfgs.dedup();
+        match cfgs.len() {
+            0 => None,
+            1 => cfgs.pop(),
+            _ => Some(Cfg::All(cfgs)),
+        }
+    }
+
+    /// All features mentioned in cfg attributes across the crate, sorted and deduplicated.
+    pub fn cfg_features(&self) -> Vec<XString> {
+        let mut features = Vec::<XString>::new();
+        for item in self.indexmap().values() {
+            for cfg in item.attrs.iter().filter_map(|a| Cfg::parse_attr(a)) {
+                features.extend(cfg.features().into_iter().map(XString::from));
+            }
+        }
+        features.sort_unstable();
+        features.dedup();
+        features
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,100 @@
+use super::Cfg;
+
+fn feature(name: &str) -> Cfg {
+    Cfg::Feature(name.into())
+}
+
+fn other(pred: &str) -> Cfg {
+    Cfg::Other(pred.into())
+}
+
+fn parse(attr: &str) -> Cfg {
+    Cfg::parse_attr(attr).unwrap_or_else(|| panic!("failed to parse {attr:?}"))
+}
+
+#[test]
+fn parse_predicates() {
+    assert_eq!(parse(r#"#[cfg(feature = "std")]"#), feature("std"));
+    assert_eq!(parse("#[cfg(unix)]"), other("unix"));
+    assert_eq!(
+        parse(r#"#[cfg(target_os = "linux")]"#),
+        other(r#"target_os = "linux""#)
+    );
+    // doc(cfg) from `#![feature(doc_cfg)]`
+    assert_eq!(parse(r#"#[doc(cfg(feature = "std"))]"#), feature("std"));
+    assert_eq!(
+        parse(r#" #[ cfg ( not ( feature = "std" ) ) ] "#),
+        Cfg::Not(Box::new(feature("std")))
+    );
+}
+
+#[test]
+fn parse_nested_predicates() {
+    let cfg = parse(
+        r#"#[cfg(all(any(feature = "a", target_os = "linux"), not(all(unix, feature = "b")),))]"#,
+    );
+    assert_eq!(
+        cfg,
+        Cfg::All(vec![
+            Cfg::Any(vec![feature("a"), other(r#"target_os = "linux""#)]),
+            Cfg::Not(Box::new(Cfg::All(vec![other("unix"), feature("b")]))),
+        ])
+    );
+    assert_eq!(cfg.features(), ["a", "b"]);
+    assert_eq!(
+        cfg.to_string(),
+        r#"(a | target_os = "linux") & !(unix & b)"#
+    );
+    assert_eq!(
+        cfg.describe(),
+        "(crate feature `a` or `target_os = \"linux\"`) and non-`unix` and crate feature `b`"
+    );
+}
+
+#[test]
+fn reject_non_cfg() {
+    for attr in [
+        r#"#[cfg_attr(feature = "std", derive(Debug))]"#,
+        r#"#[doc(cfg_attr(feature = "std", doc = "x"))]"#,
+        "#[derive(Debug)]",
+        "#[doc(hidden)]",
+        r#"#[deprecated = "cfg(unix)"]"#,
+        "#[cfg]",
+        "#[cfg()]",
+        // malformed predicates
+        "#[cfg(not(unix, windows))]",
+        r#"#[cfg(feature = "std)]"#,
+        "#[cfg(all(unix)]",
+        "#[cfg(unix windows)]",
+        "cfg(unix)",
+    ] {
+        assert_eq!(Cfg::parse_attr(attr), None, "{attr}");
+    }
+}
+
+#[test]
+fn enabled_with_features() {
+    let no_features: &[&str] = &[];
+    let std = parse(r#"#[cfg(feature = "std")]"#);
+    assert!(std.is_enabled(&["std"]));
+    assert!(!std.is_enabled(no_features));
+
+    let no_std = parse(r#"#[cfg(not(feature = "std"))]"#);
+    assert!(!no_std.is_enabled(&["std"]));
+    assert!(no_std.is_enabled(no_features));
+    assert!(no_std.is_enabled(&["alloc"]));
+
+    // predicates other than features are considered to be satisfied, even negated
+    assert!(parse("#[cfg(unix)]").is_enabled(no_features));
+    assert!(parse("#[cfg(not(unix))]").is_enabled(no_features));
+
+    let nested = parse(r#"#[cfg(all(feature = "alloc", not(feature = "std")))]"#);
+    assert!(nested.is_enabled(&["alloc"]));
+    assert!(!nested.is_enabled(&["alloc", "std"]));
+    assert!(!nested.is_enabled(no_features));
+
+    let any = parse(r#"#[cfg(any(feature = "a", not(feature = "b")))]"#);
+    assert!(any.is_enabled(no_features));
+    assert!(any.is_enabled(&["a", "b"]));
+    assert!(!any.is_enabled(&["b"]));
+}
@@ -2,7 +2,7 @@
 use super::{DModule, DocTree, Show};
 use crate::type_name::style::{long, long_path};
 use crate::util::{xformat, CompactStringExt, XString};
-use rustdoc_types::{Crate, Id, Item, ItemEnum, ItemKind, ItemSummary};
+use rustdoc_types::{Crate, Id, Item, ItemEnum, ItemKind, ItemSummary, Span};
 use std::{borrow::Borrow, cell::RefCell, collections::HashMap};
 
 /// basic impls for ID
@@ -217,6 +217,11 @@ impl IDMap {
             _ => item.docs.as_deref(),
         })
     }
+
+    /// Source location of an item. Reexported items refer to their source items.
+    pub fn span(&self, id: &str) -> Option<&Span> {
+        self.source_item(id)?.span.as_ref()
+    }
 }
 
 /// Get the shortest item name only based on IndexMap.
@@ -358,3 +363,52 @@ impl IDMap {
             .ok_or_else(|| self.name(id))
     }
 }
+
+/// Items from other crates only have summaries in PathMap, but they can be found
+/// in docs of those crates with the full path.
+impl IDMap {
+    /// Returns the crate name and the summary if the id refers to an item defined in
+    /// another crate, i.e. the item isn't in IndexMap.
+    pub fn external_item(&self, id: &str) -> Option<(&str, &ItemSummary)> {
+        if self.get_item(id).is_some() {
+            return None;
+        }
+        let item = self.get_path(id).filter(|item| item.crate_id != 0)?;
+        let krate = self.krate.external_crates.get(&item.crate_id)?;
+        Some((&krate.name, item))
+    }
+
+    /// Find the id of a local item with the full path and kind from PathMap of another crate.
+    ///
+    /// If the path doesn't exist, e.g. the item is reexported in a different module,
+    /// fall back to the only item with the same name and kind.
+    pub fn find_by_path(&self, path: &[String], kind: &ItemKind) -> Option<ID> {
+        let local = || {
+            self.pathmap()
+                .iter()
+                .filter(|(_, item)| item.crate_id == 0 && item.kind == *kind)
+        };
+        if let Some((id, _)) = local().find(|(_, item)| item.path == path) {
+            return Some(id.to_ID());
+        }
+        let name = path.last()?;
+        let mut same_name = local().filter(|(_, item)| item.path.last() == Some(name));
+        match (same_name.next(), same_name.next()) {
+            (Some((id, _)), None) => Some(id.to_ID()),
+            _ => None,
+        }
+    }
+
+    /// Find the item defined in another crate in docs of other crates, which are
+    /// matched by the crate name, and then the full path and kind of the item.
+    pub fn find_in_others<'a>(&self, id: &str, others: &[&'a IDMap]) -> Option<(&'a IDMap, ID)> {
+        let (krate, item) = self.external_item(id)?;
+        others.iter().find_map(|other| {
+            if other.name(&other.dmodule().id) != krate {
+                return None;
+            }
+            let id = other.find_by_path(&item.path, &item.kind)?;
+            Some((*other, id))
+        })
+    }
+}
@@ -31,7 +31,7 @@ impl Debug for DModule {
         base.field("id", &self.id);
         skip_fmt!(
             base, self . modules structs unions enums
-            functions traits constants statics type_alias
+            functions traits primitives constants statics type_alias
             macros_decl macros_func macros_attr macros_derv
         );
         base.finish()
@@ -85,6 +85,15 @@ impl Debug for DEnum {
     }
 }
 
+impl Debug for DPrimitive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut base = f.debug_struct("DPrimitive");
+        base.field("id", &self.id);
+        skip_fmt!(base, self.impls);
+        base.finish()
+    }
+}
+
 impl Debug for DTrait {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut base = f.debug_struct("DTrait");
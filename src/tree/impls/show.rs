@@ -68,6 +68,17 @@ impl DocTree {
     pub fn push(&mut self, node: Self) {
         self.tree.push(node.tree);
     }
+
+    /// Drop ids of all nodes, which is needed when the tree is generated from the doc
+    /// of another crate, because the ids are meaningless in the current doc.
+    pub fn without_ids(mut self) -> Self {
+        fn strip(tree: &mut Tree<TextTag>) {
+            tree.root.id = None;
+            tree.leaves.iter_mut().for_each(strip);
+        }
+        strip(&mut self.tree);
+        self
+    }
 }
 
 /// Display a node as a tree component in multiple forms.
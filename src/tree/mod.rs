@@ -2,8 +2,12 @@
 mod impls;
 // The inner macro `icon!` can be used afterwards in submods
 
+mod badge;
+mod candidates;
+mod cfg;
 mod id;
 mod nodes;
+mod search;
 mod stats;
 mod tag;
 mod textline;
@@ -11,15 +15,19 @@ mod textline;
 use rustdoc_types::Crate;
 use std::{fmt, ops::Deref, rc::Rc};
 
+pub use badge::Badges;
+pub use candidates::{BoundedParam, Candidate};
+pub use cfg::Cfg;
 pub use id::{IDMap, IDs, IdAsStr, IdToID, IndexMap, PathMap, SliceToIds, ID};
 pub use impls::show::{DocTree, Show};
 pub use nodes::{
     DConstant, DEnum, DFunction, DImpl, DImplInner, DMacroAttr, DMacroDecl, DMacroDerv, DMacroFunc,
-    DModule, DStatic, DStruct, DTrait, DTypeAlias, DUnion, DataItemKind,
+    DModule, DPrimitive, DStatic, DStruct, DTrait, DTypeAlias, DUnion, DataItemKind,
 };
+pub use search::ItemPath;
 pub use stats::{ImplCount, ImplCounts, ImplKind, ItemCount};
 pub use tag::Tag;
-pub use textline::{Text, TextTag, TreeLine, TreeLines};
+pub use textline::{Fold, Text, TextTag, TreeLine, TreeLines};
 
 /// This should be the main data structure to refer to documentation
 /// and the items tree structure in public modules.
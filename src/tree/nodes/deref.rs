@@ -0,0 +1,188 @@
+use super::{last_name, DImpl, DModule, DataItemKind};
+use crate::{
+    tree::{impls::show::show_names, DocTree, IDMap, IdToID, Tag, ID},
+    type_name::style::{short, short_bounds},
+    util::{xformat, XString},
+};
+use rustdoc_types::{GenericBound, GenericParamDefKind, Impl, ItemEnum, Type, WherePredicate};
+
+/// Methods available through `Deref`, like rustdoc's "Methods from Deref<Target = X>".
+impl DModule {
+    /// Impls of a struct/enum/union with methods from chains of `Deref` targets.
+    /// Targets defined in other crates are looked up in the given docs by their paths.
+    pub fn impl_tree_with_deref(
+        &self,
+        id: &str,
+        map: &IDMap,
+        others: &[&IDMap],
+    ) -> Option<DocTree> {
+        let mut tree = self.impl_tree(id, map)?;
+        let sections = self.with_impls(id, map, |impls| {
+            deref_sections(impls, map, others, &[map.path(id)], true)
+        });
+        tree.extend(sections.into_iter().flatten());
+        Some(tree)
+    }
+
+    fn with_impls<T>(&self, id: &str, map: &IDMap, f: impl Copy + Fn(&DImpl) -> T) -> Option<T> {
+        match DataItemKind::new(id, map)? {
+            DataItemKind::Struct => self.search_for_struct(id, |x| f(&x.impls)),
+            DataItemKind::Enum => self.search_for_enum(id, |x| f(&x.impls)),
+            DataItemKind::Union => self.search_for_union(id, |x| f(&x.impls)),
+            DataItemKind::Primitive => self.search_for_primitive(id, |x| f(&x.impls)),
+            _ => None,
+        }
+    }
+}
+
+/// A section for each `Deref` impl. Methods keep their ids only if they're in the
+/// current doc, because ids from other docs are meaningless in the current one.
+///
+/// `visited` holds paths of types in the chain to stop cyclic derefs.
+fn deref_sections(
+    impls: &DImpl,
+    map: &IDMap,
+    others: &[&IDMap],
+    visited: &[XString],
+    with_ids: bool,
+) -> Vec<DocTree> {
+    let mut sections = Vec::new();
+    for inner in &*impls.trait_ {
+        let Some((imp, ty)) = deref_target(&inner.id, map) else {
+            continue;
+        };
+        let text = xformat!("Methods from Deref<Target = {}>", short(ty));
+        let mut section = DocTree::new(text, Tag::DerefMethods, None);
+        if let Type::Generic(name) = ty {
+            // methods are only known from bounds of the generic target
+            section.push(generic_bounds(name, imp));
+            sections.push(section);
+            continue;
+        }
+        let Some((target_map, target_id, local)) = resolve(ty, map, others) else {
+            section.push(DocTree::new(
+                "Target not found in loaded docs".into(),
+                Tag::Unknown,
+                None,
+            ));
+            sections.push(section);
+            continue;
+        };
+        let target_path = target_map.path(&target_id);
+        if visited.contains(&target_path) {
+            continue;
+        }
+        let mut visited = visited.to_vec();
+        visited.push(target_path);
+        let with_ids = with_ids && local;
+        let leaves = target_map
+            .dmodule()
+            .with_impls(&target_id, target_map, |impls| {
+                let functions = &*impls.merged_inherent.functions;
+                let mut leaves: Vec<_> = if with_ids {
+                    show_names(functions, Tag::Function, target_map).collect()
+                } else {
+                    functions
+                        .iter()
+                        .map(|id| DocTree::new(target_map.name(id), Tag::Function, None))
+                        .collect()
+                };
+                leaves.extend(deref_sections(
+                    impls, target_map, others, &visited, with_ids,
+                ));
+                leaves
+            });
+        section.extend(leaves.into_iter().flatten());
+        sections.push(section);
+    }
+    sections
+}
+
+/// The impl of `Deref for Type` and its `Target` type.
+fn deref_target<'a>(impl_id: &str, map: &'a IDMap) -> Option<(&'a Impl, &'a Type)> {
+    let ItemEnum::Impl(imp) = &map.get_item(impl_id)?.inner else {
+        return None;
+    };
+    if imp.trait_.as_ref().map(|path| last_name(&path.name)) != Some("Deref") {
+        return None;
+    }
+    imp.items.iter().find_map(|id| {
+        let item = map.get_item(&id.0)?;
+        match &item.inner {
+            ItemEnum::AssocType {
+                default: Some(ty), ..
+            } if item.name.as_deref() == Some("Target") => Some((imp, ty)),
+            _ => None,
+        }
+    })
+}
+
+/// Bounds on the generic param in the impl, like `where T: ?Sized + Display`.
+fn generic_bounds(name: &str, imp: &Impl) -> DocTree {
+    let generics = &imp.generics;
+    let in_params = generics
+        .params
+        .iter()
+        .filter(|param| param.name == name)
+        .filter_map(|param| match &param.kind {
+            GenericParamDefKind::Type { bounds, .. } => Some(bounds),
+            _ => None,
+        });
+    let in_where = generics
+        .where_predicates
+        .iter()
+        .filter_map(|pred| match pred {
+            WherePredicate::BoundPredicate {
+                type_: Type::Generic(generic),
+                bounds,
+                ..
+            } if generic == name => Some(bounds),
+            _ => None,
+        });
+    let bounds: Vec<GenericBound> = in_params.chain(in_where).flatten().cloned().collect();
+    if bounds.is_empty() {
+        let text = xformat!("{name} is generic without bounds");
+        return DocTree::new(text, Tag::Unknown, None);
+    }
+    let text = xformat!("where {name}: {}", short_bounds(&bounds));
+    DocTree::new(text, Tag::Bound, None)
+}
+
+/// The doc and id of the target type, and whether it's in the current doc.
+///
+/// Primitive types like `str` and `[T]` are looked up in docs of core/alloc/std.
+fn resolve<'a>(ty: &Type, map: &'a IDMap, others: &[&'a IDMap]) -> Option<(&'a IDMap, ID, bool)> {
+    let primitive = match ty {
+        Type::Primitive(name) => Some(name.as_str()),
+        Type::Slice(_) => Some("slice"),
+        Type::Array { .. } => Some("array"),
+        Type::Tuple(_) => Some("tuple"),
+        _ => None,
+    };
+    if let Some(name) = primitive {
+        if let Some(id) = find_primitive(name, map.dmodule(), map) {
+            return Some((map, id, true));
+        }
+        return others.iter().find_map(|other| {
+            let id = find_primitive(name, other.dmodule(), other)?;
+            Some((*other, id, false))
+        });
+    }
+    let Type::ResolvedPath(path) = ty else {
+        return None;
+    };
+    if map.get_item(&path.id.0).is_some() {
+        return Some((map, path.id.to_ID(), true));
+    }
+    let (other, id) = map.find_in_others(&path.id.0, others)?;
+    Some((other, id, false))
+}
+
+fn find_primitive(name: &str, dmod: &DModule, map: &IDMap) -> Option<ID> {
+    let found = dmod.primitives.iter().find(|p| map.name(&p.id) == name);
+    found.map(|p| p.id.clone()).or_else(|| {
+        dmod.modules
+            .iter()
+            .find_map(|m| find_primitive(name, m, map))
+    })
+}
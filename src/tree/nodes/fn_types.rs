@@ -0,0 +1,242 @@
+use super::{DModule, DataItemKind};
+use crate::{
+    tree::{DocTree, IDMap, IdToID, Tag, ID},
+    type_name::style::{short, short_path},
+    util::{xformat, XString},
+};
+use rustdoc_types::{
+    FnDecl, Function, GenericArg, GenericArgs, GenericBound, GenericParamDefKind, Generics,
+    ItemEnum, Path, Term, TraitBoundModifier, Type, TypeBindingKind, WherePredicate,
+};
+
+/// Argument and return types of a function expanded into their definitions.
+impl DModule {
+    pub fn args_tree(&self, id: &str, map: &IDMap) -> Option<DocTree> {
+        let func = get_fn(id, map)?;
+        let mut root = node!(Function: map, id);
+        root.extend(self.args(func, map));
+        Some(root)
+    }
+
+    pub fn return_tree(&self, id: &str, map: &IDMap) -> Option<DocTree> {
+        let func = get_fn(id, map)?;
+        let mut root = node!(Function: map, id);
+        root.push(self.output(func, map));
+        Some(root)
+    }
+
+    /// Both arguments and return type under their headings.
+    pub(super) fn fn_types_tree(&self, id: &str, map: &IDMap) -> Option<DocTree> {
+        let func = get_fn(id, map)?;
+        let root = node!(Function: map, id);
+        Some(root.with_leaves([
+            Tag::Args.show().with_leaves(self.args(func, map)),
+            Tag::Returns.show().with_leaves([self.output(func, map)]),
+        ]))
+    }
+
+    /// Non-receiver arguments. The receiver is `Self` and already known.
+    fn args(&self, func: &Function, map: &IDMap) -> Vec<DocTree> {
+        let args: Vec<_> = func
+            .decl
+            .inputs
+            .iter()
+            .filter(|(name, _)| name != "self")
+            .map(|(name, ty)| {
+                let text = xformat!("{name}: {}", short(ty));
+                self.type_node(text, Tag::Arg, ty, &func.generics, map)
+            })
+            .collect();
+        if args.is_empty() {
+            vec![Tag::NoArgs.show()]
+        } else {
+            args
+        }
+    }
+
+    fn output(&self, func: &Function, map: &IDMap) -> DocTree {
+        match &func.decl.output {
+            Some(ty) => {
+                let text = short(ty).into();
+                self.type_node(text, Tag::Return, ty, &func.generics, map)
+            }
+            None => Tag::NoReturn.show(),
+        }
+    }
+
+    /// A type with local items and generic bounds mentioned in it as leaves.
+    fn type_node(
+        &self,
+        text: XString,
+        tag: Tag,
+        ty: &Type,
+        generics: &Generics,
+        map: &IDMap,
+    ) -> DocTree {
+        let mut mentioned = Mentioned::default();
+        mentioned.ty(ty);
+        let mut node = DocTree::new(text, tag, None);
+        node.extend(self.item_trees(&mentioned.ids, map));
+        for name in &mentioned.generics {
+            let mut in_bounds = Mentioned::default();
+            let bounds = param_bounds(name, generics);
+            for bound in &bounds {
+                in_bounds.path(bound);
+            }
+            let text = if bounds.is_empty() {
+                name.clone()
+            } else {
+                let bounds: Vec<_> = bounds.iter().map(|path| short_path(path)).collect();
+                xformat!("{name}: {}", bounds.join(" + "))
+            };
+            let leaves = self.item_trees(&in_bounds.ids, map);
+            node.push(DocTree::new(text, Tag::Bound, None).with_leaves(leaves));
+        }
+        node
+    }
+
+    /// Fields/variants/impls of local data items, and associated items of local traits.
+    fn item_trees<'a>(
+        &'a self,
+        ids: &'a [ID],
+        map: &'a IDMap,
+    ) -> impl 'a + Iterator<Item = DocTree> {
+        ids.iter()
+            .filter(|id| map.external_item(id).is_none())
+            .filter_map(|id| match DataItemKind::new(id, map)? {
+                DataItemKind::Struct
+                | DataItemKind::Enum
+                | DataItemKind::Union
+                | DataItemKind::Trait => self.item_inner_tree(id, map),
+                _ => None,
+            })
+    }
+}
+
+fn get_fn<'a>(id: &str, map: &'a IDMap) -> Option<&'a Function> {
+    match &map.get_item(id)?.inner {
+        ItemEnum::Function(func) => Some(func),
+        _ => None,
+    }
+}
+
+/// Trait bounds on the generic parameter, including bounds in where clauses.
+/// `?Sized` is not a trait bound here.
+fn param_bounds<'a>(name: &str, generics: &'a Generics) -> Vec<&'a Path> {
+    let in_params = generics
+        .params
+        .iter()
+        .filter(|param| param.name == name)
+        .filter_map(|param| match &param.kind {
+            GenericParamDefKind::Type { bounds, .. } => Some(bounds),
+            _ => None,
+        });
+    let in_where = generics
+        .where_predicates
+        .iter()
+        .filter_map(|pred| match pred {
+            WherePredicate::BoundPredicate {
+                type_: Type::Generic(ty),
+                bounds,
+                ..
+            } if ty == name => Some(bounds),
+            _ => None,
+        });
+    let mut paths = Vec::new();
+    for bound in in_params.chain(in_where).flatten() {
+        if let GenericBound::TraitBound {
+            trait_, modifier, ..
+        } = bound
+        {
+            if !matches!(modifier, TraitBoundModifier::Maybe) && !paths.contains(&trait_) {
+                paths.push(trait_);
+            }
+        }
+    }
+    paths
+}
+
+/// Item ids and generic parameters mentioned in a type in order of appearance.
+#[derive(Default)]
+struct Mentioned {
+    ids: Vec<ID>,
+    generics: Vec<XString>,
+}
+
+impl Mentioned {
+    fn ty(&mut self, ty: &Type) {
+        match ty {
+            Type::ResolvedPath(path) => self.path(path),
+            Type::DynTrait(dyn_) => dyn_.traits.iter().for_each(|t| self.path(&t.trait_)),
+            Type::Generic(name) if name != "Self" && !self.generics.iter().any(|g| g == name) => {
+                self.generics.push(name.as_str().into());
+            }
+            Type::ImplTrait(bounds) => {
+                for bound in bounds {
+                    if let GenericBound::TraitBound { trait_, .. } = bound {
+                        self.path(trait_);
+                    }
+                }
+            }
+            Type::FunctionPointer(f) => self.decl(&f.decl),
+            Type::Tuple(tys) => tys.iter().for_each(|ty| self.ty(ty)),
+            Type::Slice(ty)
+            | Type::Array { type_: ty, .. }
+            | Type::RawPointer { type_: ty, .. }
+            | Type::BorrowedRef { type_: ty, .. } => self.ty(ty),
+            Type::QualifiedPath {
+                self_type, trait_, ..
+            } => {
+                self.ty(self_type);
+                if let Some(trait_) = trait_ {
+                    self.path(trait_);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn path(&mut self, path: &Path) {
+        let id = path.id.to_ID();
+        if !self.ids.contains(&id) {
+            self.ids.push(id);
+        }
+        match path.args.as_deref() {
+            Some(GenericArgs::AngleBracketed { args, bindings }) => {
+                for arg in args {
+                    if let GenericArg::Type(ty) = arg {
+                        self.ty(ty);
+                    }
+                }
+                // e.g. `Iterator<Item = T>` or `Iterator<Item: Trait>`
+                for binding in bindings {
+                    match &binding.binding {
+                        TypeBindingKind::Equality(Term::Type(ty)) => self.ty(ty),
+                        TypeBindingKind::Constraint(bounds) => {
+                            for bound in bounds {
+                                if let GenericBound::TraitBound { trait_, .. } = bound {
+                                    self.path(trait_);
+                                }
+                            }
+                        }
+                        TypeBindingKind::Equality(Term::Constant(_)) => (),
+                    }
+                }
+            }
+            Some(GenericArgs::Parenthesized { inputs, output }) => {
+                inputs.iter().for_each(|ty| self.ty(ty));
+                if let Some(ty) = output {
+                    self.ty(ty);
+                }
+            }
+            None => (),
+        }
+    }
+
+    fn decl(&mut self, decl: &FnDecl) {
+        decl.inputs.iter().for_each(|(_, ty)| self.ty(ty));
+        if let Some(ty) = &decl.output {
+            self.ty(ty);
+        }
+    }
+}
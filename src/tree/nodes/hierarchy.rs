@@ -0,0 +1,186 @@
+use super::{DModule, DTrait};
+use crate::{
+    tree::{DocTree, IDMap, Tag, ID},
+    type_name::style::{short, short_bounds, short_generic, short_path},
+    util::xformat,
+};
+use rustdoc_types::{GenericBound, Id, ItemEnum, Path, Trait, Type, WherePredicate};
+
+impl DModule {
+    pub fn trait_hierarchy_tree(&self, id: &str, map: &IDMap) -> Option<DocTree> {
+        self.search_for_trait(id, |x| x.hierarchy(map))
+    }
+}
+
+impl DTrait {
+    /// Generic parameters, supertraits upward, subtraits downward within the crate,
+    /// bounds on associated types, and required methods apart from provided ones.
+    pub fn hierarchy(&self, map: &IDMap) -> DocTree {
+        let mut root = node!(Trait: map, &self.id);
+        let Some(ItemEnum::Trait(trait_)) = map.get_item(&self.id).map(|item| &item.inner) else {
+            return root;
+        };
+
+        let params: Vec<_> = trait_
+            .generics
+            .params
+            .iter()
+            .map(|param| DocTree::new(short_generic(param).into(), Tag::Bound, None))
+            .collect();
+        if !params.is_empty() {
+            root.push(Tag::GenericParams.show().with_leaves(params));
+        }
+
+        let mut ancestors = vec![self.id.as_str()];
+        let supers: Vec<_> = supertraits(trait_)
+            .into_iter()
+            .map(|path| super_node(path, map, &mut ancestors))
+            .collect();
+        if !supers.is_empty() {
+            root.push(Tag::Supertraits.show().with_leaves(supers));
+        }
+
+        let all = local_traits(map);
+        let mut descendants = vec![self.id.as_str()];
+        let subs = sub_nodes(&self.id, &all, map, &mut descendants);
+        if !subs.is_empty() {
+            root.push(Tag::Subtraits.show().with_leaves(subs));
+        }
+
+        let types: Vec<_> = self
+            .types
+            .iter()
+            .filter_map(|id| {
+                let item = map.get_item(id)?;
+                let ItemEnum::AssocType {
+                    bounds, default, ..
+                } = &item.inner
+                else {
+                    return None;
+                };
+                let mut text = xformat!("type {}", map.name(id));
+                if !bounds.is_empty() {
+                    text.push_str(": ");
+                    text.push_str(&short_bounds(bounds));
+                }
+                if let Some(ty) = default {
+                    text.push_str(" = ");
+                    text.push_str(&short(ty));
+                }
+                Some(DocTree::new(text, Tag::AssocType, Some(id.as_str().into())))
+            })
+            .collect();
+        if !types.is_empty() {
+            root.push(Tag::AssocTypes.show().with_leaves(types));
+        }
+
+        let (provided, required): (Vec<_>, Vec<_>) = self.functions.iter().partition(|id| {
+            matches!(
+                map.get_item(id).map(|item| &item.inner),
+                Some(ItemEnum::Function(f)) if f.has_body
+            )
+        });
+        let show = |ids: &[&ID]| -> Vec<_> {
+            ids.iter()
+                .map(|id| node!(@name AssocFn: map, *id))
+                .collect()
+        };
+        if !required.is_empty() {
+            root.push(Tag::RequiredMethods.show().with_leaves(show(&required)));
+        }
+        if !provided.is_empty() {
+            root.push(Tag::ProvidedMethods.show().with_leaves(show(&provided)));
+        }
+        root
+    }
+}
+
+/// Trait bounds on the trait, including `where Self: Trait`.
+fn supertraits(trait_: &Trait) -> Vec<&Path> {
+    let in_where = trait_
+        .generics
+        .where_predicates
+        .iter()
+        .filter_map(|pred| match pred {
+            WherePredicate::BoundPredicate {
+                type_: Type::Generic(ty),
+                bounds,
+                ..
+            } if ty == "Self" => Some(bounds),
+            _ => None,
+        });
+    let mut paths = Vec::new();
+    for bound in [&trait_.bounds].into_iter().chain(in_where).flatten() {
+        if let GenericBound::TraitBound { trait_, .. } = bound {
+            if !paths.contains(&trait_) {
+                paths.push(trait_);
+            }
+        }
+    }
+    paths
+}
+
+/// A supertrait with its own supertraits if it's defined in this crate.
+fn super_node<'a>(path: &'a Path, map: &'a IDMap, ancestors: &mut Vec<&'a str>) -> DocTree {
+    let id = path.id.0.as_str();
+    let local = match map.get_item(id).map(|item| &item.inner) {
+        Some(ItemEnum::Trait(trait_)) => Some(trait_),
+        _ => None,
+    };
+    let mut node = DocTree::new(
+        short_path(path).into(),
+        Tag::Trait,
+        local.map(|_| id.into()),
+    );
+    // a cycle is not possible in valid code, but a trait can be reached through multiple paths
+    if let Some(trait_) = local.filter(|_| !ancestors.contains(&id)) {
+        ancestors.push(id);
+        node.extend(
+            supertraits(trait_)
+                .into_iter()
+                .map(|p| super_node(p, map, ancestors)),
+        );
+        ancestors.pop();
+    }
+    node
+}
+
+/// Traits in this crate with ids of their supertraits.
+fn local_traits(map: &IDMap) -> Vec<(&Id, Vec<&str>)> {
+    map.indexmap()
+        .iter()
+        .filter(|(_, item)| item.crate_id == 0)
+        .filter_map(|(id, item)| match &item.inner {
+            ItemEnum::Trait(trait_) => {
+                let supers = supertraits(trait_).into_iter().map(|p| p.id.0.as_str());
+                Some((id, supers.collect()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn sub_nodes<'a>(
+    id: &str,
+    all: &'a [(&'a Id, Vec<&'a str>)],
+    map: &IDMap,
+    descendants: &mut Vec<&'a str>,
+) -> Vec<DocTree> {
+    let mut subs: Vec<_> = all
+        .iter()
+        .filter(|(_, supers)| supers.contains(&id))
+        .map(|(sub, _)| sub.0.as_str())
+        .collect();
+    subs.sort_unstable_by_key(|id| map.name(*id));
+    subs.into_iter()
+        .map(|sub| {
+            let mut node = node!(@name Trait: map, sub);
+            if !descendants.contains(&sub) {
+                descendants.push(sub);
+                node.extend(sub_nodes(sub, all, map, descendants));
+                descendants.pop();
+            }
+            node
+        })
+        .collect()
+}
@@ -31,7 +31,11 @@ impl DImpl {
             if id.starts_with("a:") {
                 auto.push(DImplInner::new_with_no_details(id));
             } else if id.starts_with("b:") {
-                blanket.push(DImplInner::new_with_no_details(id));
+                // items from a local trait are resolvable
+                blanket.push(match map.get_item(id).map(|item| &item.inner) {
+                    Some(ItemEnum::Impl(impl_)) => DImplInner::new(id, impl_, map),
+                    _ => DImplInner::new_with_no_details(id),
+                });
             } else if let Some(item) = map.get_item(id) {
                 if let ItemEnum::Impl(impl_) = &item.inner {
                     if impl_.trait_.is_none() {
@@ -103,13 +107,11 @@ impl Show for DImpl {
         }
         if !self.auto.is_empty() {
             let tree = Tag::AutoImpls.show();
-            let tag = Tag::ImplAuto;
-            root.push(tree.with_leaves(self.auto.iter().map(|i| i.show_prettier(tag, map))));
+            root.push(tree.with_leaves(self.auto.iter().map(|i| i.show_auto(map))));
         }
         if !self.blanket.is_empty() {
             let tree = Tag::BlanketImpls.show();
-            let tag = Tag::ImplBlanket;
-            root.push(tree.with_leaves(self.blanket.iter().map(|i| i.show_prettier(tag, map))));
+            root.push(tree.with_leaves(self.blanket.iter().map(|i| i.show_blanket(map))));
         }
         root
     }
@@ -179,7 +181,7 @@ impl DImplInner {
     }
 
     /// mainly for inherent impls
-    fn show_prettier_iter<'s: 'ret, 'map: 'ret, 'ret>(
+    pub(super) fn show_prettier_iter<'s: 'ret, 'map: 'ret, 'ret>(
         &'s self,
         map: &'map IDMap,
     ) -> impl 'ret + Iterator<Item = DocTree> {
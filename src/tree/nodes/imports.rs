@@ -13,8 +13,100 @@
 // }
 
 use super::*;
-use crate::tree::ID;
-use rustdoc_types::{Import, ItemEnum, ItemKind};
+use crate::{tree::ID, util::XString};
+use rustc_hash::FxHashSet as HashSet;
+use rustdoc_types::{Import, Item, ItemEnum, ItemKind, Visibility};
+
+/// Rust puts item names in three namespaces, and a name can be shadowed only by an item
+/// in the same namespace.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Namespace {
+    Type,
+    Value,
+    Macro,
+}
+
+impl Namespace {
+    fn new(inner: &ItemEnum, map: &IDMap) -> Option<Self> {
+        use ItemEnum::*;
+        Some(match inner {
+            Module(_) | Struct(_) | Union(_) | Enum(_) | Trait(_) | TraitAlias(_)
+            | TypeAlias(_) | Primitive(_) => Namespace::Type,
+            Function(_) | Constant(_) | Static(_) => Namespace::Value,
+            Macro(_) | ProcMacro(_) => Namespace::Macro,
+            Import(import) => {
+                let Some(id) = &import.id else {
+                    // only primitive types have no id
+                    return Some(Namespace::Type);
+                };
+                match map.indexmap().get(id) {
+                    Some(source) => Namespace::new(&source.inner, map)?,
+                    None => Namespace::from_kind(&map.pathmap().get(id)?.kind)?,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    fn from_kind(kind: &ItemKind) -> Option<Self> {
+        use ItemKind::*;
+        Some(match kind {
+            Module | Struct | Union | Enum | Trait | TraitAlias | TypeAlias | Primitive => {
+                Namespace::Type
+            }
+            Function | Constant | Static => Namespace::Value,
+            Macro | ProcAttribute | ProcDerive => Namespace::Macro,
+            _ => return None,
+        })
+    }
+}
+
+/// Names of items that have been added to a module.
+#[derive(Default)]
+pub(super) struct Names {
+    names: HashSet<(Namespace, XString)>,
+}
+
+impl Names {
+    /// Returns false if the name has been taken in the namespace.
+    fn insert(&mut self, item: &Item, map: &IDMap) -> bool {
+        let name = match &item.inner {
+            ItemEnum::Import(import) => Some(import.name.as_str()),
+            _ => item.name.as_deref(),
+        };
+        match (Namespace::new(&item.inner, map), name) {
+            (Some(ns), Some(name)) => self.names.insert((ns, name.into())),
+            _ => true,
+        }
+    }
+}
+
+/// Add items to DModule.
+///
+/// Items defined or explicitly imported shadow the ones with the same name from glob imports,
+/// thus glob imports are expanded after other items, and an item from a glob import is
+/// skipped if its name has been taken.
+pub(super) fn extract_items<'a>(
+    items: impl Iterator<Item = &'a Item>,
+    map: &IDMap,
+    dmod: &mut DModule,
+    kin: &mut Vec<ID>,
+    names: &mut Names,
+) {
+    let mut globs = Vec::new();
+    for item in items {
+        match &item.inner {
+            ItemEnum::Import(import) if import.glob => globs.push((item, import)),
+            _ => {
+                names.insert(item, map);
+                dmod.append(item, map, kin);
+            }
+        }
+    }
+    for (item, import) in globs {
+        parse_glob_import(item.id.to_ID(), import, map, dmod, kin, names);
+    }
+}
 
 /// Add the item of `pub use source {as name}` to DModule.
 ///
@@ -77,11 +169,11 @@ pub(super) fn parse_import(
         }
     } else if let Some(extern_item) = map.pathmap().get(import_id) {
         let id = import_id.to_ID();
-        // TODO: external items are in path map, which means no further information
+        // External items are in path map, which means no further information
         // except full path and item kind will be known.
-        // To get details of an external item, we need to compile the external crate,
-        // and search it with the full path and kind.
-        // A simple example of this is `nucleo` crate.
+        // Details of an external item live in the doc of the external crate, and it's
+        // searched with the full path and kind via `IDMap::find_by_path` when it's
+        // followed and the doc is cached. A simple example of this is `nucleo` crate.
         match extern_item.kind {
             ItemKind::Module if !kin.contains(&id) => {
                 // We don't know items inside external modules.
@@ -107,3 +199,60 @@ pub(super) fn parse_import(
         }
     }
 }
+
+/// Add public items in the source module of `pub use source::*` to DModule,
+/// except the ones shadowed by items with the same names.
+///
+/// Globs like `pub use self::prelude::*` may point to an ancestor module, so the source
+/// module is checked against the ancestors to stop the recursion.
+fn parse_glob_import(
+    id: ID,
+    import: &Import,
+    map: &IDMap,
+    dmod: &mut DModule,
+    kin: &mut Vec<ID>,
+    names: &mut Names,
+) {
+    let Some(source) = import.id.as_ref().and_then(|id| map.indexmap().get(id)) else {
+        // e.g. glob imports from external modules
+        parse_import(id, import, map, dmod, kin);
+        return;
+    };
+    let ItemEnum::Module(module) = &source.inner else {
+        // e.g. `pub use Enum::*` brings variants which are not module items
+        debug!(
+            "Skip the glob import from `{}` which is not a module.",
+            map.path(&source.id.0)
+        );
+        return;
+    };
+    let source_id = source.id.to_ID();
+    if kin.contains(&source_id) {
+        warn!(
+            "Stop at the glob import from `{}` that duplicates as an ancestor module.",
+            map.path(&source_id)
+        );
+        return;
+    }
+    debug!("Expand the glob import from `{}`.", map.path(&source_id));
+    let mut kin = kin.clone();
+    kin.push(source_id);
+    let mut globs = Vec::new();
+    for item_id in &module.items {
+        match map.indexmap().get(item_id) {
+            Some(item) if matches!(item.visibility, Visibility::Public) => match &item.inner {
+                ItemEnum::Import(import) if import.glob => globs.push((item, import)),
+                _ if names.insert(item, map) => dmod.append(item, map, &mut kin),
+                _ => debug!(
+                    "Skip `{}` from the glob import, which is shadowed by the same name.",
+                    map.path(&item_id.0)
+                ),
+            },
+            Some(_) => (),
+            None => warn!("the local item {item_id:?} not found in Crate's index"),
+        }
+    }
+    for (item, import) in globs {
+        parse_glob_import(item.id.to_ID(), import, map, dmod, &mut kin, names);
+    }
+}
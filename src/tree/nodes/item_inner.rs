@@ -1,6 +1,6 @@
-use super::{DEnum, DModule, DStruct, DTrait, DUnion};
+use super::{DEnum, DModule, DPrimitive, DStruct, DTrait, DUnion};
 use crate::tree::{DocTree, IDMap, Show};
-use rustdoc_types::ItemEnum;
+use rustdoc_types::{ItemEnum, ItemKind};
 
 /// Data-carrying items that provide extra tree layer on fields/variants/impls.
 #[derive(Debug, Clone, Copy)]
@@ -10,10 +10,24 @@ pub enum DataItemKind {
     Enum,
     Trait,
     Union,
+    Function,
+    Primitive,
 }
 
 impl DataItemKind {
+    /// Items from other crates are known by the kinds in PathMap.
     pub fn new(id: &str, map: &IDMap) -> Option<DataItemKind> {
+        if let Some((_, item)) = map.external_item(id) {
+            return Some(match item.kind {
+                ItemKind::Struct => DataItemKind::Struct,
+                ItemKind::Enum => DataItemKind::Enum,
+                ItemKind::Trait => DataItemKind::Trait,
+                ItemKind::Union => DataItemKind::Union,
+                ItemKind::Function => DataItemKind::Function,
+                ItemKind::Primitive => DataItemKind::Primitive,
+                _ => return None,
+            });
+        }
         map.get_item(id).and_then(|item| {
             Some(match &item.inner {
                 ItemEnum::Module(_) => DataItemKind::Module,
@@ -21,6 +35,8 @@ impl DataItemKind {
                 ItemEnum::Enum(_) => DataItemKind::Enum,
                 ItemEnum::Trait(_) => DataItemKind::Trait,
                 ItemEnum::Union(_) => DataItemKind::Union,
+                ItemEnum::Function(_) => DataItemKind::Function,
+                ItemEnum::Primitive(_) => DataItemKind::Primitive,
                 ItemEnum::Import(reexport) => {
                     let id = reexport.id.as_ref().map(|id| &*id.0)?;
                     DataItemKind::new(id, map)?
@@ -38,10 +54,11 @@ macro_rules! search {
             search_for_enum   enums   DEnum,
             search_for_trait  traits  DTrait,
             search_for_union  unions  DUnion,
+            search_for_primitive primitives DPrimitive,
         }
     };
     ($fname:ident $field:ident $typ:ident) => {
-        fn $fname<T>(
+        pub(super) fn $fname<T>(
             &self,
             id: &str,
             f: impl Copy + Fn(&$typ) -> T,
@@ -90,6 +107,8 @@ impl DModule {
             DataItemKind::Trait => self.search_for_trait(id, |x| x.show_prettier(map)),
             DataItemKind::Union => self.search_for_union(id, |x| x.show_prettier(map)),
             DataItemKind::Module => self.search_for_module(id, |x| x.item_tree(map)),
+            DataItemKind::Function => self.fn_types_tree(id, map),
+            DataItemKind::Primitive => self.search_for_primitive(id, |x| x.show_prettier(map)),
         }
     }
 
@@ -100,6 +119,9 @@ impl DModule {
             DataItemKind::Enum => self.search_for_enum(id, |x| x.impls.show_prettier(map)),
             DataItemKind::Trait => self.search_for_trait(id, |x| x.show_prettier(map)),
             DataItemKind::Union => self.search_for_union(id, |x| x.impls.show_prettier(map)),
+            DataItemKind::Primitive => {
+                self.search_for_primitive(id, |x| x.impls.show_prettier(map))
+            }
             _ => None,
         }
     }
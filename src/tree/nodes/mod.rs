@@ -13,9 +13,17 @@ pub use enums::DEnum;
 mod traits;
 pub use traits::DTrait;
 
+mod primitives;
+pub use primitives::DPrimitive;
+
 mod imports;
 
+mod deref;
+mod fn_types;
+mod hierarchy;
 mod item_inner;
+mod synthetic;
+mod usage;
 pub use item_inner::DataItemKind;
 
 use super::IDMap;
@@ -27,6 +35,11 @@ use rustdoc_types::{Id, Item, ItemEnum, MacroKind, Module};
 use serde::{Deserialize, Serialize};
 use std::ops::Not;
 
+/// The last segment of a path like `std::fmt::Display`.
+fn last_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
 /// Module tree with structural items.
 /// All the items only carry ids without actual data.
 // NOTE: small improvement by turning all the types of fields
@@ -42,6 +55,7 @@ pub struct DModule {
     pub unions: Vec<DUnion>,
     pub enums: Vec<DEnum>,
     pub traits: Vec<DTrait>,
+    pub primitives: Vec<DPrimitive>,
     pub functions: Vec<DFunction>,
     pub constants: Vec<DConstant>,
     pub statics: Vec<DStatic>,
@@ -104,12 +118,14 @@ impl DModule {
     }
 
     fn extract_items(&mut self, inner_items: &[Id], map: &IDMap, ancestor: &mut Vec<ID>) {
-        for item_id in inner_items {
-            match map.indexmap().get(item_id) {
-                Some(item) => self.append(item, map, ancestor),
-                None => warn!("the local item {item_id:?} not found in Crate's index"),
+        let items = inner_items.iter().filter_map(|item_id| {
+            let item = map.indexmap().get(item_id);
+            if item.is_none() {
+                warn!("the local item {item_id:?} not found in Crate's index");
             }
-        }
+            item
+        });
+        imports::extract_items(items, map, self, ancestor, &mut Default::default());
     }
 
     fn append(&mut self, item: &Item, map: &IDMap, ancestor: &mut Vec<ID>) {
@@ -136,7 +152,7 @@ impl DModule {
                 MacroKind::Derive => self.macros_derv.push(DMacroDerv::new(id)),
             },
             Import(import) => imports::parse_import(id, import, map, self, ancestor),
-            // Primitive(_) => todo!(),
+            Primitive(item) => self.primitives.push(DPrimitive::new(id, item, map)),
             _ => (),
         }
     }
@@ -188,6 +204,21 @@ impl DModule {
         $(self.$field.sort_unstable_by(|a, b| map.name(&a.id).cmp(&map.name(&b.id)));)+
     }
 
+    /// IDs of the module that directly contains the item (which can be reexported) and its
+    /// ancestor modules, from inner to outer. Returns None if no module contains the item.
+    pub fn ancestors(&self, id: &str, map: &IDMap) -> Option<Vec<ID>> {
+        let contains = self.modules.iter().any(|m| map.is_same_id(&m.id, id))
+            $(|| self.$field.iter().any(|item| map.is_same_id(&item.id, id)))+;
+        if contains {
+            return Some(vec![self.id.clone()]);
+        }
+        self.modules.iter().find_map(|m| {
+            let mut ancestors = m.ancestors(id, map)?;
+            ancestors.push(self.id.clone());
+            Some(ancestors)
+        })
+    }
+
     /// NOTE: this method doesn't include nested modules; only returns one-level items with mod root.
     pub fn item_tree_only_in_one_specified_mod(&self, map: &IDMap) -> DocTree {
         node!(Module: map, &self.id).with_leaves(
@@ -226,6 +257,7 @@ impl_show! {
     structs     => Struct    => Structs    => DStruct,
     unions      => Union     => Unions     => DUnion,
     enums       => Enum      => Enums      => DEnum,
+    primitives  => Primitive => Primitives => DPrimitive,
 }
 
 /// generate id wrapper types for simple items
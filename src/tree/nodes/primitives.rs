@@ -0,0 +1,33 @@
+use crate::tree::{
+    impls::show::{DocTree, Show},
+    DImpl, IDMap, ID,
+};
+use rustdoc_types::Primitive;
+
+/// Primitive types like `str` and `i32` that only exist in std docs with their impls.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct DPrimitive {
+    pub id: ID,
+    pub impls: DImpl,
+}
+
+impl DPrimitive {
+    pub fn new(id: ID, item: &Primitive, map: &IDMap) -> Self {
+        DPrimitive {
+            id,
+            impls: DImpl::new(&item.impls, map),
+        }
+    }
+}
+
+impl Show for DPrimitive {
+    fn show(&self) -> DocTree {
+        format!("[primitive] {}", self.id)
+            .show()
+            .with_leaves([self.impls.show()])
+    }
+
+    fn show_prettier(&self, map: &IDMap) -> DocTree {
+        node!(Primitive: map, &self.id).with_leaves([self.impls.show_prettier(map)])
+    }
+}
@@ -0,0 +1,233 @@
+use super::{last_name, DImplInner};
+use crate::{
+    tree::{DocTree, IDMap, IdToID, Tag, ID},
+    type_name::style::{short, short_bounds},
+    util::xformat,
+};
+use rustdoc_types::{
+    GenericArg, GenericArgs, GenericBound, GenericParamDefKind, Impl, ItemEnum, StructKind,
+    TraitBoundModifier, Type, VariantKind, WherePredicate,
+};
+
+/// Details of blanket and auto trait impls which are generated by rustdoc.
+impl DImplInner {
+    /// Bounds that make the blanket impl apply, and associated items from the trait.
+    pub(super) fn show_blanket(&self, map: &IDMap) -> DocTree {
+        let root = DocTree::new(
+            map.name(&self.id),
+            Tag::ImplBlanket,
+            Some(self.id.as_str().into()),
+        );
+        let Some(imp) = get_impl(&self.id, map) else {
+            return root;
+        };
+        let bounds = where_bounds(imp).into_iter();
+        // Items from a trait in other crates are not in the index, but names of
+        // provided methods are still known.
+        let local: Vec<_> = self.functions.iter().map(|id| map.name(id)).collect();
+        let provided = imp
+            .provided_trait_methods
+            .iter()
+            .filter(|name| !local.iter().any(|local| local.as_str() == name.as_str()))
+            .map(|name| DocTree::new(name.as_str().into(), Tag::Function, None));
+        root.with_leaves(bounds.chain(self.show_prettier_iter(map)).chain(provided))
+    }
+
+    /// Why an auto trait is implemented or not: where clauses for a positive impl,
+    /// or the fields that likely opt out for a negative one.
+    pub(super) fn show_auto(&self, map: &IDMap) -> DocTree {
+        let Some(imp) = get_impl(&self.id, map) else {
+            return DocTree::new(
+                map.name(&self.id),
+                Tag::ImplAuto,
+                Some(self.id.as_str().into()),
+            );
+        };
+        let trait_ = imp.trait_.as_ref().map_or("", |path| last_name(&path.name));
+        let (name, leaves) = if imp.negative {
+            let fields = fields(&imp.for_, map);
+            let blocking: Vec<_> = fields
+                .iter()
+                .filter(|(_, ty)| opts_out(trait_, ty))
+                .collect();
+            let (reason, fields) = if blocking.is_empty() {
+                (
+                    "no field obviously opts out; check fields below",
+                    fields.iter().collect(),
+                )
+            } else {
+                ("opted out by fields", blocking)
+            };
+            let fields = fields.into_iter().map(|(id, ty)| {
+                let text = xformat!("{}: {}", map.name(id), short(ty));
+                DocTree::new(text, Tag::Field, Some(id.as_str().into()))
+            });
+            let reason = DocTree::new(reason.into(), Tag::Bound, None).with_leaves(fields);
+            (xformat!("!{trait_}"), vec![reason])
+        } else {
+            let bounds = where_bounds(imp);
+            let leaves = if bounds.is_empty() {
+                let text = xformat!("all fields are {trait_}");
+                vec![DocTree::new(text, Tag::Bound, None)]
+            } else {
+                bounds
+            };
+            (trait_.into(), leaves)
+        };
+        DocTree::new(name, Tag::ImplAuto, Some(self.id.as_str().into())).with_leaves(leaves)
+    }
+}
+
+fn get_impl<'a>(id: &str, map: &'a IDMap) -> Option<&'a Impl> {
+    match &map.get_item(id)?.inner {
+        ItemEnum::Impl(imp) => Some(imp),
+        _ => None,
+    }
+}
+
+/// Bounds on generic params of the impl with generic args kept, like `where U: From<T>`.
+/// `?Sized` is skipped since it doesn't restrict the impl.
+fn where_bounds(imp: &Impl) -> Vec<DocTree> {
+    let params = imp
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match &param.kind {
+            GenericParamDefKind::Type { bounds, .. } => Some((param.name.clone(), bounds)),
+            _ => None,
+        });
+    let predicates = imp
+        .generics
+        .where_predicates
+        .iter()
+        .filter_map(|pred| match pred {
+            WherePredicate::BoundPredicate { type_, bounds, .. } => Some((short(type_), bounds)),
+            _ => None,
+        });
+    params
+        .chain(predicates)
+        .filter_map(|(ty, bounds)| {
+            let bounds: Vec<_> = bounds
+                .iter()
+                .filter(|b| {
+                    !matches!(
+                        b,
+                        GenericBound::TraitBound {
+                            modifier: TraitBoundModifier::Maybe,
+                            ..
+                        }
+                    )
+                })
+                .cloned()
+                .collect();
+            let bounds = (!bounds.is_empty()).then(|| short_bounds(&bounds))?;
+            Some(DocTree::new(
+                xformat!("where {ty}: {bounds}"),
+                Tag::Bound,
+                None,
+            ))
+        })
+        .collect()
+}
+
+/// Visible fields of a local struct/union, or fields in all variants of a local enum.
+fn fields<'a>(ty: &Type, map: &'a IDMap) -> Vec<(ID, &'a Type)> {
+    let Type::ResolvedPath(path) = ty else {
+        return Vec::new();
+    };
+    let field_ids: Vec<_> = match map.get_item(&path.id.0).map(|item| &item.inner) {
+        Some(ItemEnum::Struct(s)) => match &s.kind {
+            StructKind::Plain { fields, .. } => fields.iter().collect(),
+            StructKind::Tuple(fields) => fields.iter().flatten().collect(),
+            StructKind::Unit => Vec::new(),
+        },
+        Some(ItemEnum::Union(u)) => u.fields.iter().collect(),
+        Some(ItemEnum::Enum(e)) => e
+            .variants
+            .iter()
+            .filter_map(|id| match &map.get_item(&id.0)?.inner {
+                ItemEnum::Variant(v) => Some(&v.kind),
+                _ => None,
+            })
+            .flat_map(|kind| match kind {
+                VariantKind::Plain => Vec::new(),
+                VariantKind::Tuple(fields) => fields.iter().flatten().collect(),
+                VariantKind::Struct { fields, .. } => fields.iter().collect(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    field_ids
+        .into_iter()
+        .filter_map(|id| match &map.get_item(&id.0)?.inner {
+            ItemEnum::StructField(ty) => Some((id.to_ID(), ty)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Well-known types in std that don't implement the auto trait. This is a heuristic:
+/// a field may also opt out through its own fields.
+fn opts_out(trait_: &str, ty: &Type) -> bool {
+    const NOT_SEND: &[&str] = &[
+        "Rc",
+        "NonNull",
+        "MutexGuard",
+        "RwLockReadGuard",
+        "RwLockWriteGuard",
+    ];
+    const NOT_SYNC: &[&str] = &[
+        "Rc",
+        "NonNull",
+        "Cell",
+        "RefCell",
+        "UnsafeCell",
+        "OnceCell",
+        "Receiver",
+    ];
+    const NOT_UNPIN: &[&str] = &["PhantomPinned"];
+    const NOT_REF_UNWIND_SAFE: &[&str] = &["Cell", "RefCell", "UnsafeCell", "OnceCell"];
+    match ty {
+        Type::RawPointer { .. } => matches!(trait_, "Send" | "Sync"),
+        Type::ResolvedPath(path) => {
+            let list = match trait_ {
+                "Send" => NOT_SEND,
+                "Sync" => NOT_SYNC,
+                "Unpin" => NOT_UNPIN,
+                "RefUnwindSafe" | "UnwindSafe" => NOT_REF_UNWIND_SAFE,
+                _ => &[],
+            };
+            list.contains(&last_name(&path.name))
+                || path.args.as_deref().is_some_and(|args| match args {
+                    GenericArgs::AngleBracketed { args, .. } => args.iter().any(|arg| match arg {
+                        GenericArg::Type(ty) => opts_out(trait_, ty),
+                        _ => false,
+                    }),
+                    _ => false,
+                })
+        }
+        Type::DynTrait(dyn_) => {
+            matches!(trait_, "Send" | "Sync" | "RefUnwindSafe" | "UnwindSafe")
+                && !dyn_
+                    .traits
+                    .iter()
+                    .any(|t| last_name(&t.trait_.name) == trait_)
+        }
+        Type::BorrowedRef { type_, mutable, .. } => {
+            // `&mut T: !UnwindSafe`
+            if *mutable && trait_ == "UnwindSafe" {
+                return true;
+            }
+            // `&T: Send` requires `T: Sync`, and `&T: UnwindSafe` requires `T: RefUnwindSafe`
+            let trait_ = match trait_ {
+                "Send" => "Sync",
+                "UnwindSafe" => "RefUnwindSafe",
+                _ => trait_,
+            };
+            trait_ != "Unpin" && opts_out(trait_, type_)
+        }
+        Type::Tuple(tys) => tys.iter().any(|ty| opts_out(trait_, ty)),
+        Type::Slice(ty) | Type::Array { type_: ty, .. } => opts_out(trait_, ty),
+        _ => false,
+    }
+}
@@ -0,0 +1,151 @@
+use super::{DImpl, DModule};
+use crate::{
+    tree::{DocTree, IDMap, Tag, ID},
+    type_name::{style::short, WRAPPERS},
+};
+use rustdoc_types::{GenericArg, GenericArgs, Impl, ItemEnum, Type};
+
+/// How a function refers to the data item.
+#[derive(Clone, Copy)]
+enum Usage {
+    /// `fn(...) -> Self`, including `Result<Self, _>`, `Option<Self>` or `Box<Self>`.
+    Construct,
+    /// `fn(.., Self) -> ...`, i.e. non-receiver arguments, including references.
+    Consume,
+}
+
+/// Functions and methods that construct or consume a struct/enum/union.
+impl DModule {
+    pub fn constructor_tree(&self, id: &str, map: &IDMap) -> Option<DocTree> {
+        self.usage_tree(id, map, Usage::Construct)
+    }
+
+    pub fn consumer_tree(&self, id: &str, map: &IDMap) -> Option<DocTree> {
+        self.usage_tree(id, map, Usage::Consume)
+    }
+
+    fn usage_tree(&self, id: &str, map: &IDMap, usage: Usage) -> Option<DocTree> {
+        let tag = match &map.get_item(id)?.inner {
+            ItemEnum::Struct(_) => Tag::Struct,
+            ItemEnum::Enum(_) => Tag::Enum,
+            ItemEnum::Union(_) => Tag::Union,
+            _ => return None,
+        };
+        let mut root = DocTree::new(map.path(id), tag, Some(id.into()));
+        let mut leaves = Vec::new();
+        self.push_usages(id, map, usage, &mut leaves);
+        if leaves.is_empty() {
+            root.push(
+                match usage {
+                    Usage::Construct => Tag::NoConstructors,
+                    Usage::Consume => Tag::NoConsumers,
+                }
+                .show(),
+            );
+        } else {
+            root.extend(leaves);
+        }
+        Some(root)
+    }
+
+    fn push_usages(&self, target: &str, map: &IDMap, usage: Usage, leaves: &mut Vec<DocTree>) {
+        for item in &self.functions {
+            if uses(&item.id, None, target, map, usage) {
+                leaves.push(DocTree::new(
+                    map.path(&item.id),
+                    Tag::Function,
+                    Some(item.id.as_str().into()),
+                ));
+            }
+        }
+        macro_rules! push_impls {
+            ($( $field:ident ),+) => {$(
+                for item in &self.$field {
+                    push_impl_usages(&item.impls, target, map, usage, leaves);
+                }
+            )+};
+        }
+        push_impls!(structs, unions, enums);
+        for m in &self.modules {
+            m.push_usages(target, map, usage, leaves);
+        }
+    }
+}
+
+/// Methods in inherent and trait impls. Methods in blanket impls are too generic to count.
+fn push_impl_usages(
+    impls: &DImpl,
+    target: &str,
+    map: &IDMap,
+    usage: Usage,
+    leaves: &mut Vec<DocTree>,
+) {
+    for inner in impls.inherent.iter().chain(&*impls.trait_) {
+        let Some(ItemEnum::Impl(imp)) = map.get_item(&inner.id).map(|item| &item.inner) else {
+            continue;
+        };
+        for id in &*inner.functions {
+            if uses(id, Some(imp), target, map, usage) {
+                let name = format!("{}::{}", short(&imp.for_), map.name(id));
+                leaves.push(DocTree::new(
+                    name.into(),
+                    Tag::AssocFn,
+                    Some(id.as_str().into()),
+                ));
+            }
+        }
+    }
+}
+
+fn uses(id: &ID, imp: Option<&Impl>, target: &str, map: &IDMap, usage: Usage) -> bool {
+    let Some(ItemEnum::Function(func)) = map.get_item(id).map(|item| &item.inner) else {
+        return false;
+    };
+    // `Self` only refers to the target in its own impls
+    let self_is_target = imp.is_some_and(|imp| is_target(&imp.for_, target, false));
+    let decl = &func.decl;
+    match usage {
+        Usage::Construct => decl
+            .output
+            .as_ref()
+            .is_some_and(|ty| is_target(unwrap(ty), target, self_is_target)),
+        Usage::Consume => decl
+            .inputs
+            .iter()
+            .filter(|(name, _)| name != "self")
+            .any(|(_, ty)| {
+                let ty = match ty {
+                    Type::BorrowedRef { type_, .. } => type_,
+                    ty => ty,
+                };
+                is_target(unwrap(ty), target, self_is_target)
+            }),
+    }
+}
+
+fn is_target(ty: &Type, target: &str, self_is_target: bool) -> bool {
+    match ty {
+        Type::ResolvedPath(path) => path.id.0 == target,
+        Type::Generic(name) => self_is_target && name == "Self",
+        _ => false,
+    }
+}
+
+/// The first type argument of wrappers like `Option<T>` and `Result<T, E>`, or the type itself.
+fn unwrap(ty: &Type) -> &Type {
+    if let Type::ResolvedPath(path) = ty {
+        let name = path.name.rsplit("::").next().unwrap_or(&path.name);
+        if WRAPPERS.contains(&name) {
+            if let Some(GenericArgs::AngleBracketed { args, .. }) = path.args.as_deref() {
+                let first = args.iter().find_map(|arg| match arg {
+                    GenericArg::Type(ty) => Some(ty),
+                    _ => None,
+                });
+                if let Some(first) = first {
+                    return first;
+                }
+            }
+        }
+    }
+    ty
+}
@@ -0,0 +1,234 @@
+use super::{DImpl, DImplInner, DModule, DTrait, IDMap, Tag, ID};
+use crate::{
+    type_name::{FnSignature, Owner, TraitImpls},
+    util::{xformat, XString},
+};
+use rustdoc_types::{Impl, Item, ItemEnum, Path};
+use std::collections::HashSet;
+
+/// A searchable item with its full path in a crate.
+#[derive(Debug, Clone)]
+pub struct ItemPath {
+    pub id: ID,
+    /// Full path from [`IDMap::path`], or the path joined from the parent item path
+    /// and its name if the path is not recorded in PathMap, e.g. associated items.
+    pub path: XString,
+    pub tag: Tag,
+    /// The item in module tree that owns this item.
+    ///
+    /// This is None for items directly shown in module tree, and Some for associated
+    /// items in impl blocks and traits.
+    pub owner: Option<ID>,
+}
+
+impl DModule {
+    /// Collect all items in module tree, including associated items in impl blocks
+    /// and traits. Items are in the order of the module tree.
+    pub fn item_paths(&self, map: &IDMap) -> Vec<ItemPath> {
+        let mut paths = Vec::with_capacity(map.indexmap().len());
+        self.push_item_paths(map, &mut paths);
+        paths
+    }
+
+    fn push_item_paths(&self, map: &IDMap, paths: &mut Vec<ItemPath>) {
+        let mod_path = map.path(&self.id);
+        let path = |id: &ID| {
+            map.path_or_name(id)
+                .unwrap_or_else(|name| xformat!("{mod_path}::{name}"))
+        };
+        macro_rules! push {
+            ($( $field:ident => $tag:ident ),+ $(,)?) => {$(
+                for item in &self.$field {
+                    paths.push(ItemPath {
+                        id: item.id.clone(),
+                        path: path(&item.id),
+                        tag: Tag::$tag,
+                        owner: None,
+                    });
+                }
+            )+};
+        }
+
+        paths.push(ItemPath {
+            id: self.id.clone(),
+            path: mod_path.clone(),
+            tag: Tag::Module,
+            owner: None,
+        });
+        push!(
+            functions   => Function,
+            constants   => Constant,
+            statics     => Static,
+            type_alias  => TypeAlias,
+            macros_decl => MacroDecl,
+            macros_func => MacroFunc,
+            macros_attr => MacroAttr,
+            macros_derv => MacroDerv,
+        );
+        for item in &self.traits {
+            let trait_path = path(&item.id);
+            paths.push(ItemPath {
+                id: item.id.clone(),
+                path: trait_path.clone(),
+                tag: Tag::Trait,
+                owner: None,
+            });
+            push_trait_items(item, &trait_path, map, paths);
+        }
+        macro_rules! push_with_impls {
+            ($( $field:ident => $tag:ident ),+ $(,)?) => {$(
+                for item in &self.$field {
+                    let item_path = path(&item.id);
+                    paths.push(ItemPath {
+                        id: item.id.clone(),
+                        path: item_path.clone(),
+                        tag: Tag::$tag,
+                        owner: None,
+                    });
+                    push_impl_items(&item.impls, &item.id, &item_path, map, paths);
+                }
+            )+};
+        }
+        push_with_impls!(
+            structs => Struct,
+            unions => Union,
+            enums => Enum,
+            primitives => Primitive,
+        );
+        for m in &self.modules {
+            m.push_item_paths(map, paths);
+        }
+    }
+
+    /// Collect signatures of free functions, methods in inherent and trait impls and
+    /// methods in traits, as well as traits implemented by local types for matching generics.
+    pub fn fn_signatures(&self, map: &IDMap) -> (Vec<(ID, FnSignature)>, TraitImpls) {
+        let mut sigs = Vec::with_capacity(map.indexmap().len() / 2);
+        let mut impls = TraitImpls::default();
+        self.push_fn_signatures(map, &mut sigs, &mut impls);
+        (sigs, impls)
+    }
+
+    fn push_fn_signatures(
+        &self,
+        map: &IDMap,
+        sigs: &mut Vec<(ID, FnSignature)>,
+        impls: &mut TraitImpls,
+    ) {
+        for item in &self.functions {
+            push_fn_signature(&item.id, None, map, sigs);
+        }
+        for item in &self.traits {
+            let Some(ItemEnum::Trait(trait_)) = map.get_item(&item.id).map(|item| &item.inner)
+            else {
+                continue;
+            };
+            let name = map.name(&item.id);
+            for id in &*item.functions {
+                push_fn_signature(id, Some(Owner::Trait(&name, &trait_.generics)), map, sigs);
+            }
+        }
+        macro_rules! push_impls {
+            ($( $field:ident ),+) => {$(
+                for item in &self.$field {
+                    push_impl_signatures(&item.impls, map, sigs);
+                    let traits = impl_trait_names(&item.impls, map);
+                    impls.insert(item.id.clone(), map.name(&item.id), traits);
+                }
+            )+};
+        }
+        push_impls!(structs, unions, enums, primitives);
+        for m in &self.modules {
+            m.push_fn_signatures(map, sigs, impls);
+        }
+    }
+}
+
+fn push_fn_signature(
+    id: &ID,
+    owner: Option<Owner>,
+    map: &IDMap,
+    sigs: &mut Vec<(ID, FnSignature)>,
+) {
+    if let Some(ItemEnum::Function(func)) = map.get_item(id).map(|item| &item.inner) {
+        sigs.push((id.clone(), FnSignature::new(func, owner)));
+    }
+}
+
+fn get_impl<'a>(id: &ID, map: &'a IDMap) -> Option<&'a Impl> {
+    match map.get_item(id) {
+        Some(Item {
+            inner: ItemEnum::Impl(imp),
+            ..
+        }) => Some(imp),
+        _ => None,
+    }
+}
+
+/// Methods in inherent and trait impls, in line with [`push_impl_items`].
+fn push_impl_signatures(impls: &DImpl, map: &IDMap, sigs: &mut Vec<(ID, FnSignature)>) {
+    for inner in impls.inherent.iter().chain(&*impls.trait_) {
+        if let Some(imp) = get_impl(&inner.id, map) {
+            for id in &*inner.functions {
+                push_fn_signature(id, Some(Owner::Impl(imp)), map, sigs);
+            }
+        }
+    }
+}
+
+/// Traits implemented by the type, including auto traits and blanket impls.
+pub(super) fn impl_trait_names(impls: &DImpl, map: &IDMap) -> HashSet<XString> {
+    let iter = impls
+        .trait_
+        .iter()
+        .chain(&*impls.auto)
+        .chain(&*impls.blanket);
+    iter.filter_map(|inner| get_impl(&inner.id, map)?.trait_.as_ref())
+        .map(|path| trait_path(path, map))
+        .collect()
+}
+
+/// The full path of a trait if it's known in PathMap, otherwise the last name.
+/// Compare them by `same_trait`.
+pub(super) fn trait_path(path: &Path, map: &IDMap) -> XString {
+    map.path_or_name(&path.id.0)
+        .unwrap_or_else(|_| path.name.rsplit("::").next().unwrap_or(&path.name).into())
+}
+
+fn push_assoc_items(
+    [types, constants, functions]: [&[ID]; 3],
+    owner: &ID,
+    owner_path: &str,
+    map: &IDMap,
+    paths: &mut Vec<ItemPath>,
+) {
+    let iter = (types.iter().map(|id| (id, Tag::AssocType)))
+        .chain(constants.iter().map(|id| (id, Tag::AssocConst)))
+        .chain(functions.iter().map(|id| (id, Tag::AssocFn)));
+    paths.extend(iter.map(|(id, tag)| ItemPath {
+        id: id.clone(),
+        path: xformat!("{owner_path}::{}", map.name(id)),
+        tag,
+        owner: Some(owner.clone()),
+    }));
+}
+
+fn push_trait_items(item: &DTrait, path: &str, map: &IDMap, paths: &mut Vec<ItemPath>) {
+    let items = [&*item.types, &*item.constants, &*item.functions];
+    push_assoc_items(items, &item.id, path, map, paths);
+}
+
+/// Only inherent and trait impls are included, because items in auto and blanket impls
+/// are usually not defined in the local crate.
+fn push_impl_items(impls: &DImpl, owner: &ID, path: &str, map: &IDMap, paths: &mut Vec<ItemPath>) {
+    let iter = impls.inherent.iter().chain(&*impls.trait_);
+    for DImplInner {
+        functions,
+        constants,
+        types,
+        ..
+    } in iter
+    {
+        push_assoc_items([types, constants, functions], owner, path, map, paths);
+    }
+}
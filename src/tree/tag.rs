@@ -10,6 +10,7 @@ pub enum Tag {
     Union,
     Enum,
     Trait,
+    Primitive,
     Function,
     Constant,
     Static,
@@ -31,6 +32,7 @@ pub enum Tag {
     AutoImpls,
     ImplBlanket,
     BlanketImpls,
+    DerefMethods,
     NoVariants, // Head for no variants
     Variants,   // Head for variants
     Variant,
@@ -47,10 +49,25 @@ pub enum Tag {
     AssocFn,
     Implementors,
     Implementor,
+    GenericParams,
+    Supertraits,
+    Subtraits,
+    RequiredMethods,
+    ProvidedMethods,
+    NoConstructors,
+    NoConsumers,
+    Args,
+    Arg,
+    NoArgs,
+    Returns,
+    Return,
+    NoReturn,
+    Bound,
     Structs,
     Unions,
     Enums,
     Traits,
+    Primitives,
     Functions,
     Constants,
     Statics,
@@ -102,6 +119,8 @@ impl Tag {
             Tag::NoVariants => fg(183, 64, 168),  // #B740A8
             Tag::Traits => bfg(255, 140, 41),     // #FF8C29
             Tag::Trait => bufg(255, 140, 41),     // #FF8C29
+            Tag::Primitives => bfg(43, 121, 162), // #2B79A2
+            Tag::Primitive => bufg(43, 121, 162), // #2B79A2
             Tag::Functions => bfg(214, 83, 76),   // #D6534C
             Tag::Function => fg(214, 83, 76),     // #D6534C
             Tag::Constants => bfg(232, 218, 104), // #E8DA68
@@ -128,8 +147,15 @@ impl Tag {
             Tag::ImplAuto => fg(255, 140, 41),        // #FF8C29
             Tag::BlanketImpls => bfg(222, 186, 0),    // #DEBA00
             Tag::ImplBlanket => fg(222, 186, 0),      // #DEBA00
+            Tag::DerefMethods => bfg(243, 101, 134),  // #F36586
             Tag::Implementors => bfg(111, 162, 255),  // #6FA2FF
             Tag::Implementor => fg(111, 162, 255),    // #6FA2FF
+            Tag::Supertraits | Tag::Subtraits => bfg(255, 140, 41), // #FF8C29
+            Tag::GenericParams => bfg(255, 195, 144), // #FFC390
+            Tag::RequiredMethods | Tag::ProvidedMethods => bfg(214, 83, 76), // #D6534C
+            Tag::Args | Tag::Returns => bfg(214, 83, 76), // #D6534C
+            Tag::Arg | Tag::Return => fg(137, 199, 210), // #89C7D2
+            Tag::Bound => fg(255, 195, 144),          // #FFC390
             // black fg on gray bg in bg/fg inversion
             // If not set, black fg on black bg.
             _ => Style::default().fg(Gray),
@@ -144,6 +170,7 @@ impl Tag {
             Tag::Union => icon!("[Union]"),
             Tag::Enum => icon!("[Enum]"),
             Tag::Trait => icon!("[Trait]"),
+            Tag::Primitive => icon!("[Primitive]"),
             Tag::Function => icon!("[Fn]"),
             Tag::Constant => icon!("[Const]"),
             Tag::Static => icon!("[Static]"),
@@ -161,10 +188,40 @@ impl Tag {
             Tag::AssocType => icon!("[type]"),
             Tag::AssocConst => icon!("[const]"),
             Tag::AssocFn => icon!("[fn]"),
+            Tag::Arg => icon!("[arg]"),
+            Tag::Return => icon!("[ret]"),
+            Tag::Bound => icon!("[bound]"),
             _ => GlyphPalette::default(),
         }
     }
 
+    /// Item kind shown as the same text in glyph, but without tree indentation.
+    /// Empty for tags that are not for an item.
+    pub fn kind(self) -> &'static str {
+        match self {
+            Tag::Module | Tag::ModuleFolded => "[Mod]",
+            Tag::Struct => "[Struct]",
+            Tag::Union => "[Union]",
+            Tag::Enum => "[Enum]",
+            Tag::Trait => "[Trait]",
+            Tag::Primitive => "[Primitive]",
+            Tag::Function => "[Fn]",
+            Tag::Constant => "[Const]",
+            Tag::Static => "[Static]",
+            Tag::TypeAlias => "[type alias]",
+            Tag::MacroDecl => "[macro decl]",
+            Tag::MacroFunc => "[macro func]",
+            Tag::MacroAttr => "[macro attr]",
+            Tag::MacroDerv => "[macro derv]",
+            Tag::Field => "[field]",
+            Tag::Variant => "[variant]",
+            Tag::AssocType => "[type]",
+            Tag::AssocConst => "[const]",
+            Tag::AssocFn => "[fn]",
+            _ => "",
+        }
+    }
+
     /// Show as a simple heading node with no need for contexts/ids.
     pub fn show(self) -> DocTree {
         let text = match self {
@@ -184,10 +241,22 @@ impl Tag {
             Tag::AssocConsts => "Associated Constants",
             Tag::AssocFns => "Associated Functions",
             Tag::Implementors => "Implementors",
+            Tag::GenericParams => "Generic Parameters",
+            Tag::Supertraits => "Supertraits",
+            Tag::Subtraits => "Subtraits",
+            Tag::RequiredMethods => "Required Methods",
+            Tag::ProvidedMethods => "Provided Methods",
+            Tag::NoConstructors => "No Constructors!",
+            Tag::NoConsumers => "Not Used As Arguments!",
+            Tag::Args => "Arguments",
+            Tag::NoArgs => "No Arguments!",
+            Tag::Returns => "Return Type",
+            Tag::NoReturn => "Returns Unit!",
             Tag::Structs => "Structs",
             Tag::Unions => "Unions",
             Tag::Enums => "Enums",
             Tag::Traits => "Traits",
+            Tag::Primitives => "Primitive Types",
             Tag::Functions => "Functions",
             Tag::Constants => "Constants",
             Tag::Statics => "Statics",
@@ -1,9 +1,9 @@
-use self::fold::Fold;
+pub use self::fold::Fold;
 use crate::{
-    tree::{CrateDoc, DocTree, Tag},
-    util::XString,
+    tree::{Badges, CrateDoc, DocTree, IDMap, Tag},
+    util::{xformat, XString},
 };
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 use std::{
     fmt::{self, Write},
     rc::Rc,
@@ -57,6 +57,9 @@ pub struct TreeLine {
     /// Node/Item id from Crate
     pub id: Option<XString>,
     pub name: Text,
+    pub badges: Badges,
+    /// Compact cfg predicate like `[std]` for feature-gated items.
+    pub cfg: Option<XString>,
 }
 
 impl fmt::Debug for TreeLine {
@@ -98,6 +101,17 @@ impl TreeLine {
             level,
             id,
             name,
+            badges: Badges::default(),
+            cfg: None,
+        }
+    }
+
+    fn set_badges(&mut self, map: &IDMap) {
+        let Some(id) = &self.id else { return };
+        self.badges = map.badges(id);
+        self.cfg = map.cfg(id).map(|cfg| xformat!("[{cfg}]"));
+        if self.badges.deprecated {
+            self.name.style = self.name.style.add_modifier(Modifier::CROSSED_OUT);
         }
     }
 
@@ -114,14 +128,27 @@ impl TreeLine {
         ]
     }
 
-    /// non-cjk unicode width including glyph and name
+    /// texts and styles of badges and cfg after the name
+    pub fn badges(&self) -> impl Iterator<Item = (&str, Style)> + '_ {
+        let cfg_style = Style::default().fg(Color::Cyan);
+        let cfg = self.cfg.as_deref().map(|cfg| (cfg, cfg_style));
+        // shorten the 'static lifetime of badges to chain the cfg text
+        let badges = self
+            .badges
+            .texts()
+            .map(|(text, style)| (text as &str, style));
+        badges.chain(cfg)
+    }
+
+    /// non-cjk unicode width including glyph, name and badges
     ///
     /// reason for non-cjk:
     /// * path or name usually doesn't contain CJK
     /// * CJK width counts glyph width more, leading to wasteful space in outline
     pub fn width(&self) -> u16 {
         let (g, n) = (&*self.glyph.text, &*self.name.text);
-        (g.width() + n.width())
+        let cfg = self.cfg.as_deref().map_or(0, |cfg| cfg.width() + 1);
+        (g.width() + n.width() + self.badges.width() + cfg)
             .try_into()
             .unwrap_or_else(|_| panic!("The total width exceeds u16::MAX in `{g}{n}`"))
     }
@@ -132,19 +159,25 @@ pub struct TreeLines {
     doc: CrateDoc,
     lines: Rc<[TreeLine]>,
     fold: Fold,
+    /// Deprecated items are filtered out from the outline.
+    hide_deprecated: bool,
+    /// Only items available with these features are shown if specified.
+    features: Option<Box<[XString]>>,
 }
 
 impl TreeLines {
     /// This also returns an identical ZST tree as the outline layout and tree glyph.
     pub fn new_with(doc: CrateDoc, init: impl FnOnce(&CrateDoc) -> DocTree) -> (Self, Tree<Empty>) {
         let doctree = init(&doc);
-        let (lines, layout) = doctree.cache_lines();
+        let (lines, layout) = doctree.cache_lines(&doc);
 
         (
             TreeLines {
                 doc,
                 lines,
                 fold: Fold::default(),
+                hide_deprecated: false,
+                features: None,
             },
             layout,
         )
@@ -155,12 +188,14 @@ impl TreeLines {
         init: impl FnOnce(&CrateDoc) -> Option<DocTree>,
     ) -> Option<Self> {
         let doctree = init(doc)?;
-        let (lines, _) = doctree.cache_lines();
+        let (lines, _) = doctree.cache_lines(doc);
 
         Some(TreeLines {
             doc: doc.clone(),
             lines,
             fold: Fold::default(),
+            hide_deprecated: false,
+            features: None,
         })
     }
 
@@ -220,7 +255,7 @@ impl From<CrateDoc> for TreeLines {
 }
 
 impl DocTree {
-    fn cache_lines(self) -> (Rc<[TreeLine]>, Tree<Empty>) {
+    fn cache_lines(self, map: &IDMap) -> (Rc<[TreeLine]>, Tree<Empty>) {
         let (mut lines, layout) = TreeLine::flatten(self);
         let tree_glyph = glyph(&layout);
 
@@ -230,12 +265,22 @@ impl DocTree {
             "the amount of nodes is {len_nodes}, but that of glyph is {len_glyph}"
         );
 
-        lines
-            .iter_mut()
-            .zip(tree_glyph)
-            .for_each(|(l, g)| l.set_glyph(g));
+        lines.iter_mut().zip(tree_glyph).for_each(|(l, g)| {
+            l.set_glyph(g);
+            l.set_badges(map);
+        });
         (lines.into(), layout)
     }
+
+    /// Keep nodes with items satisfying the condition. Removed nodes take their subtrees away.
+    fn retain_items(&mut self, keep: &impl Fn(&str) -> bool) {
+        fn retain(tree: &mut Tree<TextTag>, keep: &impl Fn(&str) -> bool) {
+            tree.leaves
+                .retain(|leaf| leaf.root.id.as_deref().is_none_or(keep));
+            tree.leaves.iter_mut().for_each(|leaf| retain(leaf, keep));
+        }
+        retain(&mut self.tree, keep);
+    }
 }
 
 impl std::ops::Deref for TreeLines {
@@ -252,6 +297,8 @@ impl Default for TreeLines {
             doc: CrateDoc::default(),
             lines: Rc::new([]),
             fold: Fold::default(),
+            hide_deprecated: false,
+            features: None,
         }
     }
 }
@@ -1,10 +1,13 @@
 use super::TreeLines;
-use crate::tree::{DModule, DocTree, IDMap, ID};
+use crate::{
+    tree::{DModule, DocTree, IDMap, ID},
+    util::XString,
+};
 use rustc_hash::FxHashSet as HashSet;
 use rustdoc_types::ItemEnum;
 
 /// how to fold the text tree
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default, Clone, PartialEq, Eq)]
 enum Kind {
     /// Expand all public items in all modules.
     #[default]
@@ -28,7 +31,7 @@ enum Kind {
 }
 
 /// Fold based on module tree.
-#[derive(Default)]
+#[derive(Default, Clone, PartialEq, Eq)]
 pub struct Fold {
     kind: Kind,
     /// module IDs that should be expanded
@@ -54,7 +57,7 @@ impl TreeLines {
 
     pub fn expand_all(&mut self) {
         self._expand_all();
-        self.lines = self.dmodule().item_tree(self.idmap()).cache_lines().0;
+        self.set_lines(self.dmodule().item_tree(self.idmap()));
     }
 
     pub(super) fn _expand_all(&mut self) {
@@ -113,24 +116,6 @@ impl TreeLines {
     ///
     /// This pushs a module ID to a without setting any fold kind.
     pub fn expand_toggle(&mut self, id: ID) {
-        fn modules_traversal(
-            dmod: &DModule,
-            map: &IDMap,
-            parent: &mut DocTree,
-            should_stop: &mut impl FnMut(&DModule) -> bool,
-        ) {
-            for m in &dmod.modules {
-                if should_stop(m) {
-                    let node = node!(ModuleFolded: map, Module, &m.id);
-                    parent.push(node);
-                } else {
-                    let mut node = m.item_tree_only_in_one_specified_mod(map);
-                    modules_traversal(m, map, &mut node, should_stop);
-                    parent.push(node);
-                };
-            }
-        }
-
         if self.fold.kind == Kind::CurrentModule {
             // FIXME: poor interaction with CurrentModule bahavior
             //
@@ -151,6 +136,47 @@ impl TreeLines {
         } else {
             mods.insert(id);
         }
+        self.expand_specified_modules();
+    }
+
+    /// Expand modules from the root to the one that contains the item, and keep others as
+    /// they are. Returns false if the item is not in any module.
+    pub fn expand_to_item(&mut self, id: &str) -> bool {
+        let Some(ancestors) = self.dmodule().ancestors(id, self.idmap()) else {
+            return false;
+        };
+        if self.fold.kind == Kind::CurrentModule {
+            // only the innermost module can be the current one
+            self.fold.expand.clear();
+            self.fold.expand.extend(ancestors.into_iter().take(1));
+            self._expand_current_module_only();
+        } else {
+            self.fold.expand.extend(ancestors);
+            self.expand_specified_modules();
+        }
+        true
+    }
+
+    /// Expand modules in the expand set, and fold the others.
+    fn expand_specified_modules(&mut self) {
+        fn modules_traversal(
+            dmod: &DModule,
+            map: &IDMap,
+            parent: &mut DocTree,
+            should_stop: &mut impl FnMut(&DModule) -> bool,
+        ) {
+            for m in &dmod.modules {
+                if should_stop(m) {
+                    let node = node!(ModuleFolded: map, Module, &m.id);
+                    parent.push(node);
+                } else {
+                    let mut node = m.item_tree_only_in_one_specified_mod(map);
+                    modules_traversal(m, map, &mut node, should_stop);
+                    parent.push(node);
+                };
+            }
+        }
+
         self.update_cached_lines(|dmod, map, mods| {
             let mut root = dmod.item_tree_only_in_one_specified_mod(map);
             modules_traversal(dmod, map, &mut root, &mut |m| !mods.contains(&m.id));
@@ -159,6 +185,52 @@ impl TreeLines {
     }
 }
 
+impl TreeLines {
+    /// Show or hide deprecated items, and keep the current folding.
+    pub fn toggle_deprecated(&mut self) {
+        self.hide_deprecated = !self.hide_deprecated;
+        self.refresh();
+    }
+
+    pub fn is_deprecated_hidden(&self) -> bool {
+        self.hide_deprecated
+    }
+
+    /// Only show items available when the given features are enabled.
+    /// None means no filtering on features.
+    pub fn filter_features(&mut self, features: Option<Box<[XString]>>) {
+        self.features = features;
+        self.refresh();
+    }
+
+    pub fn filtered_features(&self) -> Option<&[XString]> {
+        self.features.as_deref()
+    }
+
+    /// The current folding, which can be brought back by [`TreeLines::restore_fold`].
+    pub fn fold_state(&self) -> Fold {
+        self.fold.clone()
+    }
+
+    /// Restore the folding and keep the current filters.
+    pub fn restore_fold(&mut self, fold: Fold) {
+        if self.fold != fold {
+            self.fold = fold;
+            self.refresh();
+        }
+    }
+
+    /// Rebuild lines with the current folding and filters.
+    fn refresh(&mut self) {
+        if self.fold.kind == Kind::CurrentModule {
+            self._expand_current_module_only();
+        } else {
+            // Other kinds of folding can be reproduced from the expand set.
+            self.expand_specified_modules();
+        }
+    }
+}
+
 impl TreeLines {
     pub fn expand_current_module_only(&mut self, id: ID) {
         self.fold.kind = Kind::CurrentModule;
@@ -247,10 +319,21 @@ impl TreeLines {
         let mods = &self.fold.expand;
         if mods.is_empty() {
             // if no mods are sepecified, default to expand all
-            self.lines = self.dmodule().item_tree(map).cache_lines().0;
+            self.set_lines(self.dmodule().item_tree(map));
             return;
         }
         let root = f(dmod, map, mods);
-        self.lines = root.cache_lines().0;
+        self.set_lines(root);
+    }
+
+    fn set_lines(&mut self, mut tree: DocTree) {
+        let map = self.idmap();
+        if self.hide_deprecated {
+            tree.retain_items(&|id| !map.is_deprecated(id));
+        }
+        if let Some(features) = &self.features {
+            tree.retain_items(&|id| map.cfg(id).is_none_or(|cfg| cfg.is_enabled(features)));
+        }
+        self.lines = tree.cache_lines(self.idmap()).0;
     }
 }
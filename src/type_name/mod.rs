@@ -1,5 +1,8 @@
 mod render;
+mod signature;
 pub(crate) mod style;
 
 pub use render::{DeclarationLine, DeclarationLines, TextTag};
+pub(crate) use signature::{same_trait, WRAPPERS};
+pub use signature::{FnSignature, Owner, SignatureQuery, TraitImpls, Ty};
 pub use style::StyledType;
@@ -35,8 +35,16 @@ impl From<&StyledType> for DeclarationLines {
 }
 
 impl DeclarationLines {
+    /// Attributes of the item are placed above the declaration.
     pub fn new(id: &str, map: &IDMap) -> Self {
-        Self::new_(&StyledType::new(id, map))
+        let mut decl = Self::new_(&StyledType::new(id, map));
+        if !decl.lines.is_empty() {
+            let attrs = map.attributes(id).into_iter().map(|text| DeclarationLine {
+                line: vec![TextTag { text, id: None }],
+            });
+            decl.lines.splice(0..0, attrs);
+        }
+        decl
     }
 
     fn new_(styled_type: &StyledType) -> Self {
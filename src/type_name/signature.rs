@@ -0,0 +1,742 @@
+//! Search functions by type signatures, like `&str -> Result<_, Error>` or
+//! `impl Read -> Vec<u8>`.
+//!
+//! Both the query and the types in rustdoc are normalized into [`Ty`]: types from rustdoc
+//! are formatted by [`short`] first, so they are parsed in the same way as a query.
+use super::style::{short, short_path};
+use crate::{
+    tree::ID,
+    util::{HashMap, XString},
+};
+use rustdoc_types::{
+    Function, GenericBound, GenericParamDefKind, Generics, Impl, Type, WherePredicate,
+};
+use std::collections::HashSet;
+
+/// A normalized type in signature matching.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Ty {
+    /// `_` matches any type.
+    Infer,
+    /// A path type or generic parameter with the last path name and generic arguments,
+    /// e.g. `Vec<u8>`, `T` or `Fn(u8) -> bool` whose inputs and output are arguments.
+    Named(XString, Vec<Ty>),
+    Ref(bool, Box<Ty>),
+    RawPointer(bool, Box<Ty>),
+    Slice(Box<Ty>),
+    Array(Box<Ty>),
+    Tuple(Vec<Ty>),
+    /// `impl Trait` or `dyn Trait` with trait bounds as `Named`s.
+    Bounds(Vec<Ty>),
+    /// `fn(A) -> B`
+    FnPointer(Vec<Ty>, Box<Ty>),
+    Never,
+}
+
+static UNIT: Ty = Ty::Tuple(Vec::new());
+
+impl Ty {
+    /// Parse a type. Returns None if the text is not a type.
+    pub fn parse(text: &str) -> Option<Ty> {
+        let mut parser = Parser::new(text);
+        let ty = parser.ty()?;
+        parser.is_end().then_some(ty)
+    }
+
+    fn name(&self) -> Option<&str> {
+        match self {
+            Ty::Named(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn replace_self(&mut self, self_ty: &Ty) {
+        match self {
+            Ty::Named(name, args) if *name == "Self" && args.is_empty() => *self = self_ty.clone(),
+            Ty::Named(_, tys) | Ty::Tuple(tys) | Ty::Bounds(tys) => {
+                tys.iter_mut().for_each(|ty| ty.replace_self(self_ty))
+            }
+            Ty::Ref(_, ty) | Ty::RawPointer(_, ty) | Ty::Slice(ty) | Ty::Array(ty) => {
+                ty.replace_self(self_ty)
+            }
+            Ty::FnPointer(inputs, output) => {
+                inputs.iter_mut().for_each(|ty| ty.replace_self(self_ty));
+                output.replace_self(self_ty);
+            }
+            Ty::Infer | Ty::Never => (),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token<'s> {
+    Ident(&'s str),
+    Lifetime,
+    Str,
+    Sym(&'static str),
+}
+
+const SYMBOLS: &[&str] = &[
+    "::", "->", "<", ">", ",", "&", "(", ")", "[", "]", ";", "+", "*", "!", "=", "?", ":",
+];
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_alphanumeric() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(&rest[..len]));
+            len
+        } else if c == '\'' {
+            tokens.push(Token::Lifetime);
+            1 + rest[1..]
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len() - 1)
+        } else if c == '"' {
+            tokens.push(Token::Str);
+            rest[1..].find('"').map_or(rest.len(), |pos| pos + 2)
+        } else if let Some(sym) = SYMBOLS.iter().find(|sym| rest.starts_with(**sym)) {
+            tokens.push(Token::Sym(sym));
+            sym.len()
+        } else {
+            // skip unknown chars
+            c.len_utf8()
+        };
+        rest = rest[len..].trim_start();
+    }
+    tokens
+}
+
+struct Parser<'s> {
+    tokens: Vec<Token<'s>>,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn new(text: &'s str) -> Self {
+        Parser {
+            tokens: tokenize(text),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<Token<'s>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'s>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    fn is_end(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    /// Consume the symbol if it's the next token.
+    fn eat(&mut self, sym: &str) -> bool {
+        let matched = matches!(self.peek(), Some(Token::Sym(s)) if s == sym);
+        self.pos += matched as usize;
+        matched
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        let matched = matches!(self.peek(), Some(Token::Ident(s)) if s == ident);
+        self.pos += matched as usize;
+        matched
+    }
+
+    /// Skip tokens till the closing symbol that is not nested.
+    fn skip_until(&mut self, open: &str, close: &str) {
+        let mut depth = 0usize;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Sym(s) if s == open => depth += 1,
+                Token::Sym(s) if s == close => {
+                    if depth == 0 {
+                        return;
+                    }
+                    depth -= 1;
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn ty(&mut self) -> Option<Ty> {
+        // skip prefixes for fn pointers and HRTB
+        while self.eat_ident("unsafe") || self.eat_ident("const") || self.eat_ident("async") {}
+        if self.eat_ident("extern") && self.peek() == Some(Token::Str) {
+            self.pos += 1;
+        }
+        if self.eat_ident("for") && self.eat("<") {
+            self.skip_until("<", ">");
+        }
+        match self.next()? {
+            Token::Sym("&") => {
+                if self.peek() == Some(Token::Lifetime) {
+                    self.pos += 1;
+                }
+                let mutable = self.eat_ident("mut");
+                Some(Ty::Ref(mutable, Box::new(self.ty()?)))
+            }
+            Token::Sym("*") => {
+                let mutable = self.eat_ident("mut");
+                if !mutable {
+                    self.eat_ident("const");
+                }
+                Some(Ty::RawPointer(mutable, Box::new(self.ty()?)))
+            }
+            Token::Sym("[") => {
+                let ty = Box::new(self.ty()?);
+                if self.eat(";") {
+                    self.skip_until("[", "]");
+                    Some(Ty::Array(ty))
+                } else {
+                    self.eat("]").then_some(Ty::Slice(ty))
+                }
+            }
+            Token::Sym("(") => {
+                let (mut tys, trailing_comma) = self.list(")")?;
+                if tys.len() == 1 && !trailing_comma {
+                    tys.pop()
+                } else {
+                    Some(Ty::Tuple(tys))
+                }
+            }
+            Token::Sym("!") => Some(Ty::Never),
+            Token::Sym("<") => {
+                // qualified path: `<Type as Trait>::Name`
+                self.skip_until("<", ">");
+                self.eat("::");
+                self.path()
+            }
+            Token::Ident("impl" | "dyn") => self.bounds().map(Ty::Bounds),
+            Token::Ident("fn") => {
+                if !self.eat("(") {
+                    return None;
+                }
+                let (inputs, _) = self.list(")")?;
+                let output = self.output()?.unwrap_or_else(|| UNIT.clone());
+                Some(Ty::FnPointer(inputs, Box::new(output)))
+            }
+            Token::Ident("_") => Some(Ty::Infer),
+            Token::Ident(_) => {
+                self.pos -= 1;
+                self.path()
+            }
+            _ => None,
+        }
+    }
+
+    /// Types separated by comma till the closing symbol.
+    /// The bool is true if there is a trailing comma.
+    fn list(&mut self, close: &str) -> Option<(Vec<Ty>, bool)> {
+        let mut tys = Vec::new();
+        let mut trailing_comma = false;
+        while !self.eat(close) {
+            tys.push(self.ty()?);
+            trailing_comma = self.eat(",");
+            if !trailing_comma && !self.eat(close) {
+                return None;
+            }
+            if !trailing_comma {
+                break;
+            }
+        }
+        Some((tys, trailing_comma))
+    }
+
+    /// `-> Type` if exists.
+    fn output(&mut self) -> Option<Option<Ty>> {
+        if self.eat("->") {
+            self.ty().map(Some)
+        } else {
+            Some(None)
+        }
+    }
+
+    /// A path with the last segment name and generic args.
+    fn path(&mut self) -> Option<Ty> {
+        let mut name = match self.next()? {
+            Token::Ident(name) => name,
+            _ => return None,
+        };
+        let mut args = Vec::new();
+        loop {
+            if self.eat("<") {
+                args = self.generic_args()?;
+            } else if self.eat("(") {
+                // Fn traits like `Fn(A) -> B`
+                let (inputs, _) = self.list(")")?;
+                args = inputs;
+                args.push(self.output()?.unwrap_or_else(|| UNIT.clone()));
+            }
+            if !self.eat("::") {
+                break;
+            }
+            match self.next()? {
+                Token::Ident(s) => name = s,
+                // turbofish
+                Token::Sym("<") => self.pos -= 1,
+                _ => return None,
+            }
+            args.clear();
+        }
+        Some(Ty::Named(name.into(), args))
+    }
+
+    /// Generic args in `<...>` after `<` is consumed. Lifetimes and consts are skipped,
+    /// and associated type bindings like `Item = T` are regarded as `T`.
+    fn generic_args(&mut self) -> Option<Vec<Ty>> {
+        let mut args = Vec::new();
+        loop {
+            match self.peek()? {
+                Token::Sym(">") => {
+                    self.pos += 1;
+                    return Some(args);
+                }
+                Token::Sym(",") => self.pos += 1,
+                Token::Lifetime => self.pos += 1,
+                Token::Ident(_) if self.tokens.get(self.pos + 1) == Some(&Token::Sym("=")) => {
+                    self.pos += 2;
+                }
+                Token::Ident(_) if self.tokens.get(self.pos + 1) == Some(&Token::Sym(":")) => {
+                    // associated type bounds like `Item: Trait`
+                    self.pos += 2;
+                    args.push(Ty::Bounds(self.bounds()?));
+                }
+                _ => args.push(self.ty()?),
+            }
+        }
+    }
+
+    /// Trait bounds separated by `+`. Lifetimes and `?Sized` are skipped.
+    fn bounds(&mut self) -> Option<Vec<Ty>> {
+        let mut bounds = Vec::new();
+        loop {
+            match self.peek() {
+                Some(Token::Lifetime) => self.pos += 1,
+                Some(Token::Sym("?")) => {
+                    self.pos += 1;
+                    self.path()?;
+                }
+                Some(Token::Sym("(")) => {
+                    // parenthesized bounds like `dyn (Trait)`
+                    self.pos += 1;
+                    bounds.extend(self.bounds()?);
+                    self.eat(")");
+                }
+                _ => {
+                    if self.eat_ident("for") && self.eat("<") {
+                        self.skip_until("<", ">");
+                    }
+                    bounds.push(self.path()?);
+                }
+            }
+            if !self.eat("+") {
+                return Some(bounds);
+            }
+        }
+    }
+}
+
+/// A query like `&str, usize -> Result<_, Error>`.
+///
+/// Inputs are separated by comma, and the output follows `->`.
+/// If `->` is absent, the output is not checked.
+#[derive(Clone, Debug)]
+pub struct SignatureQuery {
+    inputs: Vec<Ty>,
+    output: Option<Ty>,
+}
+
+impl SignatureQuery {
+    pub fn parse(query: &str) -> Option<Self> {
+        let mut parser = Parser::new(query);
+        let mut inputs = Vec::new();
+        while !parser.is_end() && parser.peek() != Some(Token::Sym("->")) {
+            inputs.push(parser.ty()?);
+            if !parser.eat(",") {
+                break;
+            }
+        }
+        let output = parser.output()?;
+        parser.is_end().then_some(SignatureQuery { inputs, output })
+    }
+}
+
+/// Traits implemented by local types, keyed by type IDs. Traits are stored in full paths
+/// if known, and compared with bounds by [`same_trait`].
+///
+/// This is used to check whether a concrete type satisfies trait bounds of generics.
+/// Types in signatures are only known by names, thus types sharing the same name are all
+/// taken into account. If no type has the name, it's unknown and regarded as satisfying
+/// any bound.
+#[derive(Clone, Debug, Default)]
+pub struct TraitImpls {
+    traits: HashMap<ID, HashSet<XString>>,
+    /// Type names to IDs of types that have the name.
+    names: HashMap<XString, Vec<ID>>,
+}
+
+impl TraitImpls {
+    pub fn insert(&mut self, id: ID, name: XString, traits: HashSet<XString>) {
+        let ids = self.names.entry(name).or_default();
+        if !ids.contains(&id) {
+            ids.push(id.clone());
+        }
+        self.traits.insert(id, traits);
+    }
+
+    /// Returns false only if all types with the name are known not to implement the trait.
+    pub fn may_impl(&self, ty: &str, trait_: &str) -> bool {
+        let Some(ids) = self.names.get(ty) else {
+            return true;
+        };
+        ids.iter().any(|id| {
+            self.traits
+                .get(id)
+                .is_none_or(|traits| traits.iter().any(|t| same_trait(t, trait_)))
+        })
+    }
+}
+
+/// Traits are compared by full paths if both are paths like `core::fmt::Debug`,
+/// otherwise by the last names.
+pub(crate) fn same_trait(a: &str, b: &str) -> bool {
+    fn name(path: &str) -> &str {
+        path.rsplit("::").next().unwrap_or(path)
+    }
+    if a.contains("::") && b.contains("::") {
+        a == b
+    } else {
+        name(a) == name(b)
+    }
+}
+
+/// The owner of a method.
+pub enum Owner<'a> {
+    Impl(&'a Impl),
+    /// A trait with its name and generics.
+    Trait(&'a str, &'a Generics),
+}
+
+/// A normalized function signature for searching.
+#[derive(Clone, Debug)]
+pub struct FnSignature {
+    inputs: Vec<Ty>,
+    output: Option<Ty>,
+    /// Generic type parameters with their trait bounds.
+    generics: Vec<(XString, Vec<Ty>)>,
+    /// The signature shown to users, like `(&Self, usize) -> Option<&T>`.
+    display: XString,
+}
+
+impl FnSignature {
+    pub fn new(func: &Function, owner: Option<Owner>) -> Self {
+        let mut generics = Vec::new();
+        push_generics(&func.generics, &mut generics);
+        let mut self_ty = None;
+        match owner {
+            Some(Owner::Impl(imp)) => {
+                push_generics(&imp.generics, &mut generics);
+                self_ty = Ty::parse(&short(&imp.for_));
+            }
+            Some(Owner::Trait(name, trait_generics)) => {
+                push_generics(trait_generics, &mut generics);
+                generics.push(("Self".into(), vec![Ty::Named(name.into(), Vec::new())]));
+            }
+            None => (),
+        }
+
+        let decl = &func.decl;
+        let mut display = String::with_capacity(32);
+        display.push('(');
+        let mut inputs = Vec::with_capacity(decl.inputs.len());
+        for (idx, (_, ty)) in decl.inputs.iter().enumerate() {
+            let ty = short(ty);
+            if idx != 0 {
+                display.push_str(", ");
+            }
+            display.push_str(&ty);
+            inputs.push(Ty::parse(&ty).unwrap_or(Ty::Infer));
+        }
+        display.push(')');
+        let output = decl.output.as_ref().map(|ty| {
+            let ty = short(ty);
+            display.push_str(" -> ");
+            display.push_str(&ty);
+            Ty::parse(&ty).unwrap_or(Ty::Infer)
+        });
+
+        let mut sig = FnSignature {
+            inputs,
+            output,
+            generics,
+            display: display.into(),
+        };
+        if let Some(self_ty) = &self_ty {
+            sig.inputs
+                .iter_mut()
+                .for_each(|ty| ty.replace_self(self_ty));
+            if let Some(ty) = &mut sig.output {
+                ty.replace_self(self_ty);
+            }
+        }
+        sig
+    }
+
+    pub fn display(&self) -> &str {
+        &self.display
+    }
+
+    /// Returns a score if the signature matches the query. Higher score means better match,
+    /// and exact matches always rank above partial ones.
+    ///
+    /// An exact match requires all types identical in the same order except `_` placeholders,
+    /// whereas partial matches allow generics unification, unordered or extra arguments and
+    /// output wrapped in `Option`/`Result`.
+    pub fn score(&self, query: &SignatureQuery, impls: &TraitImpls) -> Option<u32> {
+        const EXACT: u32 = 1000;
+        let mut unifier = Unifier {
+            sig: self,
+            impls,
+            subst: Vec::new(),
+            exact: true,
+        };
+        let (q, c) = (&query.inputs, &self.inputs);
+        if q.len() > c.len() {
+            return None;
+        }
+        let mut score = 0;
+        // try the same order first
+        let ordered = (q.len() == c.len())
+            .then(|| {
+                let mut u = unifier.clone();
+                let score = q
+                    .iter()
+                    .zip(c)
+                    .try_fold(0, |acc, (q, c)| u.unify(q, c).map(|s| acc + s))?;
+                Some((score, u))
+            })
+            .flatten();
+        if let Some((s, u)) = ordered {
+            score += s;
+            unifier = u;
+        } else {
+            unifier.exact = false;
+            let (s, u) = unifier.unify_unordered(q, c, &mut vec![false; c.len()])?;
+            score += s;
+            unifier = u;
+        }
+
+        if let Some(q) = &query.output {
+            let c = self.output.as_ref().unwrap_or(&UNIT);
+            let mut u = unifier.clone();
+            if let Some(s) = u.unify(q, c) {
+                score += s;
+                unifier = u;
+            } else {
+                // the expected output is wrapped in `Option`, `Result` etc
+                let inner = match c {
+                    Ty::Named(name, args) if WRAPPERS.contains(&name.as_str()) => args.first(),
+                    _ => None,
+                }?;
+                score += unifier.unify(q, inner)?;
+                unifier.exact = false;
+            }
+        }
+
+        if unifier.exact {
+            score += EXACT;
+        }
+        // penalty on extra arguments
+        Some(
+            (score + 1)
+                .saturating_sub((c.len() - q.len()) as u32)
+                .max(1),
+        )
+    }
+}
+
+pub(crate) const WRAPPERS: &[&str] = &["Option", "Result", "Box", "Rc", "Arc", "Poll"];
+
+fn push_generics(generics: &Generics, buf: &mut Vec<(XString, Vec<Ty>)>) {
+    for param in &generics.params {
+        if let GenericParamDefKind::Type { bounds, .. } = &param.kind {
+            buf.push((param.name.as_str().into(), trait_bounds(bounds)));
+        }
+    }
+    for pred in &generics.where_predicates {
+        if let WherePredicate::BoundPredicate {
+            type_: Type::Generic(name),
+            bounds,
+            ..
+        } = pred
+        {
+            let bounds = trait_bounds(bounds);
+            match buf.iter_mut().find(|(generic, _)| generic == name) {
+                Some((_, b)) => b.extend(bounds),
+                None => buf.push((name.as_str().into(), bounds)),
+            }
+        }
+    }
+}
+
+fn trait_bounds(bounds: &[GenericBound]) -> Vec<Ty> {
+    bounds
+        .iter()
+        .filter_map(|bound| match bound {
+            GenericBound::TraitBound { trait_, .. } => Ty::parse(&short_path(trait_)),
+            GenericBound::Outlives(_) => None,
+        })
+        .collect()
+}
+
+/// Match a query type against a type from a signature.
+#[derive(Clone)]
+struct Unifier<'s> {
+    sig: &'s FnSignature,
+    impls: &'s TraitImpls,
+    /// Generic parameters that have been unified with query types.
+    subst: Vec<(&'s str, Ty)>,
+    /// False if any partial matching rule is used.
+    exact: bool,
+}
+
+impl<'s> Unifier<'s> {
+    fn generic(&self, c: &Ty) -> Option<&'s (XString, Vec<Ty>)> {
+        match c {
+            Ty::Named(name, args) if args.is_empty() => {
+                self.sig.generics.iter().find(|(g, _)| g == name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns false only if the type is known not to implement the trait.
+    fn may_impl(&self, ty: &Ty, bound: &Ty) -> bool {
+        let (Some(ty), Some(bound)) = (ty.name(), bound.name()) else {
+            return true;
+        };
+        self.impls.may_impl(ty, bound)
+    }
+
+    /// Query bounds are all in candidate bounds by name.
+    fn bounds_contained(q: &[Ty], c: &[Ty]) -> bool {
+        q.iter()
+            .all(|q| c.iter().any(|c| q.name().is_some() && q.name() == c.name()))
+    }
+
+    /// Unify query types with distinct candidate types in any order, and returns the best
+    /// score with the unifier after it. Backtracking is used because a type matched early,
+    /// e.g. by `_`, may be needed by a later query type, and functions have few arguments.
+    fn unify_unordered(&self, q: &[Ty], c: &'s [Ty], used: &mut [bool]) -> Option<(u32, Self)> {
+        let Some((first, rest)) = q.split_first() else {
+            return Some((0, self.clone()));
+        };
+        let mut best: Option<(u32, Self)> = None;
+        for (pos, ty) in c.iter().enumerate() {
+            if used[pos] {
+                continue;
+            }
+            let mut u = self.clone();
+            let Some(s) = u.unify(first, ty) else {
+                continue;
+            };
+            used[pos] = true;
+            if let Some((rest, u)) = u.unify_unordered(rest, c, used) {
+                if best.as_ref().is_none_or(|(best, _)| s + rest > *best) {
+                    best = Some((s + rest, u));
+                }
+            }
+            used[pos] = false;
+        }
+        best
+    }
+
+    /// Returns a score if matched.
+    fn unify(&mut self, q: &Ty, c: &'s Ty) -> Option<u32> {
+        if let Some((name, bounds)) = self.generic(c) {
+            self.exact = false;
+            if let Some((_, ty)) = self.subst.iter().find(|(g, _)| g == name) {
+                return (ty == q).then_some(2);
+            }
+            let matched = match q {
+                Ty::Infer => true,
+                Ty::Bounds(q) => Self::bounds_contained(q, bounds),
+                Ty::Named(g, _) if self.generic(q).is_some() && g == name => true,
+                _ => bounds.iter().all(|bound| self.may_impl(q, bound)),
+            };
+            if matched {
+                self.subst.push((name, q.clone()));
+                return Some(1);
+            }
+            return None;
+        }
+        match (q, c) {
+            (Ty::Infer, _) => Some(1),
+            (Ty::Named(a, qa), Ty::Named(b, ca)) if a == b => {
+                if qa.len() == ca.len() {
+                    let mut score = 3;
+                    for (q, c) in qa.iter().zip(ca) {
+                        score += self.unify(q, c)?;
+                    }
+                    Some(score)
+                } else if qa.is_empty() || ca.is_empty() {
+                    // `Vec` matches `Vec<T>`
+                    self.exact = false;
+                    Some(2)
+                } else {
+                    None
+                }
+            }
+            (Ty::Ref(a, q), Ty::Ref(b, c)) | (Ty::RawPointer(a, q), Ty::RawPointer(b, c))
+                if a == b =>
+            {
+                self.unify(q, c).map(|s| s + 1)
+            }
+            (Ty::Slice(q), Ty::Slice(c)) | (Ty::Array(q), Ty::Array(c)) => {
+                self.unify(q, c).map(|s| s + 1)
+            }
+            (Ty::Tuple(q), Ty::Tuple(c)) if q.len() == c.len() => {
+                let mut score = 1;
+                for (q, c) in q.iter().zip(c) {
+                    score += self.unify(q, c)?;
+                }
+                Some(score)
+            }
+            (Ty::FnPointer(qi, qo), Ty::FnPointer(ci, co)) if qi.len() == ci.len() => {
+                let mut score = self.unify(qo, co)?;
+                for (q, c) in qi.iter().zip(ci) {
+                    score += self.unify(q, c)?;
+                }
+                Some(score)
+            }
+            (Ty::Bounds(q), Ty::Bounds(c)) => {
+                if !Self::bounds_contained(q, c) {
+                    return None;
+                }
+                if q.len() != c.len() {
+                    self.exact = false;
+                }
+                Some(3)
+            }
+            // a concrete type for `impl Trait` argument
+            (_, Ty::Bounds(c)) => {
+                self.exact = false;
+                c.iter().all(|bound| self.may_impl(q, bound)).then_some(1)
+            }
+            (Ty::Never, Ty::Never) => Some(3),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,244 @@
+use super::*;
+
+fn named(name: &str, args: Vec<Ty>) -> Ty {
+    Ty::Named(name.into(), args)
+}
+
+fn ty(name: &str) -> Ty {
+    named(name, Vec::new())
+}
+
+fn parse(text: &str) -> Ty {
+    Ty::parse(text).unwrap_or_else(|| panic!("failed to parse {text:?}"))
+}
+
+/// A signature from types in text, with generic names and their bounds.
+fn sig(inputs: &[&str], output: Option<&str>, generics: &[(&str, &[&str])]) -> FnSignature {
+    FnSignature {
+        inputs: inputs.iter().map(|ty| parse(ty)).collect(),
+        output: output.map(parse),
+        generics: generics
+            .iter()
+            .map(|(name, bounds)| ((*name).into(), bounds.iter().map(|b| parse(b)).collect()))
+            .collect(),
+        display: XString::default(),
+    }
+}
+
+fn query(text: &str) -> SignatureQuery {
+    SignatureQuery::parse(text).unwrap_or_else(|| panic!("failed to parse query {text:?}"))
+}
+
+fn traits(names: &[&str]) -> HashSet<XString> {
+    names.iter().map(|&name| name.into()).collect()
+}
+
+#[test]
+fn parse_types() {
+    let str_ref = Ty::Ref(false, Box::new(ty("str")));
+    assert_eq!(parse("&'a str"), str_ref);
+    assert_eq!(
+        parse("&mut [T]"),
+        Ty::Ref(true, Box::new(Ty::Slice(Box::new(ty("T")))))
+    );
+    assert_eq!(parse("[u8; 4]"), Ty::Array(Box::new(ty("u8"))));
+    assert_eq!(
+        parse("*const u8"),
+        Ty::RawPointer(false, Box::new(ty("u8")))
+    );
+    assert_eq!(parse("(u8)"), ty("u8"));
+    assert_eq!(parse("(u8,)"), Ty::Tuple(vec![ty("u8")]));
+    assert_eq!(parse("()"), UNIT);
+    assert_eq!(parse("!"), Ty::Never);
+    // only the last path segment is kept
+    assert_eq!(
+        parse("std::io::Result<()>"),
+        named("Result", vec![UNIT.clone()])
+    );
+    assert_eq!(parse("Vec::<u8>::new"), ty("new"));
+    assert_eq!(parse("<T as Iterator>::Item"), ty("Item"));
+    // lifetimes and consts in generic args are skipped, and bindings are regarded as types
+    assert_eq!(parse("Cow<'a, str>"), named("Cow", vec![ty("str")]));
+    assert_eq!(
+        parse("impl Iterator<Item = u8> + '_"),
+        Ty::Bounds(vec![named("Iterator", vec![ty("u8")])])
+    );
+    assert_eq!(
+        parse("Box<dyn Fn(&str) -> bool + Send>"),
+        named(
+            "Box",
+            vec![Ty::Bounds(vec![
+                named("Fn", vec![str_ref.clone(), ty("bool")]),
+                ty("Send")
+            ])]
+        )
+    );
+    assert_eq!(
+        parse("unsafe extern \"C\" fn(u8)"),
+        Ty::FnPointer(vec![ty("u8")], Box::new(UNIT.clone()))
+    );
+    assert_eq!(
+        parse("for<'a> fn(&'a str) -> &'a str"),
+        Ty::FnPointer(vec![str_ref.clone()], Box::new(str_ref))
+    );
+    assert_eq!(parse("impl ?Sized + Read"), Ty::Bounds(vec![ty("Read")]));
+
+    for invalid in ["", "Vec<", "&", "(u8", "u8 u16", "fn u8"] {
+        assert_eq!(Ty::parse(invalid), None, "{invalid:?}");
+    }
+}
+
+#[test]
+fn parse_queries() {
+    let q = query("&str -> Result<_, Error>");
+    assert_eq!(q.inputs, [Ty::Ref(false, Box::new(ty("str")))]);
+    assert_eq!(
+        q.output,
+        Some(named("Result", vec![Ty::Infer, ty("Error")]))
+    );
+
+    let q = query("impl Read -> Vec<u8>");
+    assert_eq!(q.inputs, [Ty::Bounds(vec![ty("Read")])]);
+    assert_eq!(q.output, Some(named("Vec", vec![ty("u8")])));
+
+    let q = query("HashMap<K, V>, &K");
+    assert_eq!(q.inputs.len(), 2);
+    assert_eq!(q.output, None);
+
+    // no input
+    let q = query("-> usize");
+    assert!(q.inputs.is_empty());
+    assert_eq!(q.output, Some(ty("usize")));
+
+    assert!(SignatureQuery::parse("usize ->").is_none());
+    assert!(SignatureQuery::parse("usize -> u8 -> u8").is_none());
+    assert!(SignatureQuery::parse("Vec<u8 -> u8").is_none());
+}
+
+#[test]
+fn unify_bounded_generics() {
+    let mut impls = TraitImpls::default();
+    impls.insert(ID::new("0:1"), "File".into(), traits(&["Read", "Write"]));
+    impls.insert(ID::new("0:2"), "Buffer".into(), traits(&["Write"]));
+
+    // fn read_all<R: Read>(reader: R) -> Vec<u8>
+    let read_all = sig(&["R"], Some("Vec<u8>"), &[("R", &["Read"])]);
+    assert!(read_all.score(&query("File -> Vec<u8>"), &impls).is_some());
+    assert!(read_all
+        .score(&query("impl Read -> Vec<u8>"), &impls)
+        .is_some());
+    assert!(read_all.score(&query("_ -> Vec<u8>"), &impls).is_some());
+    // a type not in local crate may implement any trait
+    assert!(read_all.score(&query("Stdin -> Vec<u8>"), &impls).is_some());
+    // Buffer is known not to implement Read
+    assert_eq!(read_all.score(&query("Buffer -> Vec<u8>"), &impls), None);
+    assert_eq!(read_all.score(&query("impl Write"), &impls), None);
+
+    // the same generic must be unified with the same type
+    // fn max<T: Ord>(a: T, b: T) -> T
+    let max = sig(&["T", "T"], Some("T"), &[("T", &["Ord"])]);
+    assert!(max.score(&query("u8, u8 -> u8"), &impls).is_some());
+    assert_eq!(max.score(&query("u8, u16"), &impls), None);
+    assert_eq!(max.score(&query("u8, u8 -> u16"), &impls), None);
+
+    // `impl Trait` in arguments accepts concrete types implementing the trait
+    // fn copy(reader: impl Read, writer: &mut impl Write)
+    let copy = sig(&["impl Read", "&mut impl Write"], None, &[]);
+    assert!(copy.score(&query("File, &mut Buffer"), &impls).is_some());
+    assert_eq!(copy.score(&query("Buffer, &mut File"), &impls), None);
+}
+
+#[test]
+fn same_named_types() {
+    let mut impls = TraitImpls::default();
+    impls.insert(ID::new("0:1"), "Error".into(), traits(&["Display"]));
+    impls.insert(ID::new("0:2"), "Error".into(), traits(&["Debug"]));
+    // types of the same name don't overwrite each other
+    assert!(impls.may_impl("Error", "Display"));
+    assert!(impls.may_impl("Error", "Debug"));
+    assert!(!impls.may_impl("Error", "Clone"));
+    assert!(impls.may_impl("Unknown", "Clone"));
+
+    // fn report<E: Display>(err: E)
+    let report = sig(&["E"], None, &[("E", &["Display"])]);
+    assert!(report.score(&query("Error"), &impls).is_some());
+}
+
+#[test]
+fn exact_matches_rank_first() {
+    let impls = TraitImpls::default();
+    let q = query("&str -> Result<_, Error>");
+    let score = |sig: FnSignature| sig.score(&q, &impls);
+
+    // fn parse(s: &str) -> Result<Config, Error>
+    let exact = score(sig(&["&str"], Some("Result<Config, Error>"), &[])).unwrap();
+    let partials = [
+        // fn parse<S: AsRef<str>>(s: S) -> Result<Config, Error>
+        sig(
+            &["S"],
+            Some("Result<Config, Error>"),
+            &[("S", &["AsRef<str>"])],
+        ),
+        // fn parse_with(s: &str, flags: u8) -> Result<Config, Error>
+        sig(&["&str", "u8"], Some("Result<Config, Error>"), &[]),
+        // fn parse_with(flags: u8, s: &str) -> Result<Config, Error>
+        sig(&["u8", "&str"], Some("Result<Config, Error>"), &[]),
+        // fn try_parse(s: &str) -> Option<Result<Config, Error>>
+        sig(&["&str"], Some("Option<Result<Config, Error>>"), &[]),
+        // fn parse(s: &str) -> Result
+        sig(&["&str"], Some("Result"), &[]),
+    ];
+    for partial in partials {
+        let display = format!("{partial:?}");
+        let partial = score(partial).unwrap_or_else(|| panic!("{display} doesn't match"));
+        assert!(exact > partial, "{exact} <= {partial} for {display}");
+    }
+
+    // extra arguments rank lower
+    let one = score(sig(&["&str", "u8"], Some("Result<(), Error>"), &[])).unwrap();
+    let two = score(sig(&["&str", "u8", "u8"], Some("Result<(), Error>"), &[])).unwrap();
+    assert!(one > two);
+
+    for mismatch in [
+        sig(&["&mut str"], Some("Result<Config, Error>"), &[]),
+        sig(&["&str"], Some("Result<Config, io::Error, u8>"), &[]),
+        sig(&["&str"], Some("Vec<Error>"), &[]),
+        sig(&[], Some("Result<Config, Error>"), &[]),
+    ] {
+        assert_eq!(score(mismatch.clone()), None, "{mismatch:?}");
+    }
+}
+
+#[test]
+fn unordered_inputs() {
+    let impls = TraitImpls::default();
+
+    // fn push(byte: u8, text: String)
+    let push = sig(&["u8", "String"], None, &[]);
+    assert!(push.score(&query("String, u8"), &impls).is_some());
+    // `_` must not take the only argument that a later query type can match
+    assert!(push.score(&query("_, u8"), &impls).is_some());
+    assert!(push.score(&query("_, String"), &impls).is_some());
+    assert_eq!(push.score(&query("u8, u8"), &impls), None);
+}
+
+#[test]
+fn trait_paths() {
+    let mut impls = TraitImpls::default();
+    impls.insert(
+        ID::new("0:1"),
+        "Error".into(),
+        traits(&["core::fmt::Display", "Local"]),
+    );
+    // bounds in signatures are names
+    assert!(impls.may_impl("Error", "Display"));
+    assert!(impls.may_impl("Error", "Local"));
+    assert!(!impls.may_impl("Error", "Debug"));
+
+    // full paths are compared if both are known
+    assert!(same_trait("core::fmt::Display", "core::fmt::Display"));
+    assert!(!same_trait("core::fmt::Display", "my_crate::Display"));
+    assert!(same_trait("core::fmt::Display", "Display"));
+    assert!(same_trait("Display", "Display"));
+    assert!(!same_trait("Display", "Debug"));
+}
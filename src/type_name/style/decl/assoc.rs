@@ -0,0 +1,58 @@
+//! Associated items that are declared in trait definitions.
+
+use super::VisNameMap;
+use crate::type_name::style::{
+    path::{FindName, Format},
+    utils::write_plus,
+    Decl, Punctuation, StyledType,
+};
+use rustdoc_types::{GenericBound, Generics, Type};
+
+/// `const NAME: Type = default;`
+pub(super) fn assoc_const<K: FindName>(
+    type_: &Type,
+    default: Option<&str>,
+    map: VisNameMap,
+    buf: &mut StyledType,
+) {
+    let VisNameMap { vis, id, name, .. } = map;
+    vis.format::<K>(buf);
+    buf.write(Decl::Const);
+    buf.write_id_name(id, name);
+    buf.write(Punctuation::Colon);
+    type_.format::<K>(buf);
+    if let Some(default) = default {
+        buf.write(Punctuation::Equal);
+        buf.write(default);
+    }
+    buf.write(Punctuation::SemiColon);
+}
+
+/// `type Name<...>: Bounds = Default where ...;`
+pub(super) fn assoc_type<K: FindName>(
+    generics: &Generics,
+    bounds: &[GenericBound],
+    default: Option<&Type>,
+    map: VisNameMap,
+    buf: &mut StyledType,
+) {
+    let VisNameMap { vis, id, name, .. } = map;
+    let Generics {
+        params,
+        where_predicates,
+    } = generics;
+    vis.format::<K>(buf);
+    buf.write(Decl::TypeAlias);
+    buf.write_id_name(id, name);
+    params.format::<K>(buf);
+    if !bounds.is_empty() {
+        buf.write(Punctuation::Colon);
+        buf.write_slice(bounds, GenericBound::format::<K>, write_plus);
+    }
+    if let Some(ty) = default {
+        buf.write(Punctuation::Equal);
+        ty.format::<K>(buf);
+    }
+    where_predicates.format::<K>(buf);
+    buf.write(Punctuation::SemiColon);
+}
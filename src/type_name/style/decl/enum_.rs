@@ -0,0 +1,129 @@
+use super::{Declaration, VisNameMap};
+use crate::{
+    tree::IDMap,
+    type_name::style::{
+        path::{FindName, Format},
+        utils::{write_comma, write_comma_without_whitespace},
+        Decl, Punctuation, StyledType, Syntax,
+    },
+};
+use rustdoc_types::{Enum, Generics, Id, ItemEnum, Variant, VariantKind};
+
+impl Declaration for Enum {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let Enum {
+            generics:
+                Generics {
+                    params,
+                    where_predicates,
+                },
+            variants_stripped,
+            variants,
+            ..
+        } = self;
+        let VisNameMap { vis, id, name, map } = map;
+        vis.format::<K>(buf);
+        buf.write(Decl::Enum);
+        buf.write_id_name(id, name);
+        params.format::<K>(buf);
+        where_predicates.format::<K>(buf);
+        buf.write(if where_predicates.is_empty() {
+            Punctuation::WhiteSpace
+        } else {
+            Punctuation::NewLine
+        });
+        buf.write_in_brace(|buf| {
+            buf.write_slice(
+                variants,
+                |id, buf| {
+                    buf.write(Punctuation::NewLine);
+                    buf.write(Punctuation::Indent);
+                    variant::<K>(id, map, buf);
+                },
+                write_comma_without_whitespace,
+            );
+            if *variants_stripped {
+                if !variants.is_empty() {
+                    buf.write(Punctuation::Comma);
+                }
+                buf.write(Punctuation::NewLine);
+                buf.write(Punctuation::Indent);
+                buf.write(Decl::VariantsStripped);
+                buf.write(Punctuation::NewLine);
+            } else if !variants.is_empty() {
+                buf.write(Punctuation::Comma);
+                buf.write(Punctuation::NewLine);
+            }
+        });
+    }
+}
+
+/// A variant is written in one line, and its name can be jumped to.
+fn variant<K: FindName>(id: &Id, map: &IDMap, buf: &mut StyledType) {
+    let Some(item) = map.get_item(&id.0) else {
+        error!(?id, "variant item is not found");
+        return;
+    };
+    buf.write_id_name(&id.0, item.name.as_deref().unwrap_or("???"));
+    let ItemEnum::Variant(Variant { kind, discriminant }) = &item.inner else {
+        error!(?item, "not a Variant in an enum");
+        return;
+    };
+    match kind {
+        VariantKind::Plain => (),
+        VariantKind::Tuple(fields) => buf.write_in_parentheses(|buf| {
+            buf.write_slice(
+                fields,
+                |f, buf| {
+                    let id = f.as_ref().map(|id| &*id.0);
+                    let field = id.and_then(|id| map.get_item(id).map(|x| &x.inner));
+                    if let Some(ItemEnum::StructField(f)) = field {
+                        f.format::<K>(buf);
+                    } else {
+                        // hidden field
+                        buf.write(Syntax::Infer);
+                    }
+                },
+                write_comma,
+            )
+        }),
+        VariantKind::Struct {
+            fields,
+            fields_stripped,
+        } => {
+            buf.write(Punctuation::WhiteSpace);
+            buf.write_in_brace(|buf| {
+                buf.write(Punctuation::WhiteSpace);
+                buf.write_slice(
+                    fields,
+                    |id, buf| {
+                        let Some(field) = map.get_item(&id.0) else {
+                            error!(?id, "field item is not found");
+                            return;
+                        };
+                        buf.write(field.name.as_deref().unwrap_or("???"));
+                        buf.write(Punctuation::Colon);
+                        if let ItemEnum::StructField(f) = &field.inner {
+                            f.format::<K>(buf);
+                        } else {
+                            error!(?field, "not a StructField in a variant");
+                            buf.write(Syntax::Infer);
+                        }
+                    },
+                    write_comma,
+                );
+                if *fields_stripped {
+                    if !fields.is_empty() {
+                        write_comma(buf);
+                    }
+                    buf.write(Decl::PrivateFields);
+                }
+                buf.write(Punctuation::WhiteSpace);
+            });
+        }
+    }
+    if let Some(discriminant) = discriminant {
+        buf.write(Punctuation::Equal);
+        buf.write(&*discriminant.expr);
+    }
+}
@@ -0,0 +1,80 @@
+use super::{Declaration, VisNameMap};
+use crate::type_name::style::{path::FindName, Punctuation, StyledType};
+use rustdoc_types::{MacroKind, ProcMacro};
+
+/// The source of a declarative macro is given by rustdoc with arms' bodies elided, like
+///
+/// ```text
+/// macro_rules! name {
+///     () => { ... };
+///     ($e:expr) => { ... };
+/// }
+/// ```
+///
+/// Each line is written as it is, and the first occurrence of the macro name is jumpable.
+pub(super) fn decl_macro(src: &str, map: VisNameMap, buf: &mut StyledType) {
+    let VisNameMap { id, name, .. } = map;
+    let mut lines = src.lines();
+    if let Some(first) = lines.next() {
+        match find_ident(first, name) {
+            Some(pos) => {
+                buf.write(&first[..pos]);
+                buf.write_id_name(id, name);
+                buf.write(&first[pos + name.len()..]);
+            }
+            None => buf.write(first),
+        }
+    }
+    for line in lines {
+        buf.write(Punctuation::NewLine);
+        buf.write(line);
+    }
+}
+
+/// Find the position of `name` as a whole identifier, thus `m` won't be found in `macro_rules`.
+fn find_ident(line: &str, name: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    if name.is_empty() {
+        return None;
+    }
+    line.match_indices(name).map(|(pos, _)| pos).find(|&pos| {
+        let before = line[..pos].chars().next_back();
+        let after = line[pos + name.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+impl Declaration for ProcMacro {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let ProcMacro { kind, helpers } = self;
+        let VisNameMap { id, name, .. } = map;
+        match kind {
+            // name!() { /* proc-macro */ }
+            MacroKind::Bang => {
+                buf.write_id_name(id, name);
+                buf.write("!");
+                buf.write_in_parentheses(|_| ());
+                buf.write(Punctuation::WhiteSpace);
+                buf.write_in_brace(|buf| buf.write(" /* proc-macro */ "));
+            }
+            // #[name]
+            MacroKind::Attr => {
+                buf.write("#");
+                buf.write_in_squre_bracket(|buf| buf.write_id_name(id, name));
+            }
+            // #[derive(Name)]
+            MacroKind::Derive => {
+                buf.write("#");
+                buf.write_in_squre_bracket(|buf| {
+                    buf.write("derive");
+                    buf.write_in_parentheses(|buf| buf.write_id_name(id, name));
+                });
+                if !helpers.is_empty() {
+                    buf.write(Punctuation::NewLine);
+                    buf.write("// helper attributes: ");
+                    buf.write(&*helpers.join(", "));
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,16 @@
+mod assoc;
+mod enum_;
 mod function;
+mod macro_;
+mod static_const;
 mod struct_;
+mod trait_;
+mod type_alias;
+mod union;
 
 use super::{
     path::{FindName, Format, Short},
-    StyledType, Vis,
+    Punctuation, StyledType, Symbol, Tag, Vis,
 };
 use crate::tree::IDMap;
 use rustdoc_types::{ItemEnum, Visibility};
@@ -24,6 +31,29 @@ fn item_styled(id: &str, map: &IDMap) -> StyledType {
             }
             ItemEnum::Function(f) => f.format_as_short(vis_name_map, &mut buf),
             ItemEnum::Struct(s) => s.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::Enum(e) => e.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::Union(u) => u.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::Trait(t) => t.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::TraitAlias(t) => t.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::TypeAlias(t) => t.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::Constant(c) => c.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::Static(s) => s.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::Macro(m) => macro_::decl_macro(m, vis_name_map, &mut buf),
+            ItemEnum::ProcMacro(p) => p.format_as_short(vis_name_map, &mut buf),
+            ItemEnum::AssocConst { type_, default } => {
+                assoc::assoc_const::<Short>(type_, default.as_deref(), vis_name_map, &mut buf)
+            }
+            ItemEnum::AssocType {
+                generics,
+                bounds,
+                default,
+            } => assoc::assoc_type::<Short>(
+                generics,
+                bounds,
+                default.as_ref(),
+                vis_name_map,
+                &mut buf,
+            ),
             _ => return StyledType::default(),
         };
         return buf;
@@ -58,6 +88,20 @@ struct VisNameMap<'a> {
     map: &'a IDMap,
 }
 
+impl StyledType {
+    /// Append another StyledType with an indentation at the start of each line.
+    fn write_indented(&mut self, other: StyledType) {
+        self.write(Punctuation::Indent);
+        for tag in other.inner {
+            let newline = matches!(tag, Tag::Symbol(Symbol::Punctuation(Punctuation::NewLine)));
+            self.inner.push(tag);
+            if newline {
+                self.write(Punctuation::Indent);
+            }
+        }
+    }
+}
+
 trait Declaration {
     fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType);
     fn format_as_short(&self, map: VisNameMap, buf: &mut StyledType) {
@@ -0,0 +1,53 @@
+use super::{Declaration, VisNameMap};
+use crate::type_name::style::{
+    path::{FindName, Format},
+    Decl, Punctuation, StyledType, Syntax,
+};
+use rustdoc_types::{Constant, Static};
+
+impl Declaration for Constant {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let Constant {
+            type_, expr, value, ..
+        } = self;
+        let VisNameMap { vis, id, name, .. } = map;
+        vis.format::<K>(buf);
+        buf.write(Decl::Const);
+        buf.write_id_name(id, name);
+        buf.write(Punctuation::Colon);
+        type_.format::<K>(buf);
+        buf.write(Punctuation::Equal);
+        buf.write(&**expr);
+        buf.write(Punctuation::SemiColon);
+        // show the evaluated value if the expression isn't a literal, e.g. `1 << 10` is 1024
+        if let Some(value) = value.as_deref().filter(|v| *v != expr.as_str()) {
+            buf.write(Punctuation::WhiteSpace);
+            buf.write("// ");
+            buf.write(value);
+        }
+    }
+}
+
+impl Declaration for Static {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let Static {
+            type_,
+            mutable,
+            expr,
+        } = self;
+        let VisNameMap { vis, id, name, .. } = map;
+        vis.format::<K>(buf);
+        buf.write(Decl::Static);
+        if *mutable {
+            buf.write(Syntax::Mut);
+        }
+        buf.write_id_name(id, name);
+        buf.write(Punctuation::Colon);
+        type_.format::<K>(buf);
+        if !expr.is_empty() {
+            buf.write(Punctuation::Equal);
+            buf.write(&**expr);
+        }
+        buf.write(Punctuation::SemiColon);
+    }
+}
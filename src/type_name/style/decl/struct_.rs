@@ -95,7 +95,12 @@ fn tuple<K: FindName>(fields: &[Option<Id>], map: &IDMap, buf: &mut StyledType)
     });
 }
 
-fn plain<K: FindName>(fields: &[Id], has_private_field: bool, map: &IDMap, buf: &mut StyledType) {
+pub(super) fn plain<K: FindName>(
+    fields: &[Id],
+    has_private_field: bool,
+    map: &IDMap,
+    buf: &mut StyledType,
+) {
     buf.write_in_brace(|buf| {
         buf.write_slice(
             fields,
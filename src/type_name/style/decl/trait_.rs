@@ -0,0 +1,117 @@
+use super::{assoc, Declaration, VisNameMap};
+use crate::{
+    tree::IDMap,
+    type_name::style::{
+        path::{FindName, Format},
+        utils::write_plus,
+        Decl, Punctuation, StyledType,
+    },
+};
+use rustdoc_types::{GenericBound, Generics, Id, Item, ItemEnum, Trait};
+
+impl Declaration for Trait {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let Trait {
+            is_auto,
+            is_unsafe,
+            items,
+            generics:
+                Generics {
+                    params,
+                    where_predicates,
+                },
+            bounds,
+            ..
+        } = self;
+        let VisNameMap { vis, id, name, map } = map;
+        vis.format::<K>(buf);
+        if *is_unsafe {
+            buf.write(Decl::Unsafe);
+        }
+        if *is_auto {
+            buf.write(Decl::Auto);
+        }
+        buf.write(Decl::Trait);
+        buf.write_id_name(id, name);
+        params.format::<K>(buf);
+        // supertraits
+        if !bounds.is_empty() {
+            buf.write(Punctuation::Colon);
+            buf.write_slice(bounds, GenericBound::format::<K>, write_plus);
+        }
+        where_predicates.format::<K>(buf);
+        buf.write(if where_predicates.is_empty() {
+            Punctuation::WhiteSpace
+        } else {
+            Punctuation::NewLine
+        });
+        trait_items::<K>(items, map, buf);
+    }
+}
+
+/// Associated items are grouped by types, constants and functions in order,
+/// and groups are separated by an empty line.
+fn trait_items<K: FindName>(items: &[Id], map: &IDMap, buf: &mut StyledType) {
+    let items: Vec<&Item> = items.iter().filter_map(|id| map.get_item(&id.0)).collect();
+    let types = items
+        .iter()
+        .filter(|item| matches!(item.inner, ItemEnum::AssocType { .. }));
+    let constants = items
+        .iter()
+        .filter(|item| matches!(item.inner, ItemEnum::AssocConst { .. }));
+    let functions = items
+        .iter()
+        .filter(|item| matches!(item.inner, ItemEnum::Function(_)));
+    buf.write_in_brace(|buf| {
+        let mut empty = true;
+        for group in [
+            types.collect::<Vec<_>>(),
+            constants.collect(),
+            functions.collect(),
+        ] {
+            if group.is_empty() {
+                continue;
+            }
+            if !empty {
+                // an empty line between groups
+                buf.write(Punctuation::NewLine);
+            }
+            empty = false;
+            for item in group {
+                buf.write(Punctuation::NewLine);
+                buf.write_indented(trait_item::<K>(item, map));
+            }
+        }
+        if !empty {
+            buf.write(Punctuation::NewLine);
+        }
+    });
+}
+
+fn trait_item<K: FindName>(item: &Item, map: &IDMap) -> StyledType {
+    let mut buf = StyledType::with_capacity(24);
+    let vis_name_map = VisNameMap {
+        vis: &item.visibility,
+        id: &item.id.0,
+        name: item.name.as_deref().unwrap_or(""),
+        map,
+    };
+    match &item.inner {
+        ItemEnum::AssocType {
+            generics,
+            bounds,
+            default,
+        } => assoc::assoc_type::<K>(generics, bounds, default.as_ref(), vis_name_map, &mut buf),
+        ItemEnum::AssocConst { type_, default } => {
+            assoc::assoc_const::<K>(type_, default.as_deref(), vis_name_map, &mut buf)
+        }
+        ItemEnum::Function(f) => {
+            f.format::<K>(vis_name_map, &mut buf);
+            if f.has_body {
+                buf.write(Decl::ProvidedBody);
+            }
+        }
+        _ => (),
+    }
+    buf
+}
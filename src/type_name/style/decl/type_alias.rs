@@ -0,0 +1,51 @@
+use super::{Declaration, VisNameMap};
+use crate::type_name::style::{
+    path::{FindName, Format},
+    utils::write_plus,
+    Decl, Punctuation, StyledType,
+};
+use rustdoc_types::{GenericBound, Generics, TraitAlias, TypeAlias};
+
+impl Declaration for TypeAlias {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let TypeAlias {
+            type_,
+            generics:
+                Generics {
+                    params,
+                    where_predicates,
+                },
+        } = self;
+        let VisNameMap { vis, id, name, .. } = map;
+        vis.format::<K>(buf);
+        buf.write(Decl::TypeAlias);
+        buf.write_id_name(id, name);
+        params.format::<K>(buf);
+        buf.write(Punctuation::Equal);
+        type_.format::<K>(buf);
+        where_predicates.format::<K>(buf);
+        buf.write(Punctuation::SemiColon);
+    }
+}
+
+impl Declaration for TraitAlias {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let TraitAlias {
+            generics:
+                Generics {
+                    params: generic_params,
+                    where_predicates,
+                },
+            params,
+        } = self;
+        let VisNameMap { vis, id, name, .. } = map;
+        vis.format::<K>(buf);
+        buf.write(Decl::Trait);
+        buf.write_id_name(id, name);
+        generic_params.format::<K>(buf);
+        buf.write(Punctuation::Equal);
+        buf.write_slice(params, GenericBound::format::<K>, write_plus);
+        where_predicates.format::<K>(buf);
+        buf.write(Punctuation::SemiColon);
+    }
+}
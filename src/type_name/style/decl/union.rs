@@ -0,0 +1,33 @@
+use super::{struct_::plain, Declaration, VisNameMap};
+use crate::type_name::style::{
+    path::{FindName, Format},
+    Decl, Punctuation, StyledType,
+};
+use rustdoc_types::{Generics, Union};
+
+impl Declaration for Union {
+    fn format<K: FindName>(&self, map: VisNameMap, buf: &mut StyledType) {
+        let Union {
+            generics:
+                Generics {
+                    params,
+                    where_predicates,
+                },
+            fields_stripped,
+            fields,
+            ..
+        } = self;
+        let VisNameMap { vis, id, name, map } = map;
+        vis.format::<K>(buf);
+        buf.write(Decl::Union);
+        buf.write_id_name(id, name);
+        params.format::<K>(buf);
+        where_predicates.format::<K>(buf);
+        buf.write(if where_predicates.is_empty() {
+            Punctuation::WhiteSpace
+        } else {
+            Punctuation::NewLine
+        });
+        plain::<K>(fields, *fields_stripped, map, buf);
+    }
+}
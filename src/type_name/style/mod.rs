@@ -13,7 +13,7 @@ use crate::{
 };
 use std::fmt;
 
-pub use path::{long, long_path};
+pub use path::{long, long_path, short, short_bounds, short_generic, short_path};
 
 #[derive(Default, Clone, Debug)]
 pub struct StyledType {
@@ -343,7 +343,20 @@ to_str!({val Tag::Decl(val)}
         Vis(Vis),
         Function(Function),
         Struct = "struct ",
+        Enum = "enum ",
+        Union = "union ",
+        Trait = "trait ",
+        TypeAlias = "type ",
+        Const = "const ",
+        Static = "static ",
+        /// `unsafe trait`
+        Unsafe = "unsafe ",
+        /// `auto trait`
+        Auto = "auto ",
         PrivateFields = "/* private fields */",
+        VariantsStripped = "// some variants omitted",
+        /// The body of a function provided in trait definition.
+        ProvidedBody = " { ... }",
     }
 );
 
@@ -1,5 +1,5 @@
 use super::StyledType;
-use rustdoc_types::{Path, Type};
+use rustdoc_types::{GenericBound, GenericParamDef, Path, Type};
 
 // pub trait TypeName: Copy + FnOnce(&Type, &mut StyledType) {}
 // impl<F> TypeName for F where F: Copy + FnOnce(&Type, &mut StyledType) {}
@@ -36,9 +36,29 @@ impl FindName for Long {
     }
 }
 
-// pub fn short(ty: &Type, buf: &mut StyledType) {
-//     <Type as Format>::format::<Short>(ty, buf);
-// }
+pub fn short(ty: &Type) -> String {
+    let mut buf = StyledType::with_capacity(16);
+    <Type as Format>::format::<Short>(ty, &mut buf);
+    buf.to_non_wrapped_string()
+}
+
+pub fn short_path(p: &Path) -> String {
+    let mut buf = StyledType::with_capacity(16);
+    __short_path__(p, &mut buf);
+    buf.to_non_wrapped_string()
+}
+
+pub fn short_generic(param: &GenericParamDef) -> String {
+    let mut buf = StyledType::with_capacity(16);
+    param.format::<Short>(&mut buf);
+    buf.to_non_wrapped_string()
+}
+
+pub fn short_bounds(bounds: &[GenericBound]) -> String {
+    let mut buf = StyledType::with_capacity(16);
+    bounds.format::<Short>(&mut buf);
+    buf.to_non_wrapped_string()
+}
 
 pub fn long(ty: &Type) -> String {
     let mut buf = StyledType::with_capacity(16);
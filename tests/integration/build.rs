@@ -1,4 +1,5 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rustc-check-cfg=cfg(nightly)");
     let output = std::process::Command::new("rustc").arg("-V").output()?;
     // set nightly cfg if current toolchain is nightly
     if std::str::from_utf8(&output.stdout)?.contains("nightly") {
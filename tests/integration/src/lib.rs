@@ -1,4 +1,5 @@
-#![cfg_attr(nightly, feature(c_variadic))]
+#![cfg_attr(nightly, feature(c_variadic, rustc_attrs, rustdoc_internals))]
+#![cfg_attr(nightly, allow(internal_features))]
 /// Documentation for struct AUnitStruct.
 pub struct AUnitStruct;
 
@@ -86,6 +87,8 @@ where
     'a: 'b,
 {
 }
+// bounds are intentionally split in two places
+#[allow(clippy::multiple_bound_locations)]
 pub fn func_trait_bounds<T: Copy>()
 where
     T: Clone,
@@ -195,3 +198,320 @@ pub mod structs {
         f1: &'a [T; N],
     }
 }
+
+/// Fields that make auto traits not implemented.
+pub mod auto_traits {
+    use std::{cell::Cell, marker::PhantomPinned, rc::Rc};
+
+    pub struct HoldRc<T> {
+        pub rc: Rc<T>,
+        pub len: usize,
+    }
+
+    pub struct HoldPtr<T> {
+        pub ptr: *const T,
+    }
+
+    pub struct HoldCell<T> {
+        pub cell: Cell<T>,
+    }
+
+    pub struct Pinned {
+        pub pin: PhantomPinned,
+    }
+}
+
+/// Items of various kinds for their declarations.
+pub mod decls {
+    #[repr(u8)]
+    pub enum DataEnum<T: Copy> {
+        Unit,
+        Tuple(T, u8),
+        Struct { a: T, b: Option<T> },
+        Discriminant = 8,
+    }
+
+    #[repr(C)]
+    pub union AUnion {
+        pub a: u32,
+        pub b: f32,
+    }
+
+    pub trait TraitWithItems: Clone {
+        const N: usize;
+        const M: u8 = 1;
+        type Item: Copy;
+        type Defaulted<'a>
+        where
+            Self: 'a;
+        fn required(&self) -> Self::Item;
+        fn provided(&self) {}
+    }
+
+    pub type Alias<T> = Result<T, std::io::Error>;
+
+    pub const CONST: [u8; 2] = [1, 2];
+    pub static STATIC: &str = "static";
+    pub static mut STATIC_MUT: u8 = 0;
+}
+
+/// Documentation for the primitive type declared in this crate.
+#[cfg_attr(nightly, rustc_doc_primitive = "u8")]
+mod prim_u8 {}
+
+/// Types with methods from `Deref` targets.
+pub mod deref {
+    use std::ops::Deref;
+
+    pub struct Inner;
+    impl Inner {
+        pub fn inner_method(&self) {}
+    }
+
+    pub struct Middle(Inner);
+    impl Middle {
+        pub fn middle_method(&self) {}
+    }
+    impl Deref for Middle {
+        type Target = Inner;
+        fn deref(&self) -> &Inner {
+            &self.0
+        }
+    }
+
+    /// Dereferences to Middle and then Inner.
+    pub struct Outer(Middle);
+    impl Deref for Outer {
+        type Target = Middle;
+        fn deref(&self) -> &Middle {
+            &self.0
+        }
+    }
+
+    pub struct Ping;
+    impl Deref for Ping {
+        type Target = Pong;
+        fn deref(&self) -> &Pong {
+            &Pong
+        }
+    }
+
+    pub struct Pong;
+    impl Deref for Pong {
+        type Target = Ping;
+        fn deref(&self) -> &Ping {
+            &Ping
+        }
+    }
+
+    pub struct Wrapper<T: ?Sized>(Box<T>);
+    impl<T: ?Sized> Deref for Wrapper<T>
+    where
+        T: std::fmt::Display,
+    {
+        type Target = T;
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    pub struct Byte(u8);
+    impl Deref for Byte {
+        type Target = u8;
+        fn deref(&self) -> &u8 {
+            &self.0
+        }
+    }
+
+    pub struct Bytes(Vec<u8>);
+    impl Deref for Bytes {
+        type Target = [u8];
+        fn deref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+}
+
+/// Glob imports with cycles and shadowed names.
+pub mod globs {
+    pub use self::a::*;
+    // shadows `a::Shadowed` from the glob import
+    pub use self::b::Shadowed;
+
+    pub mod a {
+        // cyclic with `b`
+        pub use super::b::*;
+
+        // shadows `b::Shadowed` from the glob import
+        pub struct Shadowed;
+        pub fn from_a() {}
+    }
+
+    pub mod b {
+        pub use super::a::*;
+
+        pub struct Shadowed(pub u8);
+        pub fn from_b() {}
+    }
+}
+
+pub mod usage {
+    pub struct Config {
+        pub verbose: bool,
+    }
+
+    pub struct ParseError;
+
+    impl Config {
+        pub fn new(verbose: bool) -> Self {
+            Config { verbose }
+        }
+        pub fn parse(_: &str) -> Result<Self, ParseError> {
+            Err(ParseError)
+        }
+        pub fn find(_: &str) -> Option<Self> {
+            None
+        }
+        pub fn boxed() -> Box<Self> {
+            Box::new(Config::new(false))
+        }
+        /// Not a constructor or consumer because of the receiver.
+        pub fn verbose(&self) -> bool {
+            self.verbose
+        }
+    }
+
+    pub fn default_config() -> Config {
+        Config::new(false)
+    }
+
+    pub fn apply(_: &Config, _: u8) {}
+
+    pub fn merge(_: Vec<Config>) {}
+}
+
+pub mod fn_types {
+    use std::fmt::Debug;
+
+    pub struct Input {
+        pub data: Vec<u8>,
+    }
+
+    pub enum Output {
+        Done,
+        Failed(String),
+    }
+
+    pub fn process<T: Clone + Debug>(_input: &Input, _extra: T) -> Option<Output> {
+        None
+    }
+
+    pub fn select<I>(_items: I) -> Result<Output, String>
+    where
+        I: IntoIterator<Item = Input>,
+    {
+        Ok(Output::Done)
+    }
+
+    pub fn produce() -> impl Iterator<Item = Input> {
+        std::iter::empty()
+    }
+}
+
+/// Supertraits form a DAG: Top is reached from Bottom through both Left and Right.
+pub mod hierarchy {
+    pub trait Top {}
+    pub trait Left: Top {}
+    pub trait Right: Top + Clone {}
+    pub trait Bottom: Left + Right {
+        type Item: Left;
+        fn required(&self);
+        fn provided(&self) {}
+    }
+    pub trait Generic<T: Top>: Bottom
+    where
+        Self: Sized,
+    {
+    }
+}
+
+/// Attributes and qualifiers shown as badges in outline and above declarations.
+pub mod badges {
+    #[deprecated(since = "0.1.0", note = "use `Current` instead")]
+    pub struct Old;
+
+    #[deprecated]
+    pub fn old_fn() {}
+
+    #[must_use = "a Current does nothing unless used"]
+    #[non_exhaustive]
+    pub struct Current {
+        pub value: u8,
+    }
+
+    impl Current {
+        pub const fn new(value: u8) -> Self {
+            Current { value }
+        }
+
+        #[must_use]
+        pub fn doubled(&self) -> u8 {
+            self.value * 2
+        }
+
+        /// # Safety
+        ///
+        /// The pointer is only valid while the value lives.
+        pub unsafe fn as_ptr(&self) -> *const u8 {
+            &self.value
+        }
+
+        #[deprecated(note = "use `value` field")]
+        pub fn get(&self) -> u8 {
+            self.value
+        }
+    }
+
+    #[non_exhaustive]
+    pub enum Kind {
+        Plain,
+        Fancy,
+    }
+}
+
+/// Concrete types for traits and bounded generic parameters.
+pub mod candidates {
+    pub trait Shape {
+        fn area(&self) -> f64;
+    }
+
+    #[derive(Clone)]
+    pub struct Circle(pub f64);
+
+    pub struct Square(pub f64);
+
+    impl Shape for Circle {
+        fn area(&self) -> f64 {
+            self.0 * self.0 * std::f64::consts::PI
+        }
+    }
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+
+    impl Shape for [Square] {
+        fn area(&self) -> f64 {
+            self.iter().map(Shape::area).sum()
+        }
+    }
+
+    pub fn total<S: Shape + Clone, T>(_shapes: &[S], _other: &T) -> f64
+    where
+        T: Shape + ?Sized,
+    {
+        0.0
+    }
+}
@@ -90,13 +90,58 @@ fn methods() {
     snap!("DeclarationLines-methods", DisplaySlice(&lines));
 }
 
+#[test]
+fn attributes() {
+    let map = &doc();
+    let dmod = map.dmodule();
+    let badges = dmod.modules.iter().find(|m| map.name(&m.id) == "badges");
+    let badges = badges.unwrap();
+    let current = &badges.structs.iter().find(|s| map.name(&s.id) == "Current");
+    let methods = &current.unwrap().impls.merged_inherent.functions;
+    let ids = badges.structs.iter().map(|s| &*s.id);
+    let ids = ids.chain(badges.enums.iter().map(|e| &*e.id));
+    let ids = ids.chain(badges.functions.iter().map(|f| &*f.id));
+    let ids = ids.chain(methods.iter().map(|f| &*f.id));
+    // attributes are placed above declarations, and deprecation is rendered back
+    let decls = ids.map(|id| {
+        let lines = DeclarationLines::new(id, map);
+        let lines = lines.iter().map(|line| {
+            let texts = line.iter().map(|tt| &*tt.text);
+            texts.collect::<String>()
+        });
+        lines.collect::<Vec<_>>().join("\n")
+    });
+    shot!(decls.collect::<Vec<_>>().join("\n"), @r#"
+    #[must_use = "a Current does nothing unless used"]
+    #[non_exhaustive]
+    pub struct Current {
+        value: u8
+    }
+    #[deprecated(since = "0.1.0", note = "use `Current` instead")]
+    pub struct Old;
+    #[non_exhaustive]
+    pub enum Kind {
+        Plain,
+        Fancy,
+    }
+    #[deprecated]
+    pub fn old_fn()
+    pub unsafe fn as_ptr(&self) -> *const u8
+    #[must_use]
+    pub fn doubled(&self) -> u8
+    #[deprecated(note = "use `value` field")]
+    pub fn get(&self) -> u8
+    pub const fn new(value: u8) -> Self
+    "#);
+}
+
 #[test]
 fn structs() {
     let map = &doc();
     let dmod = map.dmodule();
     let mut structs_str = Vec::new();
     recursive_struct_str(dmod, &mut structs_str, map);
-    shot!(DisplaySlice(&structs_str), @r###"
+    shot!(DisplaySlice(&structs_str), @"
     pub struct AUnitStruct;
     pub struct FieldsNamedStruct {
         field1: AUnitStruct,
@@ -104,6 +149,39 @@ fn structs() {
         field3: Vec<FieldsNamedStruct>,
         /* private fields */
     }
+    pub struct HoldCell<T> {
+        cell: Cell<T>
+    }
+    pub struct HoldPtr<T> {
+        ptr: *const T
+    }
+    pub struct HoldRc<T> {
+        rc: Rc<T>,
+        len: usize
+    }
+    pub struct Pinned {
+        pin: PhantomPinned
+    }
+    pub struct Current {
+        value: u8
+    }
+    pub struct Old;
+    pub struct Circle(f64);
+    pub struct Square(f64);
+    pub struct Byte(_);
+    pub struct Bytes(_);
+    pub struct Inner;
+    pub struct Middle(_);
+    pub struct Outer(_);
+    pub struct Ping;
+    pub struct Pong;
+    pub struct Wrapper<T: ?Sized>(_);
+    pub struct Input {
+        data: Vec<u8>
+    }
+    pub struct Shadowed(u8);
+    pub struct Shadowed;
+    pub struct Shadowed(u8);
     pub struct Named {
         fut: Pin<Box<dyn Future<Output = Result<(), Box<dyn Error>>>>>,
         /* private fields */
@@ -158,12 +236,76 @@ fn structs() {
         u8: Copy;
     pub struct AUnitStruct;
     pub struct AUnitStruct;
-    "###);
+    pub struct Config {
+        verbose: bool
+    }
+    pub struct ParseError;
+    ");
 
     let lines = Vec::from_iter(structs_str.iter().map(DeclarationLines::from));
     snap!("DeclarationLines-structs", DisplaySlice(&lines));
 }
 
+#[test]
+fn decls() {
+    let map = &doc();
+    let dmod = map.dmodule();
+    let decls = dmod.modules.iter().find(|m| map.name(&m.id) == "decls");
+    let decls = decls.expect("decls module not found");
+    let mut ids = Vec::new();
+    ids.extend(decls.enums.iter().map(|e| &e.id));
+    ids.extend(decls.unions.iter().map(|u| &u.id));
+    for trait_ in &decls.traits {
+        ids.push(&trait_.id);
+        ids.extend(trait_.constants.iter().chain(&*trait_.types));
+    }
+    ids.extend(decls.type_alias.iter().map(|t| &t.id));
+    ids.extend(decls.constants.iter().map(|c| &c.id));
+    ids.extend(decls.statics.iter().map(|s| &s.id));
+    ids.extend(dmod.macros_decl.iter().map(|m| &m.id));
+    let styled = Vec::from_iter(ids.into_iter().map(|id| StyledType::new(id, map)));
+    shot!(DisplaySlice(&styled), @r#"
+    pub enum DataEnum<T: Copy> {
+        Unit,
+        Tuple(T, u8),
+        Struct { a: T, b: Option<T> },
+        Discriminant = 8,
+    }
+    pub union AUnion {
+        a: u32,
+        b: f32
+    }
+    pub trait TraitWithItems: Clone {
+        type Item: Copy;
+        type Defaulted<'a>
+        where
+            Self: 'a;
+
+        const N: usize;
+        const M: u8 = 1;
+
+        fn required(&self) -> Self::Item;
+        fn provided(&self) { ... }
+    }
+    const M: u8 = 1;
+    const N: usize;
+    type Defaulted<'a>
+    where
+        Self: 'a;
+    type Item: Copy;
+    pub type Alias<T> = Result<T, Error>;
+    pub const CONST: [u8; 2] = _;
+    pub static STATIC: &str = "static";
+    pub static mut STATIC_MUT: u8 = 0;
+    macro_rules! a_decl_macro {
+        () => { ... };
+    }
+    "#);
+
+    let lines = Vec::from_iter(styled.iter().map(DeclarationLines::from));
+    snap!("DeclarationLines-decls", DisplaySlice(&lines));
+}
+
 fn recursive_struct_str(dmod: &DModule, structs_str: &mut Vec<StyledType>, map: &IDMap) {
     for struct_ in &dmod.structs {
         structs_str.push(StyledType::new(&struct_.id, map));
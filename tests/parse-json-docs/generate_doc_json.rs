@@ -64,7 +64,7 @@ where
     Q: AsRef<Path>,
 {
     rustdoc_json::Builder::default()
-        .toolchain("nightly")
+        .toolchain(crate::NIGHTLY)
         .target_dir(dir.as_ref())
         .all_features(true)
         .manifest_path(manifest_path.as_ref())
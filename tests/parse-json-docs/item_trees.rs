@@ -0,0 +1,438 @@
+use crate::{doc, shot};
+use rustdoc_types::{ExternalCrate, Id, ItemKind};
+use term_rustdoc::tree::{Candidate, CrateDoc, IDMap, Tag, TreeLines, ID};
+
+fn find(doc: &CrateDoc, path: &str, kind: ItemKind) -> ID {
+    let path: Vec<_> = path.split("::").map(String::from).collect();
+    doc.find_by_path(&path, &kind)
+        .unwrap_or_else(|| panic!("{path:?} is not found"))
+}
+
+/// Lines of the tree not deeper than the depth, since expanded items list all their impls.
+fn pruned(tree: impl std::fmt::Display, depth: usize) -> String {
+    let tree = tree.to_string();
+    let lines = tree.lines().filter(|line| {
+        let indent = line.chars().take_while(|c| "│├└─ ".contains(*c)).count();
+        indent / 4 <= depth
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+/// Candidate names with a mark for jumpable ones.
+fn candidates(candidates: Vec<Candidate>) -> String {
+    let lines = candidates.iter().map(|c| {
+        let mark = if c.id.is_some() { "[jump]" } else { "[text]" };
+        format!("{mark} {}", c.name)
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+/// Plain outline of the subtree under the line named `root`, with badges after names.
+fn outline_with_badges(lines: &TreeLines, root: &str) -> String {
+    let start = lines
+        .iter()
+        .position(|l| l.name.text.ends_with(root))
+        .unwrap_or_else(|| panic!("{root} is not in outline"));
+    let level = lines[start].level;
+    let len = lines[start + 1..]
+        .iter()
+        .take_while(|l| l.level > level)
+        .count();
+    let lines = lines[start..=start + len].iter().map(|l| {
+        let badges = l.badges().map(|(text, _)| format!(" {text}"));
+        format!(
+            "{}{}{}",
+            l.glyph.text,
+            l.name.text,
+            badges.collect::<String>()
+        )
+    });
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+#[test]
+fn deref() {
+    let doc = &doc();
+    let dmod = doc.dmodule();
+    let deref_sections = |name: &str| {
+        let id = find(
+            doc,
+            &format!("integration::deref::{name}"),
+            ItemKind::Struct,
+        );
+        let tree = dmod.impl_tree_with_deref(&id, doc, &[]).unwrap();
+        let sections = tree.tree.leaves.iter();
+        sections
+            .filter(|section| section.root.tag == Tag::DerefMethods)
+            .map(|section| section.to_string())
+            .collect::<String>()
+    };
+
+    // methods through a chain of Deref targets
+    shot!(deref_sections("Outer"), @"
+    Methods from Deref<Target = Middle>
+    ├── [Fn] middle_method
+    └── Methods from Deref<Target = Inner>
+        └── [Fn] inner_method
+    ");
+    // cyclic targets stop at the visited type
+    shot!(deref_sections("Ping"), @"Methods from Deref<Target = Pong>");
+    // generic target
+    shot!(deref_sections("Wrapper"), @"
+    Methods from Deref<Target = T>
+    └── [bound] where T: Display + ?Sized
+    ");
+    // primitive types are found in the doc declaring them
+    let byte = deref_sections("Byte");
+    shot!(byte.lines().next().unwrap(), @"Methods from Deref<Target = u8>");
+    assert!(byte.contains("[Fn] checked_add"), "{byte}");
+    // slices are only declared in core/alloc/std docs
+    shot!(deref_sections("Bytes"), @"
+    Methods from Deref<Target = [u8]>
+    └── Target not found in loaded docs
+    ");
+}
+
+#[test]
+fn glob_imports() {
+    let (globs, _) = TreeLines::new_with(doc(), |doc| {
+        let dmod = doc.dmodule();
+        let globs = dmod.modules.iter().find(|m| doc.name(&m.id) == "globs");
+        let globs = globs.unwrap();
+        // the explicitly imported `b::Shadowed` with a field wins over `a::Shadowed`
+        assert_eq!(globs.structs[0].fields.len(), 1);
+        globs.item_tree(doc)
+    });
+    shot!(globs.display_as_plain_text(), @"
+    integration::globs
+    ├── [Fn] from_a
+    ├── [Fn] from_b
+    ├── [Struct] Shadowed
+    ├── [Mod] integration::globs::a
+    │   ├── [Fn] from_a
+    │   ├── [Fn] from_b
+    │   └── [Struct] Shadowed
+    └── [Mod] integration::globs::b
+        ├── [Fn] from_a
+        ├── [Fn] from_b
+        └── [Struct] Shadowed
+    ");
+}
+
+#[test]
+fn usage() {
+    let doc = &doc();
+    let dmod = doc.dmodule();
+    let config = find(doc, "integration::usage::Config", ItemKind::Struct);
+
+    // `Result<Self, _>`, `Option<Self>` and `Box<Self>` construct Self as well
+    shot!(dmod.constructor_tree(&config, doc).unwrap(), @"
+    integration::usage::Config
+    ├── [Fn] integration::usage::default_config
+    ├── [fn] Config::boxed
+    ├── [fn] Config::find
+    ├── [fn] Config::new
+    └── [fn] Config::parse
+    ");
+    // Self or references to it in non-receiver arguments, but not Self in a Vec
+    shot!(dmod.consumer_tree(&config, doc).unwrap(), @"
+    integration::usage::Config
+    └── [Fn] integration::usage::apply
+    ");
+
+    let error = find(doc, "integration::usage::ParseError", ItemKind::Struct);
+    shot!(dmod.constructor_tree(&error, doc).unwrap(), @"
+    integration::usage::ParseError
+    └── No Constructors!
+    ");
+}
+
+#[test]
+fn fn_types() {
+    let doc = &doc();
+    let dmod = doc.dmodule();
+    let func = |name: &str| {
+        find(
+            doc,
+            &format!("integration::fn_types::{name}"),
+            ItemKind::Function,
+        )
+    };
+
+    // bounds of generic arguments are leaves
+    shot!(pruned(dmod.args_tree(&func("process"), doc).unwrap(), 3), @"
+    integration::fn_types::process
+    ├── [arg] _input: &Input
+    │   └── [Struct] integration::fn_types::Input
+    │       ├── Fields
+    │       └── Implementations
+    └── [arg] _extra: T
+        └── [bound] T: Clone + Debug
+    ");
+    shot!(pruned(dmod.return_tree(&func("process"), doc).unwrap(), 3), @"
+    integration::fn_types::process
+    └── [ret] Option<Output>
+        └── [Enum] integration::fn_types::Output
+            ├── Variants
+            └── Implementations
+    ");
+    // bounds in where clauses
+    shot!(pruned(dmod.args_tree(&func("select"), doc).unwrap(), 3), @"
+    integration::fn_types::select
+    └── [arg] _items: I
+        └── [bound] I: IntoIterator<Item = Input>
+            └── [Struct] integration::fn_types::Input
+    ");
+    shot!(pruned(dmod.return_tree(&func("select"), doc).unwrap(), 3), @"
+    integration::fn_types::select
+    └── [ret] Result<Output, String>
+        └── [Enum] integration::fn_types::Output
+            ├── Variants
+            └── Implementations
+    ");
+    shot!(pruned(dmod.return_tree(&func("produce"), doc).unwrap(), 3), @"
+    integration::fn_types::produce
+    └── [ret] impl Iterator<Item = Input>
+        └── [Struct] integration::fn_types::Input
+            ├── Fields
+            └── Implementations
+    ");
+}
+
+#[test]
+fn hierarchy() {
+    let doc = &doc();
+    let dmod = doc.dmodule();
+    let tr = |name: &str| {
+        find(
+            doc,
+            &format!("integration::hierarchy::{name}"),
+            ItemKind::Trait,
+        )
+    };
+
+    // Top is reached through both Left and Right
+    shot!(dmod.trait_hierarchy_tree(&tr("Bottom"), doc).unwrap(), @"
+    integration::hierarchy::Bottom
+    ├── Supertraits
+    │   ├── [Trait] Left
+    │   │   └── [Trait] Top
+    │   └── [Trait] Right
+    │       ├── [Trait] Top
+    │       └── [Trait] Clone
+    ├── Subtraits
+    │   └── [Trait] Generic
+    ├── Associated Types
+    │   └── [type] type Item: Left
+    ├── Required Methods
+    │   └── [fn] required
+    └── Provided Methods
+        └── [fn] provided
+    ");
+    // subtraits downward
+    shot!(dmod.trait_hierarchy_tree(&tr("Top"), doc).unwrap(), @"
+    integration::hierarchy::Top
+    └── Subtraits
+        ├── [Trait] Left
+        │   └── [Trait] Bottom
+        │       └── [Trait] Generic
+        └── [Trait] Right
+            └── [Trait] Bottom
+                └── [Trait] Generic
+    ");
+    shot!(dmod.trait_hierarchy_tree(&tr("Generic"), doc).unwrap(), @"
+    integration::hierarchy::Generic
+    ├── Generic Parameters
+    │   └── [bound] T: Top
+    └── Supertraits
+        ├── [Trait] Bottom
+        │   ├── [Trait] Left
+        │   │   └── [Trait] Top
+        │   └── [Trait] Right
+        │       ├── [Trait] Top
+        │       └── [Trait] Clone
+        └── [Trait] Sized
+    ");
+}
+
+#[test]
+fn badges() {
+    let doc = doc();
+    let badges = find(&doc, "integration::badges", ItemKind::Module);
+    let mut lines = TreeLines::new(doc);
+    lines.expand_current_module_only(badges);
+    shot!(outline_with_badges(&lines, "integration::badges"), @"
+    ├── [Mod] integration::badges
+    │   ├── [Fn] old_fn deprecated
+    │   ├── [Struct] Current non_exhaustive must_use
+    │   ├── [Struct] Old deprecated
+    │   └── [Enum] Kind non_exhaustive
+    ");
+
+    // deprecated items are filtered out with the folding kept
+    lines.toggle_deprecated();
+    shot!(outline_with_badges(&lines, "integration::badges"), @"
+    ├── [Mod] integration::badges
+    │   ├── [Struct] Current non_exhaustive must_use
+    │   └── [Enum] Kind non_exhaustive
+    ");
+}
+
+#[test]
+fn candidates_for_generics() {
+    let doc = &doc();
+
+    // implementors include non-path types, which aren't jumpable
+    let shape = find(doc, "integration::candidates::Shape", ItemKind::Trait);
+    shot!(candidates(doc.implementors(&shape)), @"
+    [jump] Circle
+    [jump] Square
+    [text] [Square]
+    ");
+
+    // `?Sized` is skipped, and bounds in where clauses are merged into params
+    let total = find(doc, "integration::candidates::total", ItemKind::Function);
+    let params = doc.bounded_params(&total);
+    let display = params
+        .iter()
+        .map(|p| format!("{} => {:?}", p.display(), p.bounds));
+    shot!(display.collect::<Vec<_>>().join("\n"), @r#"
+    S: Shape + Clone => ["integration::candidates::Shape", "core::clone::Clone"]
+    T: Shape => ["integration::candidates::Shape"]
+    "#);
+
+    // local types implementing all the bounds
+    let [s, t] = &params[..] else {
+        panic!("{params:?}")
+    };
+    shot!(candidates(doc.types_implementing(&s.bounds)), @"[jump] Circle");
+    shot!(candidates(doc.types_implementing(&t.bounds)), @"
+    [jump] Circle
+    [jump] Square
+    ");
+    // traits from other crates are compared by names if paths are unknown
+    shot!(candidates(doc.types_implementing(&["Clone".into()])), @"[jump] Circle");
+}
+
+#[test]
+fn item_paths() {
+    let doc = &doc();
+    let paths = doc.dmodule().item_paths(doc);
+    let lines = paths.iter().filter_map(|item| {
+        let in_mod = ["integration::submod1", "integration::hierarchy"]
+            .iter()
+            .any(|m| item.path.starts_with(m));
+        let owner = item.owner.as_ref().map(|id| doc.name(id));
+        let owner = owner.map(|name| format!(" (owned by {name})"));
+        in_mod.then(|| {
+            format!(
+                "{} {}{}",
+                item.tag.kind(),
+                item.path,
+                owner.unwrap_or_default()
+            )
+        })
+    });
+    // associated items in impls and traits are joined to the paths of their owners
+    shot!(lines.collect::<Vec<_>>().join("\n"), @"
+    [Mod] integration::hierarchy
+    [Trait] integration::hierarchy::Bottom
+    [type] integration::hierarchy::Bottom::Item (owned by Bottom)
+    [fn] integration::hierarchy::Bottom::provided (owned by Bottom)
+    [fn] integration::hierarchy::Bottom::required (owned by Bottom)
+    [Trait] integration::hierarchy::Generic
+    [Trait] integration::hierarchy::Left
+    [Trait] integration::hierarchy::Right
+    [Trait] integration::hierarchy::Top
+    [Mod] integration::submod1
+    [Enum] integration::submod1::AUnitEnum
+    [fn] integration::submod1::AUnitEnum::print (owned by AUnitEnum)
+    [fn] integration::submod1::AUnitEnum::fmt (owned by AUnitEnum)
+    [Mod] integration::submod1::submod2
+    [Trait] integration::submod1::submod2::ATraitNeverImplementedForTypes
+    [Struct] integration::submod1::submod2::AStructAlias
+    [Struct] integration::submod1::submod2::AUnitStruct
+    ");
+}
+
+#[test]
+fn expand_to_item() {
+    let doc = doc();
+    let target = find(
+        &doc,
+        "integration::submod1::submod2::ATraitNeverImplementedForTypes",
+        ItemKind::Trait,
+    );
+    // sub modules are folded at first
+    let mut lines = TreeLines::new(doc);
+    assert!(!lines.iter().any(|l| l.id.as_deref() == Some(&*target)));
+
+    // only modules on the way to the item are expanded
+    assert!(lines.expand_to_item(&target));
+    let modules = lines
+        .iter()
+        .filter(|l| l.name.text.contains("integration::"));
+    let modules = modules.map(|l| format!("{}{}", l.glyph.text, l.name.text));
+    shot!(modules.collect::<Vec<_>>().join("\n"), @"
+    ├─➤ [Mod] integration::auto_traits
+    ├─➤ [Mod] integration::badges
+    ├─➤ [Mod] integration::candidates
+    ├─➤ [Mod] integration::decls
+    ├─➤ [Mod] integration::deref
+    ├─➤ [Mod] integration::fn_types
+    ├─➤ [Mod] integration::globs
+    ├─➤ [Mod] integration::hierarchy
+    ├─➤ [Mod] integration::structs
+    ├── [Mod] integration::submod1
+    │   └── [Mod] integration::submod1::submod2
+    ╰─➤ [Mod] integration::usage
+    ");
+    assert!(lines.iter().any(|l| l.id.as_deref() == Some(&*target)));
+}
+
+#[test]
+fn external_items() {
+    let doc = &doc();
+    let circle = find(doc, "integration::candidates::Circle", ItemKind::Struct);
+    assert!(doc.external_item(&circle).is_none());
+
+    // a dependent crate which refers to Circle as an item from the integration crate
+    let dependent = |path: &[&str]| {
+        let mut krate = crate::INTEGRATION.doc.clone();
+        let id = Id(circle.id.to_string());
+        krate.index.remove(&id);
+        let item = krate.paths.get_mut(&id).unwrap();
+        item.crate_id = 42;
+        item.path = path.iter().map(|&s| s.into()).collect();
+        let name = "integration".into();
+        let external = ExternalCrate {
+            name,
+            html_root_url: None,
+        };
+        krate.external_crates.insert(42, external);
+        CrateDoc::new(krate)
+    };
+    let resolve = |dependent: &CrateDoc, others: &[&IDMap]| {
+        let (other, id) = dependent.find_in_others(&circle, others)?;
+        assert!(std::ptr::eq(other, &**doc));
+        Some(id)
+    };
+
+    let same_path = dependent(&["integration", "candidates", "Circle"]);
+    let (krate, item) = same_path.external_item(&circle).unwrap();
+    assert_eq!(krate, "integration");
+    assert_eq!(item.path, ["integration", "candidates", "Circle"]);
+    assert_eq!(resolve(&same_path, &[&**doc]), Some(circle.clone()));
+    // the doc of the crate is not loaded
+    assert_eq!(resolve(&same_path, &[]), None);
+
+    // reexported in another module, and the only struct named Circle is found
+    let reexported = dependent(&["integration", "shapes", "Circle"]);
+    assert_eq!(resolve(&reexported, &[&**doc]), Some(circle.clone()));
+
+    // the name is ambiguous without the full path
+    find(doc, "integration::globs::a::Shadowed", ItemKind::Struct);
+    find(doc, "integration::globs::b::Shadowed", ItemKind::Struct);
+    let shadowed = ["integration", "Shadowed"].map(String::from);
+    assert_eq!(doc.find_by_path(&shadowed, &ItemKind::Struct), None);
+}
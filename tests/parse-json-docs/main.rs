@@ -1,4 +1,3 @@
-#![feature(lazy_cell)]
 use bytesize::ByteSize;
 use color_eyre::eyre::{eyre, Result};
 use insta::{assert_debug_snapshot as snap, assert_display_snapshot as shot};
@@ -6,6 +5,7 @@ use regex::Regex;
 use rustc_hash::FxHashMap;
 use rustdoc_types::{Crate, Id, Item, ItemKind, ItemSummary};
 use std::{
+    collections::BTreeMap,
     fmt::Display,
     io::{Read, Write},
     path::PathBuf,
@@ -19,9 +19,14 @@ use term_rustdoc::{
 
 mod fn_item_decl;
 mod generate_doc_json;
+mod item_trees;
 mod parse;
 mod syntect_set;
 
+/// The nightly that emits JSON docs in the format of rustdoc-types (`FORMAT_VERSION = 28`).
+/// Item IDs in snapshots also depend on it, so pin it for reproducible snapshots.
+const NIGHTLY: &str = "nightly-2024-02-15";
+
 static INTEGRATION: LazyLock<JsonDoc> = LazyLock::new(|| {
     tracing_subscriber::fmt::init();
     println!("start!");
@@ -100,7 +105,7 @@ impl JsonDoc {
 
 fn get_integration_json_doc() -> Result<JsonDoc> {
     let json_path = rustdoc_json::Builder::default()
-        .toolchain("nightly")
+        .toolchain(NIGHTLY)
         // .manifest_path(
         //     "/root/.cargo/registry/src/rsproxy.cn-0dccff568467c15b/syn-2.0.48/Cargo.toml",
         // )
@@ -139,21 +144,106 @@ fn basic_info() -> Result<()> {
     paths.sort_unstable();
 
     // local items
-    snap!(paths, @r###"
+    snap!(paths, @r#"
     [
         "integration                                        [Module]",
         "integration::ACONSTANT                             [Constant]",
         "integration::ASTATIC                               [Constant]",
         "integration::ATrait                                [Trait]",
+        "integration::ATraitWithGAT                         [Trait]",
         "integration::AUnitStruct                           [Struct]",
         "integration::FieldsNamedStruct                     [Struct]",
         "integration::a_decl_macro                          [Macro]",
+        "integration::auto_traits                           [Module]",
+        "integration::auto_traits::HoldCell                 [Struct]",
+        "integration::auto_traits::HoldPtr                  [Struct]",
+        "integration::auto_traits::HoldRc                   [Struct]",
+        "integration::auto_traits::Pinned                   [Struct]",
+        "integration::badges                                [Module]",
+        "integration::badges::Current                       [Struct]",
+        "integration::badges::Kind                          [Enum]",
+        "integration::badges::Kind::Fancy                   [Variant]",
+        "integration::badges::Kind::Plain                   [Variant]",
+        "integration::badges::Old                           [Struct]",
+        "integration::badges::old_fn                        [Function]",
+        "integration::candidates                            [Module]",
+        "integration::candidates::Circle                    [Struct]",
+        "integration::candidates::Shape                     [Trait]",
+        "integration::candidates::Square                    [Struct]",
+        "integration::candidates::total                     [Function]",
+        "integration::decls                                 [Module]",
+        "integration::decls::AUnion                         [Union]",
+        "integration::decls::Alias                          [TypeAlias]",
+        "integration::decls::CONST                          [Constant]",
+        "integration::decls::DataEnum                       [Enum]",
+        "integration::decls::DataEnum::Discriminant         [Variant]",
+        "integration::decls::DataEnum::Struct               [Variant]",
+        "integration::decls::DataEnum::Tuple                [Variant]",
+        "integration::decls::DataEnum::Unit                 [Variant]",
+        "integration::decls::STATIC                         [Static]",
+        "integration::decls::STATIC_MUT                     [Static]",
+        "integration::decls::TraitWithItems                 [Trait]",
+        "integration::deref                                 [Module]",
+        "integration::deref::Byte                           [Struct]",
+        "integration::deref::Bytes                          [Struct]",
+        "integration::deref::Inner                          [Struct]",
+        "integration::deref::Middle                         [Struct]",
+        "integration::deref::Outer                          [Struct]",
+        "integration::deref::Ping                           [Struct]",
+        "integration::deref::Pong                           [Struct]",
+        "integration::deref::Wrapper                        [Struct]",
+        "integration::fn_types                              [Module]",
+        "integration::fn_types::Input                       [Struct]",
+        "integration::fn_types::Output                      [Enum]",
+        "integration::fn_types::Output::Done                [Variant]",
+        "integration::fn_types::Output::Failed              [Variant]",
+        "integration::fn_types::process                     [Function]",
+        "integration::fn_types::produce                     [Function]",
+        "integration::fn_types::select                      [Function]",
         "integration::func_dyn_trait                        [Function]",
         "integration::func_dyn_trait2                       [Function]",
+        "integration::func_fn_pointer_impl_trait            [Function]",
+        "integration::func_hrtb                             [Function]",
+        "integration::func_lifetime_bounds                  [Function]",
         "integration::func_primitive                        [Function]",
+        "integration::func_qualified_path                   [Function]",
+        "integration::func_trait_bounds                     [Function]",
+        "integration::func_tuple_array_slice                [Function]",
         "integration::func_with_1arg                        [Function]",
         "integration::func_with_1arg_and_ret                [Function]",
+        "integration::func_with_const                       [Function]",
         "integration::func_with_no_args                     [Function]",
+        "integration::globs                                 [Module]",
+        "integration::globs::a                              [Module]",
+        "integration::globs::a::Shadowed                    [Struct]",
+        "integration::globs::a::from_a                      [Function]",
+        "integration::globs::b                              [Module]",
+        "integration::globs::b::Shadowed                    [Struct]",
+        "integration::globs::b::from_b                      [Function]",
+        "integration::hierarchy                             [Module]",
+        "integration::hierarchy::Bottom                     [Trait]",
+        "integration::hierarchy::Generic                    [Trait]",
+        "integration::hierarchy::Left                       [Trait]",
+        "integration::hierarchy::Right                      [Trait]",
+        "integration::hierarchy::Top                        [Trait]",
+        "integration::no_synthetic                          [Function]",
+        "integration::structs                               [Module]",
+        "integration::structs::Named                        [Struct]",
+        "integration::structs::NamedAllPrivateFields        [Struct]",
+        "integration::structs::NamedAllPublicFields         [Struct]",
+        "integration::structs::NamedGeneric                 [Struct]",
+        "integration::structs::NamedGenericAllPrivate       [Struct]",
+        "integration::structs::NamedGenericWithBound        [Struct]",
+        "integration::structs::NamedGenericWithBoundAllPrivate [Struct]",
+        "integration::structs::Tuple                        [Struct]",
+        "integration::structs::TupleAllPrivate              [Struct]",
+        "integration::structs::TupleGeneric                 [Struct]",
+        "integration::structs::TupleGenericWithBound        [Struct]",
+        "integration::structs::TupleWithBound               [Struct]",
+        "integration::structs::Unit                         [Struct]",
+        "integration::structs::UnitGeneric                  [Struct]",
+        "integration::structs::UnitGenericWithBound         [Struct]",
+        "integration::structs::UnitWithBound                [Struct]",
         "integration::submod1                               [Module]",
         "integration::submod1::AUnitEnum                    [Enum]",
         "integration::submod1::AUnitEnum::A                 [Variant]",
@@ -161,17 +251,26 @@ fn basic_info() -> Result<()> {
         "integration::submod1::AUnitEnum::C                 [Variant]",
         "integration::submod1::submod2                      [Module]",
         "integration::submod1::submod2::ATraitNeverImplementedForTypes [Trait]",
+        "integration::u8                                    [Primitive]",
+        "integration::usage                                 [Module]",
+        "integration::usage::Config                         [Struct]",
+        "integration::usage::ParseError                     [Struct]",
+        "integration::usage::apply                          [Function]",
+        "integration::usage::default_config                 [Function]",
+        "integration::usage::merge                          [Function]",
+        "integration::variadic                              [Function]",
+        "integration::variadic_multiline                    [Function]",
     ]
-    "###);
+    "#);
 
     // item counts
-    shot!(doc.paths.len(), @"2009");
-    shot!(js.local_path().count(), @"20");
-    shot!(doc.index.len(), @"163");
-    shot!(js.local_index().count(), @"74");
+    shot!(doc.paths.len(), @"2107");
+    shot!(js.local_path().count(), @"114");
+    shot!(doc.index.len(), @"970");
+    shot!(js.local_index().count(), @"749");
 
     // data sizes
-    shot!(ByteSize(json.len() as _), @"372.9 KB");
+    shot!(ByteSize(json.len() as _), @"1030.8 KB");
 
     Ok(())
 }
@@ -185,11 +284,46 @@ fn compression() -> Result<()> {
         compressor.write_all(buf)?;
         Ok(compressor.finish()?.len() as _)
     }
+    /// The same fields as in Crate, but maps are sorted by keys, because the iteration order
+    /// of HashMap changes between runs. This is encoded into the same bytes as Crate does,
+    /// thus it's decoded back to Crate.
+    fn sorted(doc: &Crate) -> impl '_ + serde::Serialize {
+        let index: BTreeMap<_, _> = doc
+            .index
+            .iter()
+            .map(|(id, item)| {
+                let links: BTreeMap<_, _> = item.links.iter().collect();
+                let item = (
+                    &item.id,
+                    item.crate_id,
+                    &item.name,
+                    &item.span,
+                    &item.visibility,
+                    &item.docs,
+                    links,
+                    &item.attrs,
+                    &item.deprecation,
+                    &item.inner,
+                );
+                (&*id.0, item)
+            })
+            .collect();
+        let paths: BTreeMap<_, _> = doc.paths.iter().map(|(id, p)| (&*id.0, p)).collect();
+        let external_crates: BTreeMap<_, _> = doc.external_crates.iter().collect();
+        (
+            &doc.root,
+            &doc.crate_version,
+            doc.includes_private,
+            index,
+            paths,
+            external_crates,
+            doc.format_version,
+        )
+    }
     /// compress binary data generated by bincode from json string
     fn compress_bin(doc: &Crate) -> Result<[u64; 2]> {
         let config = bincode::config::standard();
-        let serialized = bincode::serde::encode_to_vec(doc, config)?;
-        compress(&serialized)?;
+        let serialized = bincode::serde::encode_to_vec(sorted(doc), config)?;
         // ensure bytes can be decoded back
         bincode::serde::decode_from_slice::<Crate, _>(&serialized, config)?;
         Ok([serialized.len() as _, compress(&serialized)?])
@@ -210,7 +344,7 @@ fn compression() -> Result<()> {
         "[raw json text => xz] {}",
         reduced_size(json_size, compress(json.as_bytes())?)
     );
-    shot!(json_compression, @"[raw json text => xz] 372.9 KB => 44.7 KB (-88%)");
+    shot!(json_compression, @"[raw json text => xz] 1030.8 KB => 78.6 KB (-92%)");
 
     let [bin_size, xz_size] = compress_bin(doc)?;
     let bin_compression = format!(
@@ -221,11 +355,11 @@ fn compression() -> Result<()> {
         reduced_size(bin_size, xz_size),
         reduced_size(json_size, xz_size)
     );
-    shot!(bin_compression, @r###"
-    [raw json text => bb] 372.9 KB => 179.7 KB (-52%)
-    [binary bytes  => xz] 179.7 KB => 42.5 KB (-76%)
-    [raw json text => xz] 372.9 KB => 42.5 KB (-89%) 
-    "###);
+    shot!(bin_compression, @"
+    [raw json text => bb] 1030.8 KB => 372.8 KB (-64%)
+    [binary bytes  => xz] 372.8 KB => 59.2 KB (-84%)
+    [raw json text => xz] 1030.8 KB => 59.2 KB (-94%)
+    ");
 
     Ok(())
 }
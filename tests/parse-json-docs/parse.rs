@@ -22,27 +22,30 @@ fn parse_module() {
     // item tree
     shot!("item-tree", dmod.item_tree(&doc));
 
-    snap!(dmod.current_items_counts(), @r###"
+    snap!(dmod.current_items_counts(), @"
     ItemCount {
-        modules: 1,
+        modules: 11,
         structs: 2,
-        functions: 6,
-        traits: 1,
+        functions: 16,
+        traits: 2,
         constants: 2,
         macros_decl: 1,
     }
-    "###);
-    snap!(dmod.recursive_items_counts(), @r###"
+    ");
+    snap!(dmod.recursive_items_counts(), @"
     ItemCount {
-        modules: 2,
-        structs: 2,
-        enums: 1,
-        functions: 6,
-        traits: 1,
-        constants: 2,
+        modules: 14,
+        structs: 38,
+        unions: 1,
+        enums: 4,
+        functions: 26,
+        traits: 9,
+        constants: 3,
+        statics: 2,
+        type_alias: 1,
         macros_decl: 1,
     }
-    "###);
+    ");
 
     snap!(dmod.current_impls_counts(), @r###"
     ImplCounts {
@@ -63,52 +66,119 @@ fn parse_module() {
         },
     }
     "###);
-    snap!(dmod.recursive_impls_counts(), @r###"
+    snap!(dmod.recursive_impls_counts(), @"
     ImplCounts {
         total: ImplCount {
             kind: Both,
-            total: 5,
-            structs: 3,
+            total: 19,
+            structs: 17,
             enums: 2,
         },
         inherent: ImplCount {
             kind: Both,
-            total: 2,
-            structs: 1,
+            total: 12,
+            structs: 11,
             enums: 1,
         },
         trait: ImplCount {
             kind: Trait,
-            total: 3,
-            structs: 2,
+            total: 13,
+            structs: 12,
             enums: 1,
         },
     }
-    "###);
+    ");
 
     // struct inner
     let (struct_, _) = TreeLines::new_with(treelines.doc(), |doc| {
         doc.dmodule().structs[0].show_prettier(doc)
     });
-    shot!(struct_.display_as_plain_text(), @r###"
+    shot!(struct_.display_as_plain_text(), @"
     integration::AUnitStruct
     ├── No Fields!
     └── Implementations
         ├── Trait Impls
         │   └── AUnitStruct: ATrait
         ├── Auto Impls
-        │   ├── AUnitStruct: RefUnwindSafe
-        │   ├── AUnitStruct: Send
-        │   ├── AUnitStruct: Sync
-        │   ├── AUnitStruct: Unpin
-        │   └── AUnitStruct: UnwindSafe
+        │   ├── RefUnwindSafe
+        │   │   └── [bound] all fields are RefUnwindSafe
+        │   ├── Send
+        │   │   └── [bound] all fields are Send
+        │   ├── Sync
+        │   │   └── [bound] all fields are Sync
+        │   ├── Unpin
+        │   │   └── [bound] all fields are Unpin
+        │   └── UnwindSafe
+        │       └── [bound] all fields are UnwindSafe
         └── Blanket Impls
             ├── T: Any
+            │   ├── [bound] where T: 'static
+            │   └── [Fn] type_id
             ├── T: Borrow<T>
+            │   └── [Fn] borrow
             ├── T: BorrowMut<T>
+            │   └── [Fn] borrow_mut
             ├── T: From<T>
+            │   └── [Fn] from
             ├── T: Into<U>
+            │   ├── [bound] where U: From<T>
+            │   └── [Fn] into
             ├── T: TryFrom<U>
+            │   ├── [bound] where U: Into<T>
+            │   └── [Fn] try_from
             └── T: TryInto<U>
-    "###);
+                ├── [bound] where U: TryFrom<T>
+                └── [Fn] try_into
+    ");
+
+    // reasons of auto trait impls
+    let (hold_rc, _) = TreeLines::new_with(treelines.doc(), |doc| {
+        let dmod = doc.dmodule();
+        let auto_traits = dmod
+            .modules
+            .iter()
+            .find(|m| doc.name(&m.id) == "auto_traits");
+        let structs = &auto_traits.unwrap().structs;
+        let hold_rc = structs.iter().find(|s| doc.name(&s.id) == "HoldRc");
+        hold_rc.unwrap().show_prettier(doc)
+    });
+    shot!(hold_rc.display_as_plain_text(), @"
+    integration::auto_traits::HoldRc
+    ├── Fields
+    │   ├── [field] rc
+    │   └── [field] len
+    └── Implementations
+        ├── Auto Impls
+        │   ├── RefUnwindSafe
+        │   │   └── [bound] where T: RefUnwindSafe
+        │   ├── !Send
+        │   │   └── [bound] opted out by fields
+        │   │       └── [field] rc: Rc<T>
+        │   ├── !Sync
+        │   │   └── [bound] opted out by fields
+        │   │       └── [field] rc: Rc<T>
+        │   ├── Unpin
+        │   │   └── [bound] all fields are Unpin
+        │   └── UnwindSafe
+        │       └── [bound] where T: RefUnwindSafe
+        └── Blanket Impls
+            ├── T: Any
+            │   ├── [bound] where T: 'static
+            │   └── [Fn] type_id
+            ├── T: Borrow<T>
+            │   └── [Fn] borrow
+            ├── T: BorrowMut<T>
+            │   └── [Fn] borrow_mut
+            ├── T: From<T>
+            │   └── [Fn] from
+            ├── T: Into<U>
+            │   ├── [bound] where U: From<T>
+            │   └── [Fn] into
+            ├── T: TryFrom<U>
+            │   ├── [bound] where U: Into<T>
+            │   └── [Fn] try_from
+            └── T: TryInto<U>
+                ├── [bound] where U: TryFrom<T>
+                └── [Fn] try_into
+    ");
 }